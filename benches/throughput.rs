@@ -0,0 +1,42 @@
+//! Throughput benchmarks for bulk validation.
+//!
+//! Run with `cargo bench`. Uses a plain timing harness so that no benchmarking
+//! framework is needed as a dependency.
+
+use cc_license::{parse_batch, License, Validator};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const RECORDS: usize = 1_000_000;
+const INPUTS: [&str; 4] = [
+    "https://creativecommons.org/licenses/by/4.0/",
+    "http://www.creativecommons.org/licenses/by-nc-sa/3.0",
+    "https://creativecommons.org/publicdomain/zero/1.0/",
+    "https://creativecommons.org/licenses/by/5.0/",
+];
+
+fn report(name: &str, elapsed: Duration, records: usize) {
+    let per_second = records as f64 / elapsed.as_secs_f64();
+    println!("{name:<12} {records} records in {elapsed:>10.2?} ({per_second:>12.0} records/s)");
+}
+
+fn main() {
+    let records: Vec<&str> = INPUTS.iter().cycle().take(RECORDS).copied().collect();
+
+    let start = Instant::now();
+    for record in &records {
+        let _ = black_box(License::from_url(black_box(record)));
+    }
+    report("from_url", start.elapsed(), RECORDS);
+
+    let start = Instant::now();
+    black_box(parse_batch(&records));
+    report("parse_batch", start.elapsed(), RECORDS);
+
+    let input = records.join("\n");
+    let start = Instant::now();
+    let mut validator = Validator::new();
+    validator.feed_reader(input.as_bytes()).unwrap();
+    black_box(&validator);
+    report("validator", start.elapsed(), RECORDS);
+}
@@ -0,0 +1,379 @@
+use crate::error::ParseError;
+use crate::options::ParseOptions;
+use crate::rights::Rights;
+use crate::License;
+use std::fmt::Write;
+
+/// Hosts serving license badge images
+const BADGE_HOSTS: &[&str] = &["licensebuttons.net", "i.creativecommons.org"];
+
+impl License {
+    /// Obtain descriptive alternative text for the license badge image
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("https://creativecommons.org/licenses/by-nc/4.0/")?;
+    /// assert_eq!(
+    ///     license.badge_alt_text(),
+    ///     "Creative Commons Attribution-NonCommercial 4.0 International badge".to_string()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn badge_alt_text(&self) -> String {
+        let mut alt = String::new();
+        self.write_title_to(&mut alt)
+            .expect("writing to a String cannot fail");
+        alt.push_str(" badge");
+        alt
+    }
+
+    /// Obtain an accessible label for a link or badge pointing to the license
+    ///
+    /// Suitable for an `aria-label` attribute: it names the license in full,
+    /// followed by its abbreviation, so screen readers do not have to spell out
+    /// the abbreviation alone.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("https://creativecommons.org/licenses/by-sa/3.0/")?;
+    /// assert_eq!(
+    ///     license.badge_aria_label(),
+    ///     "License: Creative Commons Attribution-ShareAlike 3.0 Unported (CC BY-SA 3.0)".to_string()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn badge_aria_label(&self) -> String {
+        let mut label = String::from("License: ");
+        self.write_title_to(&mut label)
+            .and_then(|_| write!(label, " ({})", self.short()))
+            .expect("writing to a String cannot fail");
+        label
+    }
+
+    /// Obtain the URL of the 88x31 badge image hosted on licensebuttons.net
+    ///
+    /// The badge of a ported license is the one of its jurisdiction, e.g.
+    /// `https://licensebuttons.net/l/by/3.0/de/88x31.png`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("https://creativecommons.org/licenses/by-nd/4.0/")?;
+    /// assert_eq!(license.badge_url(), "https://licensebuttons.net/l/by-nd/4.0/88x31.png".to_string());
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn badge_url(&self) -> String {
+        let path = self.path();
+        let (kind, path) = match path.strip_prefix("licenses/") {
+            Some(path) => ("l", path),
+            None => ("p", path.trim_start_matches("publicdomain/")),
+        };
+        format!("https://licensebuttons.net/{}/{}/88x31.png", kind, path)
+    }
+
+    /// Parse a Creative Commons license from the URL of its badge image
+    ///
+    /// Accepts the images hosted on `licensebuttons.net` and
+    /// `i.creativecommons.org` in any size, such as the one returned by
+    /// [`License::badge_url`] or `https://i.creativecommons.org/l/by/3.0/de/80x15.png`,
+    /// which scraped pages often contain in place of a link to the license.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_badge_url("https://licensebuttons.net/l/by-sa/4.0/88x31.png")?;
+    /// assert_eq!(license, License::CC_BY_SA_4_0);
+    /// assert_eq!(License::from_badge_url(&license.badge_url())?, license);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn from_badge_url(url: &str) -> Result<Self, ParseError> {
+        let path = badge_path(url).ok_or(ParseError::InvalidUrl)?;
+        let (kind, path) = path.split_once('/').ok_or(ParseError::InvalidUrl)?;
+        let (path, image) = path.rsplit_once('/').ok_or(ParseError::InvalidUrl)?;
+        let is_image = image.rsplit_once('.').is_some_and(|(size, extension)| {
+            matches!(extension, "png" | "svg") && is_image_size(size)
+        });
+        if !is_image {
+            return Err(ParseError::InvalidUrl);
+        }
+        let prefix = match kind {
+            "l" => "licenses",
+            "p" => "publicdomain",
+            _ => return Err(ParseError::InvalidUrl),
+        };
+        let url = format!("https://creativecommons.org/{}/{}/", prefix, path);
+        License::parse(&url, &ParseOptions::strict())
+    }
+
+    /// Obtain an HTML notice with the license badge, for embedding in web pages
+    ///
+    /// The badge carries the accessible alternative text and label, and both links
+    /// are marked with `rel="license"` so the notice is machine-readable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("https://creativecommons.org/licenses/by/4.0/")?;
+    /// let html = license.html_notice();
+    /// assert!(html.contains(r#"<img alt="Creative Commons Attribution 4.0 International badge""#));
+    /// assert!(html.ends_with(
+    ///     r#"This work is licensed under a <a rel="license" href="https://creativecommons.org/licenses/by/4.0/">Creative Commons Attribution 4.0 International License</a>."#
+    /// ));
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn html_notice(&self) -> String {
+        let url = self.to_url();
+        let mut title = String::new();
+        self.write_title_to(&mut title)
+            .expect("writing to a String cannot fail");
+        let statement = match self.rights_enum() {
+            Rights::Zero | Rights::Certification => {
+                format!("This work is dedicated to the public domain under the <a rel=\"license\" href=\"{url}\">{title}</a>.")
+            }
+            Rights::Mark => {
+                format!("This work is free of known copyright restrictions, as indicated by the <a rel=\"license\" href=\"{url}\">{title}</a>.")
+            }
+            _ => format!(
+                "This work is licensed under a <a rel=\"license\" href=\"{url}\">{title} License</a>."
+            ),
+        };
+        format!(
+            "<a rel=\"license\" href=\"{url}\" aria-label=\"{}\"><img alt=\"{}\" style=\"border-width:0\" src=\"{}\" /></a><br />{statement}",
+            self.badge_aria_label(),
+            self.badge_alt_text(),
+            self.badge_url(),
+        )
+    }
+}
+
+/// The path of a badge image URL after the host, without its query or fragment
+fn badge_path(url: &str) -> Option<&str> {
+    let (scheme, rest) = url.trim().split_once("://")?;
+    if !scheme.eq_ignore_ascii_case("https") && !scheme.eq_ignore_ascii_case("http") {
+        return None;
+    }
+    let rest = rest.split(['?', '#']).next().unwrap_or(rest);
+    let (host, path) = rest.split_once('/')?;
+    BADGE_HOSTS
+        .iter()
+        .any(|badge_host| host.eq_ignore_ascii_case(badge_host))
+        .then_some(path)
+}
+
+/// Whether the file name is a badge size, e.g. `88x31`
+fn is_image_size(name: &str) -> bool {
+    name.split_once('x').is_some_and(|(width, height)| {
+        [width, height]
+            .iter()
+            .all(|side| !side.is_empty() && side.bytes().all(|b| b.is_ascii_digit()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ParseError;
+    use crate::jurisdiction::Jurisdiction;
+    use crate::rights::Rights;
+    use crate::table::LICENSES;
+    use crate::version::Version;
+    use crate::License;
+
+    #[test]
+    fn test_badge_alt_text() {
+        assert_eq!(
+            License::from_parts(Rights::Zero, Version::One, None).badge_alt_text(),
+            "Creative Commons CC0 1.0 Universal badge".to_string()
+        );
+        assert_eq!(
+            License::from_parts(Rights::Certification, Version::One, None).badge_alt_text(),
+            "Creative Commons Public Domain Dedication and Certification badge".to_string()
+        );
+    }
+
+    #[test]
+    fn test_badge_url() {
+        assert_eq!(
+            License::from_parts(Rights::Zero, Version::One, None).badge_url(),
+            "https://licensebuttons.net/p/zero/1.0/88x31.png".to_string()
+        );
+        assert_eq!(
+            License::from_parts(Rights::ByNcSa, Version::Three, None).badge_url(),
+            "https://licensebuttons.net/l/by-nc-sa/3.0/88x31.png".to_string()
+        );
+        assert_eq!(
+            License::PDM_1_0.badge_url(),
+            "https://licensebuttons.net/p/mark/1.0/88x31.png".to_string()
+        );
+    }
+
+    #[test]
+    fn test_from_badge_url() {
+        for (url, license) in [
+            (
+                "https://licensebuttons.net/l/by-sa/4.0/88x31.png",
+                License::CC_BY_SA_4_0,
+            ),
+            (
+                "http://i.creativecommons.org/l/by-nc/3.0/80x15.png",
+                License::CC_BY_NC_3_0,
+            ),
+            (
+                "https://licensebuttons.net/p/zero/1.0/88x31.png",
+                License::CC0_1_0,
+            ),
+            (
+                "https://i.creativecommons.org/p/mark/1.0/88x31.png?ref=badge",
+                License::PDM_1_0,
+            ),
+            (
+                "https://licensebuttons.net/l/publicdomain/88x31.png",
+                License::PDDC,
+            ),
+            (
+                "https://LICENSEBUTTONS.NET/l/by/4.0/88x31.svg",
+                License::CC_BY_4_0,
+            ),
+        ] {
+            assert_eq!(License::from_badge_url(url), Ok(license), "{}", url);
+        }
+        assert_eq!(
+            License::from_badge_url("https://i.creativecommons.org/l/by/3.0/de/88x31.png"),
+            License::ported(Rights::By, Version::Three, Jurisdiction::Germany)
+        );
+        for license in LICENSES {
+            assert_eq!(License::from_badge_url(&license.badge_url()), Ok(*license));
+        }
+    }
+
+    #[test]
+    fn test_ported_badge_url() {
+        let license = License::ported(Rights::By, Version::Three, Jurisdiction::Germany).unwrap();
+        assert_eq!(
+            license.badge_url(),
+            "https://licensebuttons.net/l/by/3.0/de/88x31.png"
+        );
+        for jurisdiction in Jurisdiction::iter() {
+            for version in jurisdiction.versions() {
+                for rights in Rights::iter() {
+                    if let Ok(license) = License::ported(rights, *version, jurisdiction) {
+                        assert_eq!(
+                            License::from_badge_url(&license.badge_url()),
+                            Ok(license),
+                            "{}",
+                            license.badge_url()
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_badge_url_errors() {
+        for (url, error) in [
+            (
+                "https://creativecommons.org/licenses/by/4.0/",
+                ParseError::InvalidUrl,
+            ),
+            (
+                "https://example.org/l/by/4.0/88x31.png",
+                ParseError::InvalidUrl,
+            ),
+            (
+                "https://licensebuttons.net/l/by/4.0/",
+                ParseError::InvalidUrl,
+            ),
+            (
+                "https://licensebuttons.net/l/by/4.0/logo.png",
+                ParseError::InvalidUrl,
+            ),
+            (
+                "https://licensebuttons.net/x/by/4.0/88x31.png",
+                ParseError::InvalidUrl,
+            ),
+            (
+                "https://licensebuttons.net/l/by/4.0/deed.fr/88x31.png",
+                ParseError::TrailingSegments,
+            ),
+            (
+                "https://licensebuttons.net/l/by-xx/4.0/88x31.png",
+                ParseError::InvalidRights,
+            ),
+            (
+                "https://licensebuttons.net/l/by/5.0/88x31.png",
+                ParseError::InvalidVersion,
+            ),
+            (
+                "https://licensebuttons.net/p/by/4.0/88x31.png",
+                ParseError::InvalidUrl,
+            ),
+            (
+                "https://licensebuttons.net/l/zero/1.0/88x31.png",
+                ParseError::InvalidUrl,
+            ),
+        ] {
+            assert_eq!(License::from_badge_url(url), Err(error), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_html_notice() {
+        assert_eq!(
+            License::from_parts(Rights::Zero, Version::One, None).html_notice(),
+            "<a rel=\"license\" href=\"https://creativecommons.org/publicdomain/zero/1.0/\" \
+            aria-label=\"License: Creative Commons CC0 1.0 Universal (CC0 1.0)\">\
+            <img alt=\"Creative Commons CC0 1.0 Universal badge\" style=\"border-width:0\" \
+            src=\"https://licensebuttons.net/p/zero/1.0/88x31.png\" /></a><br />\
+            This work is dedicated to the public domain under the \
+            <a rel=\"license\" href=\"https://creativecommons.org/publicdomain/zero/1.0/\">\
+            Creative Commons CC0 1.0 Universal</a>."
+        );
+        assert!(License::PDM_1_0.html_notice().ends_with(
+            "This work is free of known copyright restrictions, as indicated by the \
+            <a rel=\"license\" href=\"https://creativecommons.org/publicdomain/mark/1.0/\">\
+            Public Domain Mark 1.0</a>."
+        ));
+    }
+
+    #[test]
+    fn test_badge_aria_label() {
+        assert_eq!(
+            License::from_parts(Rights::ByNcNd, Version::TwoFive, None)
+            .badge_aria_label(),
+            "License: Creative Commons Attribution-NonCommercial-NoDerivatives 2.5 Generic (CC BY-NC-ND 2.5)"
+                .to_string()
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use crate::error::ParseError;
+use crate::options::ParseOptions;
+use crate::License;
+use std::collections::HashMap;
+
+/// Aggregate result of validating a batch of license URLs
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct BatchReport {
+    total: usize,
+    failures: Vec<(usize, ParseError)>,
+    error_counts: HashMap<ParseError, usize>,
+}
+
+impl BatchReport {
+    /// Number of inputs processed
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Number of inputs that parsed into a valid license
+    pub fn valid(&self) -> usize {
+        self.total - self.failures.len()
+    }
+
+    /// Number of inputs that failed to parse
+    pub fn invalid(&self) -> usize {
+        self.failures.len()
+    }
+
+    /// Number of inputs that failed with the given error
+    pub fn count(&self, error: ParseError) -> usize {
+        self.error_counts.get(&error).copied().unwrap_or(0)
+    }
+
+    /// Position within the batch and error of every failed input, in input order
+    pub fn failures(&self) -> &[(usize, ParseError)] {
+        &self.failures
+    }
+
+    fn record(&mut self, result: Result<License, ParseError>) {
+        if let Err(e) = result {
+            self.failures.push((self.total, e));
+            *self.error_counts.entry(e).or_insert(0) += 1;
+        }
+        self.total += 1;
+    }
+
+    /// Append the report of a batch that immediately followed this one
+    pub fn merge(&mut self, other: BatchReport) {
+        let offset = self.total;
+        self.failures.extend(
+            other
+                .failures
+                .into_iter()
+                .map(|(index, e)| (index + offset, e)),
+        );
+        for (e, count) in other.error_counts {
+            *self.error_counts.entry(e).or_insert(0) += count;
+        }
+        self.total += other.total;
+    }
+}
+
+/// Result of every input of a batch, along with the report on the whole batch
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct ParsedBatch {
+    results: Vec<Result<License, ParseError>>,
+    report: BatchReport,
+}
+
+impl ParsedBatch {
+    /// Result of every input, in input order
+    pub fn results(&self) -> &[Result<License, ParseError>] {
+        &self.results
+    }
+
+    /// Counts of valid inputs and of each failure
+    pub fn report(&self) -> &BatchReport {
+        &self.report
+    }
+
+    /// Take the result of every input, in input order
+    pub fn into_results(self) -> Vec<Result<License, ParseError>> {
+        self.results
+    }
+
+    /// Append a batch that immediately followed this one
+    #[cfg(feature = "parallel")]
+    fn merge(&mut self, other: ParsedBatch) {
+        self.results.extend(other.results);
+        self.report.merge(other.report);
+    }
+}
+
+impl License {
+    /// Parse every input as a license URL, keeping each result and a summary
+    ///
+    /// Accepts any iterator of strings, such as the lines of an export, and
+    /// resolves the global default [`ParseOptions`] once for the whole batch
+    /// rather than once per input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{License, ParseError};
+    ///
+    /// let export = "https://creativecommons.org/licenses/by/4.0/\nhttps://creativecommons.org/licenses/by/5.0/";
+    /// let batch = License::parse_many(export.lines());
+    /// assert_eq!(batch.results(), &[Ok(License::CC_BY_4_0), Err(ParseError::InvalidVersion)]);
+    /// assert_eq!(batch.report().count(ParseError::InvalidVersion), 1);
+    /// ```
+    pub fn parse_many<I>(inputs: I) -> ParsedBatch
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let options = ParseOptions::global_default();
+        let mut batch = ParsedBatch::default();
+        for input in inputs {
+            let result = License::parse(input.as_ref(), options);
+            batch.report.record(result);
+            batch.results.push(result);
+        }
+        batch
+    }
+
+    /// Parse every input as a license URL across all available threads, keeping
+    /// each result and a summary
+    ///
+    /// Inputs are split into one shard per thread, and the result is identical to
+    /// [`License::parse_many`], in input order. On platforms without threads, such
+    /// as `wasm32-wasip1`, the batch is parsed on the calling thread.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::License;
+    ///
+    /// let inputs = vec!["https://creativecommons.org/licenses/by-sa/4.0/"; 10_000];
+    /// let batch = License::parse_many_par(&inputs);
+    /// assert_eq!(batch.report().valid(), 10_000);
+    /// assert!(batch.results().iter().all(|result| *result == Ok(License::CC_BY_SA_4_0)));
+    /// ```
+    #[cfg(feature = "parallel")]
+    pub fn parse_many_par<S: AsRef<str> + Sync>(inputs: &[S]) -> ParsedBatch {
+        parse_sharded(
+            inputs,
+            |shard| License::parse_many(shard),
+            ParsedBatch::merge,
+        )
+    }
+}
+
+/// Parse every input as a license URL and report on the outcome
+///
+/// # Example
+///
+/// ```rust
+/// use cc_license::{parse_batch, ParseError};
+///
+/// let report = parse_batch(&[
+///     "https://creativecommons.org/licenses/by/4.0/",
+///     "https://creativecommons.org/licenses/by/5.0/",
+/// ]);
+/// assert_eq!(report.valid(), 1);
+/// assert_eq!(report.failures(), &[(1, ParseError::InvalidVersion)]);
+/// ```
+pub fn parse_batch<S: AsRef<str>>(inputs: &[S]) -> BatchReport {
+    License::parse_many(inputs).report
+}
+
+/// Parse every input as a license URL across all available threads
+///
+/// Inputs are sharded as by [`License::parse_many_par`], so the result is
+/// identical to [`parse_batch`].
+#[cfg(feature = "parallel")]
+pub fn parse_batch_par<S: AsRef<str> + Sync>(inputs: &[S]) -> BatchReport {
+    License::parse_many_par(inputs).report
+}
+
+/// Parse one shard of the inputs per available thread and merge the results of
+/// the shards in input order
+#[cfg(feature = "parallel")]
+fn parse_sharded<S, T>(inputs: &[S], parse: fn(&[S]) -> T, merge: fn(&mut T, T)) -> T
+where
+    S: Sync,
+    T: Default + Send,
+{
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    if threads == 1 {
+        return parse(inputs);
+    }
+    let shard_size = inputs.len().div_ceil(threads).max(1);
+    std::thread::scope(|scope| {
+        let shards: Vec<_> = inputs
+            .chunks(shard_size)
+            .map(|shard| scope.spawn(move || parse(shard)))
+            .collect();
+        shards.into_iter().fold(T::default(), |mut merged, shard| {
+            merge(
+                &mut merged,
+                shard.join().expect("batch parsing thread panicked"),
+            );
+            merged
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUTS: [&str; 5] = [
+        "https://creativecommons.org/licenses/by/4.0/",
+        "https://creativecommons.org/licenses/by/5.0/",
+        "https://creativecommons.org/publicdomain/zero/1.0/",
+        "not a url",
+        "https://creativecommons.org/licenses/by-nc/2.6/",
+    ];
+
+    #[test]
+    fn test_parse_batch() {
+        let report = parse_batch(&INPUTS);
+        assert_eq!(report.total(), 5);
+        assert_eq!(report.valid(), 2);
+        assert_eq!(report.invalid(), 3);
+        assert_eq!(report.count(ParseError::InvalidVersion), 2);
+        assert_eq!(report.count(ParseError::InvalidScheme), 1);
+        assert_eq!(report.count(ParseError::InvalidRights), 0);
+        assert_eq!(
+            report.failures(),
+            &[
+                (1, ParseError::InvalidVersion),
+                (3, ParseError::InvalidScheme),
+                (4, ParseError::InvalidVersion),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_many() {
+        let batch = License::parse_many(INPUTS.iter().map(|input| input.to_string()));
+        assert_eq!(batch.report(), &parse_batch(&INPUTS));
+        assert_eq!(batch.results().len(), 5);
+        assert_eq!(batch.results()[0], Ok(License::CC_BY_4_0));
+        assert_eq!(batch.results()[3], Err(ParseError::InvalidScheme));
+        let results = batch.into_results();
+        for (failure, error) in parse_batch(&INPUTS).failures() {
+            assert_eq!(results[*failure], Err(*error));
+        }
+        assert_eq!(
+            License::parse_many(Vec::<String>::new()),
+            ParsedBatch::default()
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut report = parse_batch(&INPUTS[..2]);
+        report.merge(parse_batch(&INPUTS[2..]));
+        assert_eq!(report, parse_batch(&INPUTS));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parse_batch_par() {
+        let inputs: Vec<&str> = INPUTS.iter().cycle().take(1000).copied().collect();
+        assert_eq!(parse_batch_par(&inputs), parse_batch(&inputs));
+        assert_eq!(parse_batch_par::<&str>(&[]), BatchReport::default());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parse_many_par() {
+        let inputs: Vec<&str> = INPUTS.iter().cycle().take(1001).copied().collect();
+        assert_eq!(
+            License::parse_many_par(&inputs),
+            License::parse_many(&inputs)
+        );
+        assert_eq!(License::parse_many_par::<&str>(&[]), ParsedBatch::default());
+    }
+}
@@ -0,0 +1,115 @@
+//! Shell completion scripts for the command line tool.
+
+use cc_license::License;
+
+const COMMANDS: [(&str, &str); 3] = [
+    ("info", "Describe a Creative Commons license"),
+    ("choose", "Choose a license by answering a few questions"),
+    ("completions", "Print a shell completion script"),
+];
+pub(crate) const SHELLS: [&str; 3] = ["bash", "zsh", "fish"];
+
+/// Canonical URLs of every license, offered when completing license arguments
+fn license_urls() -> Vec<String> {
+    License::all().iter().map(License::to_url).collect()
+}
+
+fn commands() -> String {
+    COMMANDS.map(|(command, _)| command).join(" ")
+}
+
+/// Generate the completion script for a shell, if supported
+pub(crate) fn script(shell: &str) -> Option<String> {
+    let urls = license_urls().join(" ");
+    let shells = SHELLS.join(" ");
+    match shell {
+        "bash" => Some(format!(
+            r#"_cc_license() {{
+    local cur="${{COMP_WORDS[COMP_CWORD]}}"
+    local prev="${{COMP_WORDS[COMP_CWORD-1]}}"
+    case "$COMP_CWORD" in
+        1) COMPREPLY=($(compgen -W "{commands}" -- "$cur")) ;;
+        2)
+            case "$prev" in
+                info) COMPREPLY=($(compgen -W "{urls}" -- "$cur")) ;;
+                completions) COMPREPLY=($(compgen -W "{shells}" -- "$cur")) ;;
+            esac
+            ;;
+    esac
+    # URLs contain colons, which bash treats as word breaks
+    if declare -F __ltrim_colon_completions >/dev/null; then
+        __ltrim_colon_completions "$cur"
+    fi
+}}
+complete -F _cc_license cc-license
+"#,
+            commands = commands(),
+        )),
+        "zsh" => {
+            let described: Vec<String> = COMMANDS
+                .iter()
+                .map(|(command, description)| format!("'{command}:{description}'"))
+                .collect();
+            Some(format!(
+                r#"#compdef cc-license
+
+_cc_license() {{
+    local -a commands
+    commands=({described})
+    if (( CURRENT == 2 )); then
+        _describe 'command' commands
+    elif (( CURRENT == 3 )); then
+        case $words[2] in
+            info) compadd -- {urls} ;;
+            completions) compadd -- {shells} ;;
+        esac
+    fi
+}}
+
+_cc_license "$@"
+"#,
+                described = described.join(" "),
+            ))
+        }
+        "fish" => {
+            let mut script = String::from("complete -c cc-license -f\n");
+            for (command, description) in COMMANDS {
+                script.push_str(&format!(
+                    "complete -c cc-license -n __fish_use_subcommand -a {command} -d '{description}'\n"
+                ));
+            }
+            script.push_str(&format!(
+                "complete -c cc-license -n '__fish_seen_subcommand_from info' -a '{urls}'\n"
+            ));
+            script.push_str(&format!(
+                "complete -c cc-license -n '__fish_seen_subcommand_from completions' -a '{shells}'\n"
+            ));
+            Some(script)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_license_urls() {
+        let urls = license_urls();
+        assert_eq!(urls.len(), 42);
+        assert!(urls.contains(&"https://creativecommons.org/licenses/by-nc-sa/4.0/".to_string()));
+        assert!(urls.contains(&"https://creativecommons.org/publicdomain/zero/1.0/".to_string()));
+    }
+
+    #[test]
+    fn test_script() {
+        for shell in SHELLS {
+            let script = script(shell).unwrap();
+            assert!(script.contains("cc-license"));
+            assert!(script.contains("https://creativecommons.org/licenses/by/4.0/"));
+            assert!(script.contains("completions"));
+        }
+        assert!(script("powershell").is_none());
+    }
+}
@@ -0,0 +1,98 @@
+//! Minimal JSON rendering for machine-readable output.
+
+use cc_license::License;
+use std::fmt::Write;
+
+/// Render a string as a JSON string literal
+pub(crate) fn string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Render a license as a JSON object
+pub(crate) fn license(license: &License) -> String {
+    format!(
+        "{{\"url\":{},\"short\":{},\"title\":{},\"rights\":{},\"version\":{},\"jurisdiction\":{},\"key\":{},\"spdx\":{},\"status\":{}}}",
+        string(&license.to_url()),
+        string(&license.short()),
+        string(&license.to_string()),
+        string(&license.rights()),
+        string(&license.version()),
+        license
+            .jurisdiction()
+            .map_or("null".to_string(), |jurisdiction| string(jurisdiction.slug())),
+        string(license.canonical_key()),
+        license
+            .spdx_id()
+            .map_or("null".to_string(), |spdx_id| string(&spdx_id)),
+        string(&license.status().to_string().to_lowercase()),
+    )
+}
+
+/// Render an error as a JSON object
+pub(crate) fn error(code: &str, message: &str) -> String {
+    format!(
+        "{{\"error\":{{\"code\":{},\"message\":{}}}}}",
+        string(code),
+        string(message)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string() {
+        assert_eq!(string("CC BY"), "\"CC BY\"");
+        assert_eq!(string("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+    }
+
+    #[test]
+    fn test_license() {
+        let license = License::from_url("https://creativecommons.org/licenses/by-sa/3.0/").unwrap();
+        assert_eq!(
+            super::license(&license),
+            "{\"url\":\"https://creativecommons.org/licenses/by-sa/3.0/\",\
+            \"short\":\"CC BY-SA 3.0\",\
+            \"title\":\"Creative Commons Attribution-ShareAlike 3.0 Unported license (CC BY-SA 3.0).\",\
+            \"rights\":\"CC BY-SA\",\
+            \"version\":\"3.0\",\
+            \"jurisdiction\":null,\
+            \"key\":\"by-sa/3.0\",\
+            \"spdx\":\"CC-BY-SA-3.0\",\
+            \"status\":\"active\"}"
+        );
+    }
+
+    #[test]
+    fn test_ported_license() {
+        let license = License::from_url("https://creativecommons.org/licenses/by/3.0/de/").unwrap();
+        let json = super::license(&license);
+        assert!(json.contains("\"jurisdiction\":\"de\",\"key\":\"by/3.0/de\""));
+        assert!(json.contains("\"spdx\":\"CC-BY-3.0-DE\""));
+    }
+
+    #[test]
+    fn test_error() {
+        assert_eq!(
+            error("CC_E001", "Invalid URL"),
+            "{\"error\":{\"code\":\"CC_E001\",\"message\":\"Invalid URL\"}}"
+        );
+    }
+}
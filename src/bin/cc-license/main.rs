@@ -0,0 +1,395 @@
+//! Command line interface to the cc_license crate.
+
+mod completions;
+mod json;
+
+use cc_license::{License, ParseError};
+use std::fmt;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: cc-license [--format <text|json>] <command>
+
+Commands:
+  info <url>   Describe a Creative Commons license
+  choose       Choose a license by answering a few questions
+  completions <bash|zsh|fish>
+               Print a shell completion script
+
+Exit codes:
+  0  success
+  1  the license could not be parsed
+  2  invalid command line usage
+  3  input or output error
+";
+
+/// Output format selected with `--format`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Format {
+    Text,
+    Json,
+}
+
+/// Failure of a command, each class mapping to a stable exit code
+#[derive(Debug)]
+enum CliError {
+    License(ParseError),
+    Usage(String),
+    Io(io::Error),
+}
+
+impl CliError {
+    fn exit_code(&self) -> u8 {
+        match self {
+            CliError::License(_) => 1,
+            CliError::Usage(_) => 2,
+            CliError::Io(_) => 3,
+        }
+    }
+
+    fn code(&self) -> &str {
+        match self {
+            CliError::License(e) => e.code(),
+            CliError::Usage(_) => "usage",
+            CliError::Io(_) => "io",
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "i18n")]
+            CliError::License(e) => f.write_str(e.localized_description()),
+            #[cfg(not(feature = "i18n"))]
+            CliError::License(e) => write!(f, "{e}"),
+            CliError::Usage(message) => write!(f, "{message}"),
+            CliError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<ParseError> for CliError {
+    fn from(e: ParseError) -> Self {
+        CliError::License(e)
+    }
+}
+
+impl From<io::Error> for CliError {
+    fn from(e: io::Error) -> Self {
+        CliError::Io(e)
+    }
+}
+
+/// Extract the `--format` option from the arguments
+fn parse_format(args: &mut Vec<String>) -> Result<Format, CliError> {
+    let Some(i) = args
+        .iter()
+        .position(|arg| arg == "--format" || arg.starts_with("--format="))
+    else {
+        return Ok(Format::Text);
+    };
+    let option = args.remove(i);
+    let value = match option.strip_prefix("--format=") {
+        Some(value) => value.to_string(),
+        None if i < args.len() => args.remove(i),
+        None => return Err(CliError::Usage("missing value for --format".to_string())),
+    };
+    match value.as_str() {
+        "text" => Ok(Format::Text),
+        "json" => Ok(Format::Json),
+        _ => Err(CliError::Usage(format!(
+            "unsupported format {value}, expected text or json"
+        ))),
+    }
+}
+
+fn run(mut args: Vec<String>, format: &mut Format) -> Result<(), CliError> {
+    *format = parse_format(&mut args)?;
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let stdout = io::stdout();
+    match args.as_slice() {
+        ["info", url] => info(url, *format, colour(), stdout.lock()),
+        ["choose"] => choose(io::stdin().lock(), stdout.lock(), *format),
+        ["completions", shell] => completions(shell, *format, stdout.lock()),
+        _ => Err(CliError::Usage(USAGE.trim_end().to_string())),
+    }
+}
+
+fn main() -> ExitCode {
+    let mut format = Format::Text;
+    match run(std::env::args().skip(1).collect(), &mut format) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            match format {
+                Format::Json => println!("{}", json::error(e.code(), &e.to_string())),
+                Format::Text => match e {
+                    CliError::Usage(_) => eprintln!("{e}"),
+                    _ => eprintln!("error: {e}"),
+                },
+            }
+            ExitCode::from(e.exit_code())
+        }
+    }
+}
+
+/// Whether text output may be coloured: only on a terminal, unless disabled with
+/// the `NO_COLOR` environment variable
+fn colour() -> bool {
+    io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+}
+
+fn info<W: Write>(url: &str, format: Format, colour: bool, mut output: W) -> Result<(), CliError> {
+    let license = License::from_url(url)?;
+    match format {
+        Format::Text if colour => write!(output, "{}", license.render_terminal())?,
+        Format::Text => write!(output, "{}", license.render_plain())?,
+        Format::Json => writeln!(output, "{}", json::license(&license))?,
+    }
+    Ok(())
+}
+
+fn completions<W: Write>(shell: &str, format: Format, mut output: W) -> Result<(), CliError> {
+    let script = completions::script(shell).ok_or_else(|| {
+        CliError::Usage(format!(
+            "unsupported shell {shell}, expected one of: {}",
+            completions::SHELLS.join(", ")
+        ))
+    })?;
+    match format {
+        Format::Text => write!(output, "{script}")?,
+        Format::Json => writeln!(
+            output,
+            "{{\"shell\":{},\"script\":{}}}",
+            json::string(shell),
+            json::string(&script)
+        )?,
+    }
+    Ok(())
+}
+
+/// Ask a question until one of the accepted answers is given
+fn ask<R: BufRead, W: Write>(
+    input: &mut R,
+    output: &mut W,
+    question: &str,
+    answers: &[&str],
+) -> io::Result<usize> {
+    loop {
+        write!(output, "{question} [{}] ", answers.join("/"))?;
+        output.flush()?;
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "no answer given",
+            ));
+        }
+        let line = line.trim().to_lowercase();
+        if let Some(i) = answers
+            .iter()
+            .position(|answer| !line.is_empty() && answer.starts_with(&line))
+        {
+            return Ok(i);
+        }
+    }
+}
+
+/// Walk through the license chooser questions and pick a license
+fn ask_license<R: BufRead, W: Write>(input: &mut R, output: &mut W) -> io::Result<License> {
+    let public_domain = ask(
+        input,
+        output,
+        "Waive all your rights and dedicate the work to the public domain?",
+        &["yes", "no"],
+    )? == 0;
+    let builder = License::builder();
+    let builder = if public_domain {
+        builder.public_domain()
+    } else {
+        let adaptations = ask(
+            input,
+            output,
+            "Allow others to share adaptations of your work?",
+            &["yes", "no", "share-alike"],
+        )?;
+        let commercial = ask(
+            input,
+            output,
+            "Allow commercial uses of your work?",
+            &["yes", "no"],
+        )? == 0;
+        let builder = match adaptations {
+            0 => builder.attribution(),
+            1 => builder.attribution().no_derivatives(),
+            _ => builder.attribution().share_alike(),
+        };
+        if commercial {
+            builder
+        } else {
+            builder.non_commercial()
+        }
+    };
+    Ok(builder.build().expect("chooser only yields valid licenses"))
+}
+
+fn choose<R: BufRead, W: Write>(
+    mut input: R,
+    mut output: W,
+    format: Format,
+) -> Result<(), CliError> {
+    if format == Format::Json {
+        // keep standard output machine-readable by asking on standard error
+        let license = ask_license(&mut input, &mut io::stderr())?;
+        writeln!(output, "{}", json::license(&license))?;
+        return Ok(());
+    }
+    let license = ask_license(&mut input, &mut output)?;
+    writeln!(output)?;
+    writeln!(output, "{license}")?;
+    writeln!(output)?;
+    writeln!(output, "Short name: {}", license.short())?;
+    writeln!(output, "URL:        {}", license.canonical_forms().url)?;
+    writeln!(output, "Key:        {}", license.canonical_key())?;
+    writeln!(
+        output,
+        "SPDX:       {}",
+        license.spdx_id().as_deref().unwrap_or("none")
+    )?;
+    writeln!(output, "HTML:       {}", license.html_notice())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chosen(answers: &str) -> io::Result<License> {
+        ask_license(&mut answers.as_bytes(), &mut Vec::new())
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_ask_license() {
+        assert_eq!(chosen("y\n").unwrap().short(), "CC0 1.0".to_string());
+        assert_eq!(
+            chosen("n\ny\ny\n").unwrap().short(),
+            "CC BY 4.0".to_string()
+        );
+        assert_eq!(
+            chosen("no\nshare\nno\n").unwrap().short(),
+            "CC BY-NC-SA 4.0".to_string()
+        );
+        assert_eq!(
+            chosen("n\nn\nn\n").unwrap().short(),
+            "CC BY-NC-ND 4.0".to_string()
+        );
+        // unrecognised answers are asked again
+        assert_eq!(
+            chosen("maybe\n\nn\nx\ns\ny\n").unwrap().short(),
+            "CC BY-SA 4.0".to_string()
+        );
+        assert!(chosen("n\n").is_err());
+    }
+
+    #[test]
+    fn test_choose() {
+        let mut output = Vec::new();
+        choose("n\ny\nn\n".as_bytes(), &mut output, Format::Text).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains(
+            "Creative Commons Attribution-NonCommercial 4.0 International license (CC BY-NC 4.0)."
+        ));
+        assert!(output.contains("URL:        https://creativecommons.org/licenses/by-nc/4.0/\n"));
+        assert!(output.contains("SPDX:       CC-BY-NC-4.0\n"));
+
+        let mut output = Vec::new();
+        choose("y\n".as_bytes(), &mut output, Format::Json).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(
+            output.starts_with("{\"url\":\"https://creativecommons.org/publicdomain/zero/1.0/\"")
+        );
+    }
+
+    #[test]
+    fn test_parse_format() {
+        let mut arguments = args(&["info", "--format", "json", "url"]);
+        assert_eq!(parse_format(&mut arguments).unwrap(), Format::Json);
+        assert_eq!(arguments, args(&["info", "url"]));
+
+        let mut arguments = args(&["--format=text", "choose"]);
+        assert_eq!(parse_format(&mut arguments).unwrap(), Format::Text);
+        assert_eq!(arguments, args(&["choose"]));
+
+        assert_eq!(parse_format(&mut args(&["choose"])).unwrap(), Format::Text);
+        assert!(parse_format(&mut args(&["choose", "--format"])).is_err());
+        assert!(parse_format(&mut args(&["--format", "yaml"])).is_err());
+    }
+
+    #[test]
+    fn test_info() {
+        let mut output = Vec::new();
+        info(
+            "https://creativecommons.org/licenses/by/4.0/",
+            Format::Json,
+            false,
+            &mut output,
+        )
+        .unwrap();
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("\"short\":\"CC BY 4.0\""));
+
+        let error = info(
+            "https://creativecommons.org/licenses/by/5.0/",
+            Format::Json,
+            false,
+            Vec::new(),
+        )
+        .unwrap_err();
+        assert_eq!(error.exit_code(), 1);
+        assert_eq!(error.code(), "CC_E003");
+    }
+
+    #[test]
+    fn test_info_text() {
+        let url = "https://creativecommons.org/licenses/by/4.0/";
+        let mut output = Vec::new();
+        info(url, Format::Text, false, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("(CC BY 4.0)"));
+        assert!(!output.contains("\x1b["));
+
+        let mut output = Vec::new();
+        info(url, Format::Text, true, &mut output).unwrap();
+        assert!(String::from_utf8(output).unwrap().contains("\x1b["));
+    }
+
+    #[test]
+    fn test_exit_codes() {
+        let mut format = Format::Text;
+        assert_eq!(
+            run(args(&["unknown"]), &mut format)
+                .unwrap_err()
+                .exit_code(),
+            2
+        );
+        assert_eq!(
+            run(args(&["completions", "tcsh"]), &mut format)
+                .unwrap_err()
+                .exit_code(),
+            2
+        );
+        assert_eq!(
+            run(args(&["--format", "json", "info", "nope"]), &mut format)
+                .unwrap_err()
+                .exit_code(),
+            1
+        );
+        assert_eq!(format, Format::Json);
+    }
+}
@@ -0,0 +1,181 @@
+use crate::error::ParseError;
+use crate::rights::Rights;
+use crate::version::Version;
+use crate::License;
+
+/// Builder composing a license from its clauses, as license choosers do
+///
+/// Created by [`License::builder`].
+///
+/// # Example
+///
+/// ```rust
+/// use cc_license::{License, ParseError, Version};
+///
+/// let license = License::builder()
+///     .attribution()
+///     .non_commercial()
+///     .share_alike()
+///     .version(Version::Four)
+///     .build()?;
+/// assert_eq!(license, License::CC_BY_NC_SA_4_0);
+///
+/// // NoDerivatives and ShareAlike cannot be combined
+/// assert_eq!(
+///     License::builder().attribution().no_derivatives().share_alike().build(),
+///     Err(ParseError::InvalidRights)
+/// );
+/// # Ok::<(), ParseError>(())
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct LicenseBuilder {
+    attribution: bool,
+    share_alike: bool,
+    non_commercial: bool,
+    no_derivatives: bool,
+    public_domain: bool,
+    version: Option<Version>,
+}
+
+impl LicenseBuilder {
+    /// Require attribution (BY)
+    pub const fn attribution(mut self) -> Self {
+        self.attribution = true;
+        self
+    }
+
+    /// Require adaptations to be shared under the same license (SA)
+    pub const fn share_alike(mut self) -> Self {
+        self.share_alike = true;
+        self
+    }
+
+    /// Forbid commercial use (NC)
+    pub const fn non_commercial(mut self) -> Self {
+        self.non_commercial = true;
+        self
+    }
+
+    /// Forbid sharing adaptations (ND)
+    pub const fn no_derivatives(mut self) -> Self {
+        self.no_derivatives = true;
+        self
+    }
+
+    /// Dedicate the work to the public domain (CC0) instead of licensing it
+    pub const fn public_domain(mut self) -> Self {
+        self.public_domain = true;
+        self
+    }
+
+    /// Choose the version of the license suite, 4.0 by default
+    pub const fn version(mut self, version: Version) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Build the license, validating the combination of clauses
+    ///
+    /// Fails with [`ParseError::InvalidRights`] when the clauses do not form a
+    /// license: ShareAlike with NoDerivatives, clauses without attribution, or
+    /// clauses combined with a public domain dedication. Fails like
+    /// [`License::new`] when the version is not available for the rights.
+    pub const fn build(self) -> Result<License, ParseError> {
+        let rights = if self.public_domain {
+            if self.attribution || self.share_alike || self.non_commercial || self.no_derivatives {
+                return Err(ParseError::InvalidRights);
+            }
+            Rights::Zero
+        } else if !self.attribution {
+            return Err(ParseError::InvalidRights);
+        } else {
+            match (self.non_commercial, self.share_alike, self.no_derivatives) {
+                (false, false, false) => Rights::By,
+                (false, true, false) => Rights::BySa,
+                (false, false, true) => Rights::ByNd,
+                (true, false, false) => Rights::ByNc,
+                (true, true, false) => Rights::ByNcSa,
+                (true, false, true) => Rights::ByNcNd,
+                (_, true, true) => return Err(ParseError::InvalidRights),
+            }
+        };
+        let version = match (self.version, rights) {
+            (Some(version), _) => version,
+            (None, Rights::Zero) => Version::One,
+            (None, _) => Version::Four,
+        };
+        License::new(rights, version)
+    }
+}
+
+impl License {
+    /// Start composing a license from its clauses
+    pub const fn builder() -> LicenseBuilder {
+        LicenseBuilder {
+            attribution: false,
+            share_alike: false,
+            non_commercial: false,
+            no_derivatives: false,
+            public_domain: false,
+            version: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let by = License::builder().attribution();
+        assert_eq!(by.build(), Ok(License::CC_BY_4_0));
+        assert_eq!(by.share_alike().build(), Ok(License::CC_BY_SA_4_0));
+        assert_eq!(
+            by.no_derivatives().version(Version::Three).build(),
+            Ok(License::CC_BY_ND_3_0)
+        );
+        assert_eq!(
+            by.non_commercial().no_derivatives().build(),
+            Ok(License::CC_BY_NC_ND_4_0)
+        );
+        assert_eq!(
+            by.non_commercial().version(Version::TwoFive).build(),
+            Ok(License::CC_BY_NC_2_5)
+        );
+        assert_eq!(
+            License::builder().public_domain().build(),
+            Ok(License::CC0_1_0)
+        );
+        assert_eq!(License::builder(), LicenseBuilder::default());
+    }
+
+    #[test]
+    fn test_invalid_combinations() {
+        assert_eq!(
+            License::builder().non_commercial().build(),
+            Err(ParseError::InvalidRights)
+        );
+        assert_eq!(License::builder().build(), Err(ParseError::InvalidRights));
+        assert_eq!(
+            License::builder().public_domain().attribution().build(),
+            Err(ParseError::InvalidRights)
+        );
+        assert_eq!(
+            License::builder()
+                .attribution()
+                .non_commercial()
+                .share_alike()
+                .no_derivatives()
+                .build(),
+            Err(ParseError::InvalidRights)
+        );
+        assert_eq!(
+            License::builder()
+                .public_domain()
+                .version(Version::Four)
+                .build(),
+            Err(ParseError::InvalidPublicDomainVersion)
+        );
+    }
+}
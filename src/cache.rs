@@ -0,0 +1,147 @@
+use crate::error::ParseError;
+use crate::License;
+use std::collections::HashMap;
+
+/// Parser that remembers the results of recently seen inputs
+///
+/// Real-world feeds tend to repeat the same handful of URLs, so caching by exact
+/// input string skips matching entirely for all but the first occurrence. The
+/// cache holds at most `capacity` entries and evicts the least recently used one
+/// when full; it is intended for small capacities.
+///
+/// # Example
+///
+/// ```rust
+/// use cc_license::CachedParser;
+///
+/// let mut parser = CachedParser::new(16);
+/// for _ in 0..3 {
+///     let license = parser.parse("https://creativecommons.org/licenses/by/4.0/").unwrap();
+///     assert_eq!(license.short(), "CC BY 4.0".to_string());
+/// }
+/// assert_eq!(parser.misses(), 1);
+/// assert_eq!(parser.hits(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CachedParser {
+    capacity: usize,
+    clock: u64,
+    entries: HashMap<String, (Result<License, ParseError>, u64)>,
+    hits: u64,
+    misses: u64,
+}
+
+impl CachedParser {
+    /// Create a cache holding up to `capacity` distinct inputs
+    pub fn new(capacity: usize) -> Self {
+        CachedParser {
+            capacity,
+            clock: 0,
+            entries: HashMap::with_capacity(capacity),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Parse a license URL, reusing the previous result for a repeated input
+    pub fn parse(&mut self, input: &str) -> Result<License, ParseError> {
+        self.clock += 1;
+        if let Some((result, last_used)) = self.entries.get_mut(input) {
+            *last_used = self.clock;
+            self.hits += 1;
+            return *result;
+        }
+        self.misses += 1;
+        let result = License::from_url(input);
+        if self.capacity > 0 {
+            if self.entries.len() >= self.capacity {
+                self.evict();
+            }
+            self.entries.insert(input.to_string(), (result, self.clock));
+        }
+        result
+    }
+
+    fn evict(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, (_, last_used))| *last_used)
+            .map(|(input, _)| input.clone());
+        if let Some(input) = oldest {
+            self.entries.remove(&input);
+        }
+    }
+
+    /// Number of lookups answered from the cache
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Number of lookups that required parsing
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Number of inputs currently cached
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Remove every cached input
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BY: &str = "https://creativecommons.org/licenses/by/4.0/";
+    const BY_SA: &str = "https://creativecommons.org/licenses/by-sa/4.0/";
+    const INVALID: &str = "https://creativecommons.org/licenses/by/5.0/";
+
+    #[test]
+    fn test_parse() {
+        let mut parser = CachedParser::new(4);
+        assert_eq!(parser.parse(BY), License::from_url(BY));
+        assert_eq!(parser.parse(INVALID), Err(ParseError::InvalidVersion));
+        assert_eq!(parser.parse(BY), License::from_url(BY));
+        assert_eq!(parser.parse(INVALID), Err(ParseError::InvalidVersion));
+        assert_eq!(parser.hits(), 2);
+        assert_eq!(parser.misses(), 2);
+        assert_eq!(parser.len(), 2);
+        parser.clear();
+        assert!(parser.is_empty());
+    }
+
+    #[test]
+    fn test_eviction() {
+        let mut parser = CachedParser::new(2);
+        parser.parse(BY).unwrap();
+        parser.parse(BY_SA).unwrap();
+        parser.parse(BY).unwrap();
+        // BY_SA is the least recently used and gets evicted
+        parser.parse(INVALID).unwrap_err();
+        assert_eq!(parser.len(), 2);
+        parser.parse(BY).unwrap();
+        assert_eq!(parser.hits(), 2);
+        parser.parse(BY_SA).unwrap();
+        assert_eq!(parser.misses(), 4);
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        let mut parser = CachedParser::new(0);
+        parser.parse(BY).unwrap();
+        parser.parse(BY).unwrap();
+        assert_eq!(parser.misses(), 2);
+        assert!(parser.is_empty());
+    }
+}
@@ -0,0 +1,140 @@
+use crate::rights::Rights;
+use crate::version::Version;
+use std::fmt;
+
+/// A substantive difference introduced by a new version of the license suite
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Change {
+    /// Credit may be directed to a party designated by the licensor (2.5)
+    AttributionParties,
+    /// Moral rights are addressed explicitly (3.0)
+    MoralRights,
+    /// Collecting society royalties are addressed explicitly (3.0)
+    CollectingSocieties,
+    /// Adaptations may be shared under compatible licenses (3.0)
+    CompatibleLicenses,
+    /// Sui generis database rights are licensed (4.0)
+    DatabaseRights,
+    /// Rights are reinstated if a violation is cured within 30 days (4.0)
+    CurePeriod,
+    /// Attribution may be given in any reasonable manner, such as a link (4.0)
+    FlexibleAttribution,
+    /// Changes must be indicated for every modification (4.0)
+    IndicateChanges,
+    /// A single international license replaces jurisdiction ports (4.0)
+    International,
+}
+
+/// Every change, paired with the version that introduced it, in chronological order
+const CHANGES: &[(Version, Change)] = &[
+    (Version::TwoFive, Change::AttributionParties),
+    (Version::Three, Change::MoralRights),
+    (Version::Three, Change::CollectingSocieties),
+    (Version::Three, Change::CompatibleLicenses),
+    (Version::Four, Change::DatabaseRights),
+    (Version::Four, Change::CurePeriod),
+    (Version::Four, Change::FlexibleAttribution),
+    (Version::Four, Change::IndicateChanges),
+    (Version::Four, Change::International),
+];
+
+impl Change {
+    /// Obtain a one-sentence summary of the change
+    pub fn description(&self) -> &'static str {
+        match self {
+            Change::AttributionParties => "Credit may be given to a party designated by the licensor, such as a publisher or journal.",
+            Change::MoralRights => "Moral rights are waived or not asserted to the extent necessary to exercise the licensed rights.",
+            Change::CollectingSocieties => "The licensor's right to collect royalties through collecting societies is addressed explicitly.",
+            Change::CompatibleLicenses => "Adaptations may be shared under a license approved as compatible by Creative Commons.",
+            Change::DatabaseRights => "Sui generis database rights are licensed along with copyright.",
+            Change::CurePeriod => "Rights lost through a violation are reinstated automatically if it is corrected within 30 days of discovery.",
+            Change::FlexibleAttribution => "Attribution may be given in any reasonable manner for the medium, including by linking to a page with the required information.",
+            Change::IndicateChanges => "Any modification must be indicated, not only adaptations.",
+            Change::International => "A single international license replaces jurisdiction-specific ports and is intended to need no adaptation.",
+        }
+    }
+
+    fn applies_to(&self, rights: Rights) -> bool {
+        match self {
+            Change::AttributionParties | Change::FlexibleAttribution => {
+                rights.requires_attribution()
+            }
+            Change::CompatibleLicenses => rights.requires_share_alike(),
+            Change::IndicateChanges => rights.allows_derivatives(),
+            _ => true,
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+/// List the substantive changes between two versions of a rights family
+///
+/// Returns the changes introduced after `from` up to and including `to`, in
+/// chronological order, skipping those that do not apply to `rights`. The list is
+/// empty when `to` is not newer than `from`.
+///
+/// # Example
+///
+/// ```rust
+/// use cc_license::{changes_between, Change, Rights, Version};
+///
+/// let changes = changes_between(Version::Three, Version::Four, Rights::ByNd);
+/// assert!(changes.contains(&Change::CurePeriod));
+/// assert!(changes.contains(&Change::DatabaseRights));
+/// assert!(!changes.contains(&Change::IndicateChanges));
+/// assert!(changes_between(Version::Four, Version::Three, Rights::By).is_empty());
+/// ```
+pub fn changes_between(from: Version, to: Version, rights: Rights) -> Vec<Change> {
+    CHANGES
+        .iter()
+        .filter(|(since, change)| from < *since && *since <= to && change.applies_to(rights))
+        .map(|(_, change)| *change)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changes_between() {
+        assert_eq!(
+            changes_between(Version::Three, Version::Four, Rights::BySa),
+            vec![
+                Change::DatabaseRights,
+                Change::CurePeriod,
+                Change::FlexibleAttribution,
+                Change::IndicateChanges,
+                Change::International,
+            ]
+        );
+        assert_eq!(
+            changes_between(Version::Two, Version::Three, Rights::BySa),
+            vec![
+                Change::AttributionParties,
+                Change::MoralRights,
+                Change::CollectingSocieties,
+                Change::CompatibleLicenses,
+            ]
+        );
+        assert_eq!(
+            changes_between(Version::TwoFive, Version::Three, Rights::ByNc),
+            vec![Change::MoralRights, Change::CollectingSocieties]
+        );
+        assert_eq!(
+            changes_between(Version::One, Version::Four, Rights::By).len(),
+            CHANGES.len() - 1
+        );
+        assert!(changes_between(Version::Four, Version::Four, Rights::By).is_empty());
+    }
+
+    #[test]
+    fn test_chronological() {
+        assert!(CHANGES.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+    }
+}
@@ -0,0 +1,228 @@
+use crate::error::ParseError;
+use crate::jurisdiction::Jurisdiction;
+use crate::lenient::percent_decode;
+use crate::rights::Rights;
+use crate::table::LICENSES;
+use crate::version::Version;
+use crate::License;
+
+/// Hosts serving the license chooser, besides the `choose/` pages of the main site
+const CHOOSER_HOSTS: &[&str] = &[
+    "chooser.creativecommons.org",
+    "chooser-beta.creativecommons.org",
+];
+
+impl License {
+    /// Parse the license selected in a link to the Creative Commons license
+    /// chooser
+    ///
+    /// Accepts links to `chooser.creativecommons.org`,
+    /// `chooser-beta.creativecommons.org` and the `choose/` pages of
+    /// `creativecommons.org`, whose query names the license either with the
+    /// `license_code`, `version` and `jurisdiction` parameters, or with the answers
+    /// of the original chooser, `field_commercial` and `field_derivatives`. The
+    /// latest version of the license is assumed when none is given.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let url = "https://chooser-beta.creativecommons.org/?license_code=by-nc-sa&version=4.0&lang=en";
+    /// assert_eq!(License::from_chooser_url(url)?, License::CC_BY_NC_SA_4_0);
+    /// let url = "https://creativecommons.org/choose/results-one?field_commercial=n&field_derivatives=sa";
+    /// assert_eq!(License::from_chooser_url(url)?, License::CC_BY_NC_SA_4_0);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn from_chooser_url(url: &str) -> Result<Self, ParseError> {
+        let query = chooser_query(url).ok_or(ParseError::InvalidUrl)?;
+        let parameter = |name: &str| {
+            query
+                .split('&')
+                .filter_map(|parameter| parameter.split_once('='))
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| percent_decode(value).to_ascii_lowercase())
+                .filter(|value| !value.is_empty())
+        };
+        let rights = match parameter("license_code") {
+            Some(code) => {
+                let slug = if code == "cc0" { "zero" } else { &code };
+                Rights::from_slug(slug.as_bytes()).ok_or(ParseError::InvalidRights)?
+            }
+            None => {
+                let commercial = parameter("field_commercial");
+                let derivatives = parameter("field_derivatives");
+                if commercial.is_none() && derivatives.is_none() {
+                    return Err(ParseError::InvalidUrl);
+                }
+                let mut slug = String::from("by");
+                match commercial.as_deref() {
+                    None | Some("y") => {}
+                    Some("n") => slug.push_str("-nc"),
+                    Some(_) => return Err(ParseError::InvalidRights),
+                }
+                match derivatives.as_deref() {
+                    None | Some("y") => {}
+                    Some("n") => slug.push_str("-nd"),
+                    Some("sa") => slug.push_str("-sa"),
+                    Some(_) => return Err(ParseError::InvalidRights),
+                }
+                Rights::from_slug(slug.as_bytes()).ok_or(ParseError::InvalidRights)?
+            }
+        };
+        let version = match parameter("version") {
+            Some(version) => {
+                Version::from_slug(version.as_bytes()).ok_or(ParseError::InvalidVersion)?
+            }
+            None => latest_version(rights).ok_or(ParseError::InvalidVersion)?,
+        };
+        match parameter("jurisdiction").or_else(|| parameter("field_jurisdiction")) {
+            Some(slug) => {
+                let jurisdiction = Jurisdiction::from_slug(slug.as_bytes())
+                    .ok_or(ParseError::InvalidJurisdiction)?;
+                License::ported(rights, version, jurisdiction)
+            }
+            None => License::new(rights, version),
+        }
+    }
+}
+
+/// The query of a link to the license chooser, or `None` for other URLs
+fn chooser_query(url: &str) -> Option<&str> {
+    let (scheme, rest) = url.trim().split_once("://")?;
+    if !scheme.eq_ignore_ascii_case("https") && !scheme.eq_ignore_ascii_case("http") {
+        return None;
+    }
+    let (address, query) = rest.split_once('?')?;
+    let query = query.split('#').next().unwrap_or(query);
+    let (host, path) = address.split_once('/').unwrap_or((address, ""));
+    let host = host.to_ascii_lowercase();
+    let is_chooser = CHOOSER_HOSTS.contains(&host.as_str())
+        || (matches!(
+            host.as_str(),
+            "creativecommons.org" | "www.creativecommons.org"
+        ) && (path == "choose" || path.starts_with("choose/")));
+    is_chooser.then_some(query)
+}
+
+/// The latest version of the unported licenses with the rights
+fn latest_version(rights: Rights) -> Option<Version> {
+    LICENSES
+        .iter()
+        .filter(|license| license.rights_enum() == rights)
+        .map(|license| license.version_enum())
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_license_code() {
+        for (url, license) in [
+            (
+                "https://chooser-beta.creativecommons.org/?license_code=by&version=4.0",
+                License::CC_BY_4_0,
+            ),
+            (
+                "https://chooser.creativecommons.org/?lang=en&license_code=by-nd",
+                License::CC_BY_ND_4_0,
+            ),
+            (
+                "https://creativecommons.org/choose/results-one?license_code=by-sa&jurisdiction=&version=3.0&lang=en",
+                License::CC_BY_SA_3_0,
+            ),
+            (
+                "https://creativecommons.org/choose/results-one?license_code=cc0",
+                License::CC0_1_0,
+            ),
+            (
+                "https://creativecommons.org/choose/results-one?license_code=by-nc%2Dsa#results",
+                License::CC_BY_NC_SA_4_0,
+            ),
+        ] {
+            assert_eq!(License::from_chooser_url(url), Ok(license), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_ported() {
+        assert_eq!(
+            License::from_chooser_url(
+                "http://creativecommons.org/choose/results-one?license_code=by&version=3.0&jurisdiction=de"
+            ),
+            License::ported(Rights::By, Version::Three, Jurisdiction::Germany)
+        );
+        assert_eq!(
+            License::from_chooser_url(
+                "http://creativecommons.org/choose/results-one?field_commercial=y&field_derivatives=n&field_jurisdiction=es&version=2.5"
+            ),
+            License::ported(Rights::ByNd, Version::TwoFive, Jurisdiction::Spain)
+        );
+    }
+
+    #[test]
+    fn test_answers() {
+        for (commercial, derivatives, license) in [
+            ("y", "y", License::CC_BY_4_0),
+            ("y", "sa", License::CC_BY_SA_4_0),
+            ("n", "n", License::CC_BY_NC_ND_4_0),
+            ("n", "y", License::CC_BY_NC_4_0),
+        ] {
+            let url = format!(
+                "https://creativecommons.org/choose/results-one?q_1=2&field_commercial={}&field_derivatives={}",
+                commercial, derivatives
+            );
+            assert_eq!(License::from_chooser_url(&url), Ok(license), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        for (url, error) in [
+            (
+                "https://creativecommons.org/licenses/by/4.0/",
+                ParseError::InvalidUrl,
+            ),
+            (
+                "https://chooser.creativecommons.org/",
+                ParseError::InvalidUrl,
+            ),
+            (
+                "https://chooser.creativecommons.org/?lang=en",
+                ParseError::InvalidUrl,
+            ),
+            (
+                "https://example.org/choose/?license_code=by",
+                ParseError::InvalidUrl,
+            ),
+            (
+                "https://creativecommons.org/chooser?license_code=by",
+                ParseError::InvalidUrl,
+            ),
+            (
+                "https://chooser.creativecommons.org/?license_code=by-xx",
+                ParseError::InvalidRights,
+            ),
+            (
+                "https://chooser.creativecommons.org/?license_code=by&version=5.0",
+                ParseError::InvalidVersion,
+            ),
+            (
+                "https://chooser.creativecommons.org/?field_commercial=maybe",
+                ParseError::InvalidRights,
+            ),
+            (
+                "https://chooser.creativecommons.org/?license_code=by&version=4.0&jurisdiction=de",
+                ParseError::InvalidJurisdiction,
+            ),
+        ] {
+            assert_eq!(License::from_chooser_url(url), Err(error), "{}", url);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::error::ParseError;
+use crate::table;
+use crate::License;
+
+impl License {
+    /// Obtain the stable numeric code of the license, if it is unported
+    ///
+    /// Every unported license has a distinct code that is never changed or reused once
+    /// assigned, so codes can be stored as a single byte, for instance in a
+    /// database column, and read back with [`License::try_from`]. Licenses added
+    /// in later versions of the crate take the next unassigned code, and code 0 is
+    /// never assigned. Licenses ported to a jurisdiction are too numerous for a
+    /// single byte and have no code. The codes are:
+    ///
+    /// | Rights       | 1.0 | 2.0 | 2.5 | 3.0 | 4.0 |
+    /// |--------------|-----|-----|-----|-----|-----|
+    /// | CC BY        | 1   | 2   | 3   | 4   | 5   |
+    /// | CC BY-SA     | 6   | 7   | 8   | 9   | 10  |
+    /// | CC BY-ND     | 11  | 12  | 13  | 14  | 15  |
+    /// | CC BY-NC     | 16  | 17  | 18  | 19  | 20  |
+    /// | CC BY-NC-SA  | 21  | 22  | 23  | 24  | 25  |
+    /// | CC BY-NC-ND  | 26  | 27  | 28  | 29  | 30  |
+    /// | CC0          | 31  |     |     |     |     |
+    /// | PDDC         | 32  |     |     |     |     |
+    /// | PDM          | 33  |     |     |     |     |
+    /// | Sampling     | 34  |     |     |     |     |
+    /// | Sampling+    | 35  |     |     |     |     |
+    /// | NC-Sampling+ | 36  |     |     |     |     |
+    /// | DevNations   |     | 37  |     |     |     |
+    /// | CC SA        | 38  |     |     |     |     |
+    /// | CC ND        | 39  |     |     |     |     |
+    /// | CC NC        | 40  |     |     |     |     |
+    /// | CC NC-SA     | 41  |     |     |     |     |
+    /// | CC ND-NC     | 42  |     |     |     |     |
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let code = License::CC_BY_NC_SA_4_0.code().unwrap();
+    /// assert_eq!(code, 25);
+    /// assert_eq!(License::try_from(code)?, License::CC_BY_NC_SA_4_0);
+    /// assert_eq!(License::try_from(0), Err(ParseError::InvalidCode));
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub const fn code(&self) -> Option<u8> {
+        table::canonical_code(self)
+    }
+}
+
+impl TryFrom<u8> for License {
+    type Error = ParseError;
+
+    /// Obtain the license with the given numeric code, see [`License::code`]
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        table::from_code(code).ok_or(ParseError::InvalidCode)
+    }
+}
+
+impl TryFrom<License> for u8 {
+    type Error = ParseError;
+
+    /// Obtain the numeric code of the license, see [`License::code`]
+    fn try_from(license: License) -> Result<Self, Self::Error> {
+        license.code().ok_or(ParseError::InvalidCode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::LICENSES;
+
+    #[test]
+    fn test_code_round_trip() {
+        for license in LICENSES {
+            assert_eq!(License::try_from(license.code().unwrap()), Ok(*license));
+            assert_eq!(u8::try_from(*license), Ok(license.code().unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_documented_codes() {
+        assert_eq!(License::CC_BY_1_0.code(), Some(1));
+        assert_eq!(License::CC_BY_SA_2_5.code(), Some(8));
+        assert_eq!(License::CC_BY_NC_ND_4_0.code(), Some(30));
+        assert_eq!(License::CC0_1_0.code(), Some(31));
+        assert_eq!(License::PDDC.code(), Some(32));
+        assert_eq!(License::PDM_1_0.code(), Some(33));
+        assert_eq!(License::CC_NC_SAMPLING_PLUS_1_0.code(), Some(36));
+        assert_eq!(License::CC_DEVNATIONS_2_0.code(), Some(37));
+        assert_eq!(License::CC_ND_NC_1_0.code(), Some(42));
+        assert_eq!(License::try_from(43), Err(ParseError::InvalidCode));
+        assert_eq!(License::try_from(u8::MAX), Err(ParseError::InvalidCode));
+    }
+}
@@ -0,0 +1,200 @@
+use crate::error::ParseError;
+use crate::jurisdiction::Jurisdiction;
+use crate::table::LICENSES;
+use crate::License;
+use std::collections::BTreeMap;
+
+const MESSY: &str = include_str!("../corpus/messy.tsv");
+
+/// A license string found in real-world metadata
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct CorpusEntry {
+    /// Kind of metadata the string was taken from, e.g. "crossref" or "onix"
+    pub source: &'static str,
+    /// The string as found, including surrounding noise
+    pub input: &'static str,
+    /// License a human reader identifies in the string, if any
+    pub expected: Option<License>,
+}
+
+/// Every entry of the bundled corpus of messy license strings
+///
+/// The corpus is a curated, anonymised selection of license strings as they
+/// appear in Crossref, OAI-PMH, ONIX and Internet Archive metadata, including
+/// strings that name no license at all.
+pub fn corpus() -> Vec<CorpusEntry> {
+    MESSY
+        .lines()
+        .filter(|line| !line.starts_with('#') && !line.is_empty())
+        .map(|line| {
+            let mut fields = line.splitn(3, '\t');
+            let (Some(source), Some(expected), Some(input)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                panic!("malformed corpus line: {}", line);
+            };
+            let expected = (expected != "-").then(|| {
+                from_canonical_key(expected)
+                    .unwrap_or_else(|| panic!("unknown license in corpus: {}", expected))
+            });
+            CorpusEntry {
+                source,
+                input,
+                expected,
+            }
+        })
+        .collect()
+}
+
+/// Look up a license by its canonical key, e.g. "by-sa/2.0/uk"
+fn from_canonical_key(key: &str) -> Option<License> {
+    let (unported, jurisdiction) = match key.match_indices('/').nth(1) {
+        Some((i, _)) => (&key[..i], Some(&key[i + 1..])),
+        None => (key, None),
+    };
+    let license = LICENSES
+        .iter()
+        .find(|license| license.canonical_key() == unported)?;
+    match jurisdiction {
+        Some(slug) => {
+            let jurisdiction = Jurisdiction::from_slug(slug.as_bytes())?;
+            License::ported(license.rights_enum(), license.version_enum(), jurisdiction).ok()
+        }
+        None => Some(*license),
+    }
+}
+
+/// Recognition counts for a group of corpus entries
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct Coverage {
+    /// Number of entries naming a license
+    pub licensed: usize,
+    /// Number of entries parsed into the license they name
+    pub recognised: usize,
+    /// Number of entries parsed into a license they do not name
+    pub misrecognised: usize,
+}
+
+impl Coverage {
+    /// Share of the entries naming a license that were recognised, from 0 to 1
+    pub fn ratio(&self) -> f64 {
+        if self.licensed == 0 {
+            return 1.0;
+        }
+        self.recognised as f64 / self.licensed as f64
+    }
+
+    fn record(&mut self, entry: &CorpusEntry, result: Result<License, ParseError>) {
+        if entry.expected.is_some() {
+            self.licensed += 1;
+        }
+        match result {
+            Ok(license) if Some(license) == entry.expected => self.recognised += 1,
+            Ok(_) => self.misrecognised += 1,
+            Err(_) => {}
+        }
+    }
+}
+
+/// Outcome of running a parser over the bundled corpus
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct CoverageReport {
+    total: Coverage,
+    sources: BTreeMap<&'static str, Coverage>,
+    misses: Vec<CorpusEntry>,
+}
+
+impl CoverageReport {
+    /// Counts over the whole corpus
+    pub fn total(&self) -> Coverage {
+        self.total
+    }
+
+    /// Counts over the entries taken from one source
+    pub fn source(&self, source: &str) -> Option<Coverage> {
+        self.sources.get(source).copied()
+    }
+
+    /// Counts per source, ordered by source name
+    pub fn sources(&self) -> impl Iterator<Item = (&'static str, Coverage)> + '_ {
+        self.sources
+            .iter()
+            .map(|(source, coverage)| (*source, *coverage))
+    }
+
+    /// Entries that were not recognised as the license they name, or that were
+    /// recognised as a license when they name none
+    pub fn misses(&self) -> &[CorpusEntry] {
+        &self.misses
+    }
+}
+
+/// Run a parser over the bundled corpus and report how much of it is recognised
+///
+/// # Example
+///
+/// ```rust
+/// use cc_license::{corpus_coverage, License, ParseError, ParseOptions};
+///
+/// let options = ParseOptions::new();
+/// let report = corpus_coverage(|input| License::parse_with(input, &options).map_err(ParseError::from));
+/// assert_eq!(report.total().misrecognised, 0);
+/// assert!(report.total().ratio() > 0.0);
+/// println!("{:.0}% of Crossref strings recognised", report.source("crossref").unwrap().ratio() * 100.0);
+/// ```
+pub fn corpus_coverage<F>(parser: F) -> CoverageReport
+where
+    F: Fn(&str) -> Result<License, ParseError>,
+{
+    let mut report = CoverageReport::default();
+    for entry in corpus() {
+        let result = parser(entry.input);
+        if result.ok() != entry.expected {
+            report.misses.push(entry);
+        }
+        report.total.record(&entry, result);
+        report
+            .sources
+            .entry(entry.source)
+            .or_default()
+            .record(&entry, result);
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corpus() {
+        let corpus = corpus();
+        assert!(corpus.len() > 40);
+        assert!(corpus.iter().any(|entry| entry.expected.is_none()));
+        for source in ["crossref", "oai-pmh", "onix", "internet-archive"] {
+            assert!(corpus.iter().any(|entry| entry.source == source));
+        }
+    }
+
+    #[test]
+    fn test_corpus_coverage() {
+        let report = corpus_coverage(License::from_url);
+        let total = report.total();
+        assert_eq!(total.misrecognised, 0);
+        assert!(total.recognised > 0 && total.recognised < total.licensed);
+        assert_eq!(
+            report.sources().map(|(_, c)| c.licensed).sum::<usize>(),
+            total.licensed
+        );
+        assert_eq!(
+            report.misses().len(),
+            total.licensed - total.recognised + total.misrecognised
+        );
+
+        let everything = corpus_coverage(|_| Ok(LICENSES[0]));
+        assert!(everything.total().misrecognised > 0);
+        assert!(corpus_coverage(|_| Err(ParseError::InvalidUrl))
+            .source("onix")
+            .is_some_and(|onix| onix.recognised == 0));
+    }
+}
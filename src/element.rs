@@ -0,0 +1,326 @@
+use crate::error::ParseError;
+use crate::rights::Rights;
+use crate::version::Version;
+use crate::License;
+use std::fmt;
+use std::ops::{BitAnd, BitOr, Not, Sub};
+
+/// A clause of a Creative Commons license
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum Element {
+    Attribution,
+    NonCommercial,
+    NoDerivatives,
+    ShareAlike,
+}
+
+impl Element {
+    /// Obtain the abbreviation used in license names, e.g. "NC"
+    pub const fn abbreviation(&self) -> &'static str {
+        match self {
+            Element::Attribution => "BY",
+            Element::NonCommercial => "NC",
+            Element::NoDerivatives => "ND",
+            Element::ShareAlike => "SA",
+        }
+    }
+}
+
+impl From<Element> for ElementFlags {
+    fn from(element: Element) -> Self {
+        match element {
+            Element::Attribution => ElementFlags::ATTRIBUTION,
+            Element::NonCommercial => ElementFlags::NON_COMMERCIAL,
+            Element::NoDerivatives => ElementFlags::NO_DERIVATIVES,
+            Element::ShareAlike => ElementFlags::SHARE_ALIKE,
+        }
+    }
+}
+
+/// Set of license clauses packed into a single byte
+///
+/// # Example
+///
+/// ```rust
+/// use cc_license::{ElementFlags, License};
+///
+/// // every license allowing commercial use
+/// let commercial: Vec<_> = License::all()
+///     .iter()
+///     .filter(|license| !license.element_bits().contains(ElementFlags::NON_COMMERCIAL))
+///     .collect();
+/// assert!(commercial.contains(&&License::CC_BY_SA_4_0));
+/// assert!(!commercial.contains(&&License::CC_BY_NC_4_0));
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct ElementFlags(u8);
+
+impl ElementFlags {
+    /// No clauses, as for public domain tools
+    pub const EMPTY: ElementFlags = ElementFlags(0);
+    /// [`Element::Attribution`]
+    pub const ATTRIBUTION: ElementFlags = ElementFlags(1);
+    /// [`Element::NonCommercial`]
+    pub const NON_COMMERCIAL: ElementFlags = ElementFlags(1 << 1);
+    /// [`Element::NoDerivatives`]
+    pub const NO_DERIVATIVES: ElementFlags = ElementFlags(1 << 2);
+    /// [`Element::ShareAlike`]
+    pub const SHARE_ALIKE: ElementFlags = ElementFlags(1 << 3);
+    /// Every clause
+    pub const ALL: ElementFlags = ElementFlags(0b1111);
+
+    /// Obtain the raw bits of the set
+    pub const fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// Create a set from raw bits, returning `None` if an unknown bit is set
+    pub const fn from_bits(bits: u8) -> Option<Self> {
+        if bits & !ElementFlags::ALL.0 != 0 {
+            return None;
+        }
+        Some(ElementFlags(bits))
+    }
+
+    /// Whether every clause of `other` is in the set
+    pub const fn contains(&self, other: ElementFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether any clause of `other` is in the set
+    pub const fn intersects(&self, other: ElementFlags) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// Whether the set has no clauses
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Add the clauses of `other` to the set
+    pub fn insert(&mut self, other: ElementFlags) {
+        self.0 |= other.0;
+    }
+
+    /// Remove the clauses of `other` from the set
+    pub fn remove(&mut self, other: ElementFlags) {
+        self.0 &= !other.0;
+    }
+}
+
+impl BitOr for ElementFlags {
+    type Output = ElementFlags;
+
+    fn bitor(self, other: ElementFlags) -> ElementFlags {
+        ElementFlags(self.0 | other.0)
+    }
+}
+
+impl BitAnd for ElementFlags {
+    type Output = ElementFlags;
+
+    fn bitand(self, other: ElementFlags) -> ElementFlags {
+        ElementFlags(self.0 & other.0)
+    }
+}
+
+impl Sub for ElementFlags {
+    type Output = ElementFlags;
+
+    fn sub(self, other: ElementFlags) -> ElementFlags {
+        ElementFlags(self.0 & !other.0)
+    }
+}
+
+impl Not for ElementFlags {
+    type Output = ElementFlags;
+
+    fn not(self) -> ElementFlags {
+        ElementFlags(!self.0 & ElementFlags::ALL.0)
+    }
+}
+
+impl FromIterator<Element> for ElementFlags {
+    fn from_iter<I: IntoIterator<Item = Element>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(ElementFlags::EMPTY, |flags, element| flags | element.into())
+    }
+}
+
+impl fmt::Display for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let element = match self {
+            Element::Attribution => "Attribution",
+            Element::NonCommercial => "NonCommercial",
+            Element::NoDerivatives => "NoDerivatives",
+            Element::ShareAlike => "ShareAlike",
+        };
+        write!(f, "{}", element)
+    }
+}
+
+impl Rights {
+    pub(crate) const fn elements(&self) -> &'static [Element] {
+        use Element::*;
+        match self {
+            Rights::By => &[Attribution],
+            Rights::BySa => &[Attribution, ShareAlike],
+            Rights::ByNd => &[Attribution, NoDerivatives],
+            Rights::ByNc => &[Attribution, NonCommercial],
+            Rights::ByNcSa => &[Attribution, NonCommercial, ShareAlike],
+            Rights::ByNcNd => &[Attribution, NonCommercial, NoDerivatives],
+            Rights::Zero | Rights::Mark | Rights::Certification => &[],
+            Rights::Sampling
+            | Rights::SamplingPlus
+            | Rights::NcSamplingPlus
+            | Rights::DevNations => &[],
+            Rights::Sa => &[ShareAlike],
+            Rights::Nd => &[NoDerivatives],
+            Rights::Nc => &[NonCommercial],
+            Rights::NcSa => &[NonCommercial, ShareAlike],
+            Rights::NdNc => &[NoDerivatives, NonCommercial],
+        }
+    }
+}
+
+impl License {
+    /// Obtain the clauses of the license, in the order they appear in its name
+    ///
+    /// Public domain tools have no clauses, and neither do the retired Sampling
+    /// and Developing Nations licenses, whose terms predate the four clauses.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{Element, License};
+    ///
+    /// assert_eq!(
+    ///     License::CC_BY_NC_SA_4_0.elements(),
+    ///     &[Element::Attribution, Element::NonCommercial, Element::ShareAlike]
+    /// );
+    /// assert!(!License::CC_BY_4_0.elements().contains(&Element::NonCommercial));
+    /// assert!(License::CC0_1_0.elements().is_empty());
+    /// ```
+    pub const fn elements(&self) -> &'static [Element] {
+        self.rights_enum().elements()
+    }
+
+    /// Obtain the clauses of the license as a set of flags
+    pub fn element_bits(&self) -> ElementFlags {
+        self.elements().iter().copied().collect()
+    }
+
+    /// Create a license from its clauses and version
+    ///
+    /// An empty set of clauses stands for CC0. Fails like [`LicenseBuilder::build`]
+    /// for combinations of clauses that do not form a license.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{ElementFlags, License, ParseError, Version};
+    ///
+    /// let flags = ElementFlags::ATTRIBUTION | ElementFlags::NO_DERIVATIVES;
+    /// assert_eq!(License::from_element_bits(flags, Version::Four)?, License::CC_BY_ND_4_0);
+    /// assert_eq!(
+    ///     License::from_element_bits(ElementFlags::SHARE_ALIKE, Version::Four),
+    ///     Err(ParseError::InvalidRights)
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// [`LicenseBuilder::build`]: crate::LicenseBuilder::build
+    pub fn from_element_bits(flags: ElementFlags, version: Version) -> Result<Self, ParseError> {
+        let mut builder = License::builder().version(version);
+        if flags.is_empty() {
+            builder = builder.public_domain();
+        }
+        if flags.contains(ElementFlags::ATTRIBUTION) {
+            builder = builder.attribution();
+        }
+        if flags.contains(ElementFlags::NON_COMMERCIAL) {
+            builder = builder.non_commercial();
+        }
+        if flags.contains(ElementFlags::NO_DERIVATIVES) {
+            builder = builder.no_derivatives();
+        }
+        if flags.contains(ElementFlags::SHARE_ALIKE) {
+            builder = builder.share_alike();
+        }
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_elements_match_short_name() {
+        for license in License::all() {
+            let rights = license.rights_enum().to_string();
+            let abbreviations: Vec<_> = license
+                .elements()
+                .iter()
+                .map(Element::abbreviation)
+                .collect();
+            if abbreviations.is_empty() {
+                assert!(!rights.contains("BY"));
+            } else {
+                assert_eq!(rights, format!("CC {}", abbreviations.join("-")));
+            }
+        }
+    }
+
+    #[test]
+    fn test_element_bits_round_trip() {
+        for license in License::all() {
+            if license.rights_enum() != Rights::Zero
+                && !license.elements().contains(&Element::Attribution)
+            {
+                continue;
+            }
+            assert_eq!(
+                License::from_element_bits(license.element_bits(), license.version_enum()),
+                Ok(*license)
+            );
+        }
+        assert_eq!(License::PDDC.element_bits(), ElementFlags::EMPTY);
+    }
+
+    #[test]
+    fn test_flags() {
+        let mut flags = ElementFlags::ATTRIBUTION | ElementFlags::SHARE_ALIKE;
+        assert_eq!(flags.bits(), 0b1001);
+        assert!(flags.contains(ElementFlags::SHARE_ALIKE));
+        assert!(!flags.contains(ElementFlags::ALL));
+        assert!(flags.intersects(ElementFlags::ALL));
+        flags.insert(ElementFlags::NON_COMMERCIAL);
+        flags.remove(ElementFlags::SHARE_ALIKE);
+        assert_eq!(
+            flags,
+            ElementFlags::ATTRIBUTION | ElementFlags::NON_COMMERCIAL
+        );
+        assert_eq!(
+            !flags,
+            ElementFlags::NO_DERIVATIVES | ElementFlags::SHARE_ALIKE
+        );
+        assert_eq!(
+            flags - ElementFlags::ATTRIBUTION,
+            ElementFlags::NON_COMMERCIAL
+        );
+        assert_eq!(flags & ElementFlags::ATTRIBUTION, ElementFlags::ATTRIBUTION);
+        assert_eq!(ElementFlags::from_bits(0b1111), Some(ElementFlags::ALL));
+        assert_eq!(ElementFlags::from_bits(0b10000), None);
+        assert!(ElementFlags::default().is_empty());
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(
+            format!("{}", Element::Attribution),
+            "Attribution".to_string()
+        );
+        assert_eq!(format!("{}", Element::ShareAlike), "ShareAlike".to_string());
+    }
+}
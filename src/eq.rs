@@ -0,0 +1,172 @@
+use crate::License;
+use std::fmt;
+
+/// Sink comparing everything written to it against an expected string, ignoring
+/// ASCII case
+///
+/// The expected string is given in parts so that a suffix can be supplied without
+/// allocating, and the first `skip` bytes written are not compared at all.
+struct Matcher<'a> {
+    parts: [&'a [u8]; 2],
+    skip: usize,
+}
+
+impl<'a> Matcher<'a> {
+    fn new(parts: [&'a [u8]; 2], skip: usize) -> Self {
+        Matcher { parts, skip }
+    }
+
+    /// Whether the written string matched the expected one in full
+    fn matches(write: fmt::Result, matcher: Matcher) -> bool {
+        write.is_ok() && matcher.parts.iter().all(|part| part.is_empty())
+    }
+}
+
+impl fmt::Write for Matcher<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for &byte in s.as_bytes() {
+            if self.skip > 0 {
+                self.skip -= 1;
+                continue;
+            }
+            let part = self
+                .parts
+                .iter_mut()
+                .find(|part| !part.is_empty())
+                .ok_or(fmt::Error)?;
+            if !part[0].eq_ignore_ascii_case(&byte) {
+                return Err(fmt::Error);
+            }
+            *part = &part[1..];
+        }
+        Ok(())
+    }
+}
+
+impl License {
+    /// Whether the string is the canonical URL of the license, ignoring the scheme,
+    /// a missing trailing slash and case
+    fn eq_url(&self, s: &str) -> bool {
+        let rest = match s.split_once("://") {
+            Some((scheme, rest))
+                if scheme.eq_ignore_ascii_case("https") || scheme.eq_ignore_ascii_case("http") =>
+            {
+                rest
+            }
+            _ => return false,
+        };
+        let rest = rest.strip_suffix('/').unwrap_or(rest);
+        let mut matcher = Matcher::new([rest.as_bytes(), b"/"], "https://".len());
+        let write = self.write_url_to(&mut matcher);
+        Matcher::matches(write, matcher)
+    }
+
+    fn eq_spdx_id(&self, s: &str) -> bool {
+        if !self.is_on_spdx_list() {
+            return false;
+        }
+        let mut matcher = Matcher::new([s.as_bytes(), b""], 0);
+        let write = self.write_spdx_id_to(&mut matcher);
+        Matcher::matches(write, matcher)
+    }
+
+    fn eq_short(&self, s: &str) -> bool {
+        let mut matcher = Matcher::new([s.as_bytes(), b""], 0);
+        let write = self.write_short_to(&mut matcher);
+        Matcher::matches(write, matcher)
+    }
+}
+
+impl PartialEq<str> for License {
+    /// Compare the license with its URL, SPDX identifier or short name
+    ///
+    /// The comparison ignores ASCII case, accepts both `http` and `https`, and does
+    /// not require the trailing slash of URLs. No intermediate strings are
+    /// allocated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::License;
+    ///
+    /// let license = License::CC_BY_4_0;
+    /// assert!(license == "https://creativecommons.org/licenses/by/4.0/");
+    /// assert!(license == "HTTP://creativecommons.org/licenses/BY/4.0");
+    /// assert!(license == "CC-BY-4.0");
+    /// assert!(license == "cc by 4.0");
+    /// assert!(license != "CC BY-SA 4.0");
+    /// ```
+    fn eq(&self, other: &str) -> bool {
+        self.eq_url(other) || self.eq_spdx_id(other) || self.eq_short(other)
+    }
+}
+
+impl PartialEq<&str> for License {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl PartialEq<License> for str {
+    fn eq(&self, other: &License) -> bool {
+        *other == *self
+    }
+}
+
+impl PartialEq<License> for &str {
+    fn eq(&self, other: &License) -> bool {
+        *other == **self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eq_forms() {
+        for license in License::all() {
+            let forms = license.canonical_forms();
+            assert_eq!(*license, forms.url.as_str());
+            if let Some(spdx_id) = &forms.spdx_id {
+                assert_eq!(*license, spdx_id.as_str());
+            }
+            assert_eq!(*license, forms.short.as_str());
+            assert_eq!(forms.url.as_str(), *license);
+            for other in License::all().iter().filter(|other| *other != license) {
+                assert_ne!(*other, forms.url.as_str());
+                if let Some(spdx_id) = &forms.spdx_id {
+                    assert_ne!(*other, spdx_id.as_str());
+                }
+                assert_ne!(*other, forms.short.as_str());
+            }
+        }
+    }
+
+    #[test]
+    fn test_eq_url_normalisation() {
+        let license = License::CC0_1_0;
+        assert_eq!(license, "http://creativecommons.org/publicdomain/zero/1.0/");
+        assert_eq!(license, "https://CreativeCommons.org/publicdomain/zero/1.0");
+        assert_ne!(
+            license,
+            "https://creativecommons.org/publicdomain/zero/1.0//"
+        );
+        assert_ne!(license, "ftp://creativecommons.org/publicdomain/zero/1.0/");
+        assert_ne!(license, "creativecommons.org/publicdomain/zero/1.0/");
+        assert_ne!(
+            license,
+            "https://creativecommons.org/publicdomain/zero/1.0/legalcode"
+        );
+        assert_ne!(license, "https://creativecommons.org/publicdomain/zero/");
+        assert_ne!(license, "");
+    }
+
+    #[test]
+    fn test_eq_str() {
+        let id = String::from("cc-by-nc-sa-2.5");
+        assert_eq!(License::CC_BY_NC_SA_2_5, *id.as_str());
+        assert_eq!(*id.as_str(), License::CC_BY_NC_SA_2_5);
+        assert_ne!(License::CC_BY_NC_SA_2_5, "CC-BY-NC-SA-2.5-DE");
+    }
+}
@@ -1,33 +1,240 @@
-use std::error::Error;
+use std::error::Error as StdError;
 use std::fmt;
+use std::io;
 
 macro_rules! errors {
-    ($($name: ident => $description: expr,)+) => {
+    ($($name: ident => $code: expr, $description: expr,)+) => {
         /// Errors that can occur during parsing.
-        #[derive(PartialEq, Eq, Clone, Copy, Debug)]
+        ///
+        /// New variants may be added in minor releases, so matches on this enum
+        /// need a wildcard arm. Each variant has a stable [code](ParseError::code)
+        /// suitable for error responses and telemetry.
+        #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+        #[non_exhaustive]
         pub enum ParseError {
             $(
                 $name,
             )+
         }
 
-        impl fmt::Display for ParseError {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl ParseError {
+            /// Every error, in order of code
+            #[cfg(test)]
+            pub(crate) const ALL: &'static [ParseError] = &[$(ParseError::$name,)+];
+
+            /// Obtain the stable code of the error
+            ///
+            /// Codes have the form `CC_E` followed by three digits. A code is never
+            /// changed or reused once assigned, so it is safe to reference from
+            /// telemetry, documentation and consumers in other languages.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// use cc_license::{License, ParseError};
+            ///
+            /// let error = License::from_url("https://creativecommons.org/licenses/by/5.0/").unwrap_err();
+            /// assert_eq!(error, ParseError::InvalidVersion);
+            /// assert_eq!(error.code(), "CC_E003");
+            /// ```
+            pub const fn code(&self) -> &'static str {
+                match *self {
+                    $(
+                        ParseError::$name => $code,
+                    )+
+                }
+            }
+
+            /// Look up the error with the given stable code
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// use cc_license::ParseError;
+            ///
+            /// assert_eq!(ParseError::from_code("CC_E003"), Some(ParseError::InvalidVersion));
+            /// assert_eq!(ParseError::from_code("CC_E999"), None);
+            /// ```
+            pub fn from_code(code: &str) -> Option<ParseError> {
+                match code {
+                    $(
+                        $code => Some(ParseError::$name),
+                    )+
+                    _ => None,
+                }
+            }
+
+            /// Obtain a static description of the error
+            pub const fn description(&self) -> &'static str {
                 match *self {
                     $(
-                        ParseError::$name => fmt.write_str($description),
+                        ParseError::$name => $description,
                     )+
                 }
             }
         }
+
+        impl fmt::Display for ParseError {
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt.write_str(self.description())
+            }
+        }
     }
 }
 
-impl Error for ParseError {}
+impl StdError for ParseError {}
+
+/// Failure of an operation that involves more than parsing, such as reading the
+/// input, keeping the underlying cause as its [source](StdError::source)
+///
+/// # Example
+///
+/// ```rust
+/// use cc_license::{Error, License, ParseError};
+/// use std::error::Error as _;
+///
+/// let error = License::from_reader("All rights reserved.".as_bytes()).unwrap_err();
+/// assert_eq!(error.parse_error(), Some(ParseError::NotFound));
+/// assert_eq!(error.to_string(), "Failed to parse a license");
+/// assert_eq!(error.source().unwrap().to_string(), "No license found");
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The input was read but holds no valid license
+    Parse(ParseError),
+    /// The input could not be read
+    Io(io::Error),
+}
+
+impl Error {
+    /// The parsing error, if parsing is what failed
+    pub fn parse_error(&self) -> Option<ParseError> {
+        match self {
+            Error::Parse(e) => Some(*e),
+            Error::Io(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(_) => f.write_str("Failed to parse a license"),
+            Error::Io(_) => f.write_str("Failed to read the input"),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Error::Parse(e) => Some(e),
+            Error::Io(e) => Some(e),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
 
 errors! {
-    InvalidUrl => "Invalid URL",
-    InvalidRights => "Invalid rights string",
-    InvalidVersion => "Invalid version string",
-    InvalidPublicDomainVersion => "The version of CC0 licenses must be 1.0",
+    InvalidUrl => "CC_E001", "Invalid URL",
+    InvalidRights => "CC_E002", "Invalid rights string",
+    InvalidVersion => "CC_E003", "Invalid version string",
+    InvalidPublicDomainVersion => "CC_E004", "The version of public domain tools must be 1.0",
+    InvalidDate => "CC_E005", "Invalid date",
+    InvalidCode => "CC_E006", "Invalid license code",
+    InvalidNomenclature => "CC_E007", "Invalid nomenclature",
+    InvalidJurisdiction => "CC_E008", "Invalid jurisdiction",
+    SpoofedDomain => "CC_E009", "Look-alike of the Creative Commons domain",
+    NotFound => "CC_E010", "No license found",
+    InvalidScheme => "CC_E011", "Invalid URL scheme",
+    InvalidDomain => "CC_E012", "Invalid domain",
+    MissingVersion => "CC_E013", "Missing version",
+    TrailingSegments => "CC_E014", "Unexpected segments after the license",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code() {
+        assert_eq!(ParseError::InvalidUrl.code(), "CC_E001");
+        assert_eq!(ParseError::InvalidRights.code(), "CC_E002");
+        assert_eq!(ParseError::InvalidVersion.code(), "CC_E003");
+        assert_eq!(ParseError::InvalidPublicDomainVersion.code(), "CC_E004");
+        assert_eq!(ParseError::InvalidDate.code(), "CC_E005");
+        assert_eq!(ParseError::InvalidCode.code(), "CC_E006");
+        assert_eq!(ParseError::InvalidNomenclature.code(), "CC_E007");
+        assert_eq!(ParseError::InvalidJurisdiction.code(), "CC_E008");
+        assert_eq!(ParseError::SpoofedDomain.code(), "CC_E009");
+        assert_eq!(ParseError::NotFound.code(), "CC_E010");
+        assert_eq!(ParseError::InvalidScheme.code(), "CC_E011");
+        assert_eq!(ParseError::InvalidDomain.code(), "CC_E012");
+        assert_eq!(ParseError::MissingVersion.code(), "CC_E013");
+        assert_eq!(ParseError::TrailingSegments.code(), "CC_E014");
+    }
+
+    #[test]
+    fn test_public_domain_version() {
+        for url in [
+            "https://creativecommons.org/publicdomain/zero/2.0/",
+            "https://creativecommons.org/publicdomain/mark/2.0/",
+        ] {
+            let error = crate::License::from_url(url).unwrap_err();
+            assert_eq!(error, ParseError::InvalidPublicDomainVersion, "{}", url);
+            assert_eq!(
+                error.to_string(),
+                "The version of public domain tools must be 1.0"
+            );
+        }
+    }
+
+    #[test]
+    fn test_error_source() {
+        let error = Error::from(ParseError::InvalidVersion);
+        assert_eq!(error.parse_error(), Some(ParseError::InvalidVersion));
+        assert_eq!(
+            error.source().unwrap().to_string(),
+            "Invalid version string"
+        );
+
+        let error = Error::from(io::Error::other("connection reset"));
+        assert_eq!(error.parse_error(), None);
+        assert_eq!(error.to_string(), "Failed to read the input");
+        let source = error.source().unwrap();
+        assert_eq!(source.to_string(), "connection reset");
+        assert!(source.downcast_ref::<io::Error>().is_some());
+    }
+
+    #[test]
+    fn test_from_code() {
+        for error in ParseError::ALL {
+            assert_eq!(ParseError::from_code(error.code()), Some(*error));
+        }
+        assert_eq!(ParseError::from_code("cc_e001"), None);
+        assert_eq!(ParseError::from_code(""), None);
+    }
+
+    #[test]
+    fn test_codes_are_unique() {
+        for (i, error) in ParseError::ALL.iter().enumerate() {
+            assert_eq!(error.code().len(), 7);
+            assert!(error.code().starts_with("CC_E"));
+            assert!(ParseError::ALL[i + 1..]
+                .iter()
+                .all(|other| other.code() != error.code()));
+        }
+    }
 }
@@ -0,0 +1,177 @@
+use crate::License;
+use std::fmt;
+
+/// A single permission, condition or limitation of a license
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Term {
+    Share,
+    Adapt,
+    CommercialUse,
+    Attribution,
+    ShareAlike,
+    NonCommercial,
+    NoDerivatives,
+    NoWarranty,
+    NoTrademarkOrPatentRights,
+}
+
+impl Term {
+    /// Obtain a short human-readable label, e.g. "Commercial use"
+    pub fn label(&self) -> &'static str {
+        match self {
+            Term::Share => "Share",
+            Term::Adapt => "Adapt",
+            Term::CommercialUse => "Commercial use",
+            Term::Attribution => "Attribution",
+            Term::ShareAlike => "ShareAlike",
+            Term::NonCommercial => "NonCommercial",
+            Term::NoDerivatives => "NoDerivatives",
+            Term::NoWarranty => "No warranty",
+            Term::NoTrademarkOrPatentRights => "No trademark or patent rights",
+        }
+    }
+
+    /// Obtain a one-sentence explanation of the term
+    pub fn description(&self) -> &'static str {
+        match self {
+            Term::Share => "Copy and redistribute the material in any medium or format.",
+            Term::Adapt => "Remix, transform, and build upon the material.",
+            Term::CommercialUse => "Use the material for commercial purposes.",
+            Term::Attribution => "Give appropriate credit, provide a link to the license, and indicate if changes were made.",
+            Term::ShareAlike => "Distribute contributions under the same license as the original.",
+            Term::NonCommercial => "The material may not be used for commercial purposes.",
+            Term::NoDerivatives => "Modified material may not be distributed.",
+            Term::NoWarranty => "The material is provided as-is, without warranties of any kind.",
+            Term::NoTrademarkOrPatentRights => "Trademark and patent rights are not licensed or waived.",
+        }
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+/// What a license permits, requires and does not allow
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Explanation {
+    /// What anyone may do with the material
+    pub permissions: Vec<Term>,
+    /// What must be done when exercising the permissions
+    pub conditions: Vec<Term>,
+    /// What the license does not allow or does not provide
+    pub limitations: Vec<Term>,
+}
+
+impl License {
+    /// Explain the license as lists of permissions, conditions and limitations
+    ///
+    /// Each [`Term`] carries a label and a short description, intended to drive
+    /// explanatory UI panels.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::{License, Term};
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("https://creativecommons.org/licenses/by-nc-sa/4.0/")?;
+    /// let explanation = license.explanation();
+    /// assert_eq!(explanation.permissions, vec![Term::Share, Term::Adapt]);
+    /// assert_eq!(explanation.conditions, vec![Term::Attribution, Term::ShareAlike]);
+    /// assert_eq!(explanation.limitations[0], Term::NonCommercial);
+    /// assert_eq!(
+    ///     explanation.conditions[1].description(),
+    ///     "Distribute contributions under the same license as the original."
+    /// );
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn explanation(&self) -> Explanation {
+        let rights = self.rights_enum();
+        let mut permissions = vec![Term::Share];
+        let mut conditions = Vec::new();
+        let mut limitations = Vec::new();
+        if rights.allows_derivatives() {
+            permissions.push(Term::Adapt);
+        } else {
+            limitations.push(Term::NoDerivatives);
+        }
+        if rights.allows_commercial_use() {
+            permissions.push(Term::CommercialUse);
+        } else {
+            limitations.insert(0, Term::NonCommercial);
+        }
+        if rights.requires_attribution() {
+            conditions.push(Term::Attribution);
+        }
+        if rights.requires_share_alike() {
+            conditions.push(Term::ShareAlike);
+        }
+        limitations.push(Term::NoWarranty);
+        limitations.push(Term::NoTrademarkOrPatentRights);
+        Explanation {
+            permissions,
+            conditions,
+            limitations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rights::Rights;
+    use crate::version::Version;
+
+    fn explain(rights: Rights) -> Explanation {
+        License::from_parts(rights, Version::One, None).explanation()
+    }
+
+    #[test]
+    fn test_explanation() {
+        assert_eq!(
+            explain(Rights::By),
+            Explanation {
+                permissions: vec![Term::Share, Term::Adapt, Term::CommercialUse],
+                conditions: vec![Term::Attribution],
+                limitations: vec![Term::NoWarranty, Term::NoTrademarkOrPatentRights],
+            }
+        );
+        assert_eq!(
+            explain(Rights::ByNcNd),
+            Explanation {
+                permissions: vec![Term::Share],
+                conditions: vec![Term::Attribution],
+                limitations: vec![
+                    Term::NonCommercial,
+                    Term::NoDerivatives,
+                    Term::NoWarranty,
+                    Term::NoTrademarkOrPatentRights
+                ],
+            }
+        );
+        assert_eq!(
+            explain(Rights::Zero),
+            Explanation {
+                permissions: vec![Term::Share, Term::Adapt, Term::CommercialUse],
+                conditions: vec![],
+                limitations: vec![Term::NoWarranty, Term::NoTrademarkOrPatentRights],
+            }
+        );
+    }
+
+    #[test]
+    fn test_term() {
+        assert_eq!(
+            format!("{}", Term::CommercialUse),
+            "Commercial use".to_string()
+        );
+        assert!(Term::Attribution
+            .description()
+            .starts_with("Give appropriate credit"));
+    }
+}
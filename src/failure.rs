@@ -0,0 +1,443 @@
+use crate::error::ParseError;
+use crate::fuzzy::edit_distance;
+use crate::jurisdiction::Jurisdiction;
+use crate::options::ParseOptions;
+use crate::parser::{self, Segments, Span};
+use crate::rights::Rights;
+use crate::table::LICENSES;
+use crate::version::Version;
+use crate::License;
+use std::cmp::Reverse;
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+/// Most suggestions offered for a rejected segment
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Failure to parse a license URL, along with the input and what was likely meant
+///
+/// Wraps the [`ParseError`] with the input that was rejected, the segment of it at
+/// fault when one can be located, and suggestions of known values close to a
+/// rejected rights or version segment.
+///
+/// # Example
+///
+/// ```rust
+/// use cc_license::{License, ParseError};
+///
+/// let failure = License::from_url_detailed("https://creativecommons.org/licenses/by-nc-as/4.0/").unwrap_err();
+/// assert_eq!(failure.error(), ParseError::InvalidRights);
+/// assert_eq!(failure.rejected(), Some("by-nc-as"));
+/// assert_eq!(failure.suggestions(), ["by-nc-sa"]);
+/// assert_eq!(
+///     failure.to_string(),
+///     r#"Invalid rights string "by-nc-as" in "https://creativecommons.org/licenses/by-nc-as/4.0/", did you mean by-nc-sa?"#
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct ParseFailure {
+    error: ParseError,
+    input: String,
+    rejected: Option<Range<usize>>,
+    suggestions: Vec<String>,
+}
+
+impl ParseFailure {
+    /// Describe the failure of parsing the input with the error, locating the
+    /// rejected segment and suggesting corrections
+    ///
+    /// Useful to explain the failures kept by [`License::parse_many`], which only
+    /// hold the lightweight [`ParseError`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{License, ParseFailure};
+    ///
+    /// let inputs = ["https://creativecommons.org/licenses/by/4.0/", "https://creativecommons.org/licenses/by/4.1/"];
+    /// let batch = License::parse_many(&inputs);
+    /// let failures: Vec<ParseFailure> = inputs
+    ///     .iter()
+    ///     .zip(batch.results())
+    ///     .filter_map(|(input, result)| result.err().map(|error| ParseFailure::new(input, error)))
+    ///     .collect();
+    /// assert_eq!(failures.len(), 1);
+    /// assert_eq!(failures[0].rejected(), Some("4.1"));
+    /// ```
+    pub fn new(input: &str, error: ParseError) -> Self {
+        ParseFailure {
+            error,
+            input: input.to_string(),
+            rejected: locate(input, error).filter(|range| !range.is_empty()),
+            suggestions: suggest(input, error),
+        }
+    }
+
+    /// The reason parsing failed
+    pub fn error(&self) -> ParseError {
+        self.error
+    }
+
+    /// The whole input that failed to parse
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// The segment of the input at fault, e.g. `5.0` for an unknown version
+    ///
+    /// `None` when the failure is not about a single segment, such as an invalid
+    /// URL, or the segment cannot be located in the input.
+    pub fn rejected(&self) -> Option<&str> {
+        self.rejected
+            .as_ref()
+            .map(|range| &self.input[range.clone()])
+    }
+
+    /// Byte range of the rejected segment within the input, e.g. to highlight it
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::License;
+    ///
+    /// let url = "https://creativecommons.org/licenses/by/5.0/";
+    /// let failure = License::from_url_detailed(url).unwrap_err();
+    /// assert_eq!(failure.span(), Some(40..43));
+    /// assert_eq!(&url[40..43], "5.0");
+    /// ```
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.rejected.clone()
+    }
+
+    /// Known values close to the rejected segment, most likely first, e.g.
+    /// `by-nc-sa` for `by-nc-as`
+    ///
+    /// Empty when nothing is close enough, or the failure is not about the rights
+    /// or version segments.
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+}
+
+impl fmt::Display for ParseFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.rejected() {
+            Some(rejected) => write!(f, "{} {:?} in {:?}", self.error, rejected, self.input)?,
+            None => write!(f, "{}: {:?}", self.error, self.input)?,
+        }
+        if self.suggestions.is_empty() {
+            return Ok(());
+        }
+        f.write_str(", did you mean ")?;
+        let last = self.suggestions.len() - 1;
+        for (i, suggestion) in self.suggestions.iter().enumerate() {
+            let separator = match i {
+                0 => "",
+                _ if i == last => " or ",
+                _ => ", ",
+            };
+            write!(f, "{}{}", separator, suggestion)?;
+        }
+        f.write_str("?")
+    }
+}
+
+impl Error for ParseFailure {}
+
+impl From<ParseFailure> for ParseError {
+    fn from(failure: ParseFailure) -> Self {
+        failure.error
+    }
+}
+
+impl License {
+    /// Parse a license URL like [`License::from_url`], keeping the input and
+    /// suggesting corrections of the rights and version segments on failure
+    ///
+    /// See [`ParseFailure`] for an example.
+    pub fn from_url_detailed(url: &str) -> Result<Self, ParseFailure> {
+        License::parse_with(url, ParseOptions::global_default())
+    }
+}
+
+/// Byte range of the part of the input rejected with the error
+fn locate(input: &str, error: ParseError) -> Option<Range<usize>> {
+    let authority = input.find("://").map(|scheme_end| {
+        let start = scheme_end + 3;
+        start
+            ..input[start..]
+                .find('/')
+                .map_or(input.len(), |end| start + end)
+    });
+    let located = Located::new(input);
+    let span = match error {
+        ParseError::InvalidScheme => return authority.map(|authority| 0..authority.start - 3),
+        ParseError::InvalidDomain | ParseError::SpoofedDomain => return authority,
+        ParseError::InvalidRights => located.as_ref()?.segments.rights,
+        ParseError::InvalidVersion | ParseError::InvalidPublicDomainVersion => {
+            located.as_ref()?.segments.version
+        }
+        ParseError::InvalidJurisdiction => located.as_ref()?.segments.jurisdiction,
+        _ => return None,
+    };
+    Some(located?.range(span))
+}
+
+/// Segments of an input, located as lenient parsing sees them
+struct Located<'a> {
+    input: &'a str,
+    segments: Segments,
+    /// Length of the scheme added in front of the input to locate its segments
+    offset: usize,
+}
+
+impl<'a> Located<'a> {
+    /// Locate the segments of the input, adding the `https` scheme that lenient
+    /// parsing assumes when the input has none
+    fn new(input: &'a str) -> Option<Self> {
+        match parser::split_url(input.as_bytes()) {
+            Ok(segments) => Some(Located {
+                input,
+                segments,
+                offset: 0,
+            }),
+            Err(ParseError::InvalidScheme) if !input.contains("://") => {
+                let rest = input.strip_prefix("//").unwrap_or(input);
+                let url = format!("https://{}", rest);
+                let segments = parser::split_url(url.as_bytes()).ok()?;
+                Some(Located {
+                    input,
+                    segments,
+                    offset: url.len() - input.len(),
+                })
+            }
+            Err(_) => None,
+        }
+    }
+
+    /// Byte range of the segment within the input
+    fn range(&self, span: Span) -> Range<usize> {
+        span.start - self.offset..span.end - self.offset
+    }
+
+    /// The segment within the input, `None` when empty
+    fn get(&self, span: Span) -> Option<&'a str> {
+        (span.start < span.end).then(|| &self.input[self.range(span)])
+    }
+}
+
+fn suggest(input: &str, error: ParseError) -> Vec<String> {
+    let Some(located) = Located::new(input) else {
+        return Vec::new();
+    };
+    let rights = located.get(located.segments.rights).unwrap_or_default();
+    match error {
+        ParseError::InvalidRights => suggest_rights(rights),
+        ParseError::InvalidVersion | ParseError::InvalidPublicDomainVersion => suggest_version(
+            rights,
+            located.get(located.segments.version),
+            located.get(located.segments.jurisdiction),
+        ),
+        _ => Vec::new(),
+    }
+}
+
+/// Known rights slugs close to the segment, counting reordered clauses as exact
+fn suggest_rights(segment: &str) -> Vec<String> {
+    let segment = segment.to_ascii_lowercase();
+    let segment = segment.strip_prefix("cc-").unwrap_or(&segment);
+    let tolerance = (segment.len() / 3).max(1);
+    let mut candidates: Vec<(usize, &str)> = Rights::iter()
+        .filter(|rights| !rights.is_unversioned())
+        .map(|rights| rights.slug())
+        .map(|slug| {
+            let distance = if clauses(slug) == clauses(segment) {
+                0
+            } else {
+                edit_distance(segment, slug)
+            };
+            (distance, slug)
+        })
+        .filter(|(distance, _)| *distance <= tolerance)
+        .collect();
+    candidates.sort_by_key(|(distance, _)| *distance);
+    closest(candidates)
+        .map(|(_, slug)| slug.to_string())
+        .collect()
+}
+
+/// The clauses of a rights slug, in a canonical order
+fn clauses(slug: &str) -> Vec<&str> {
+    let mut clauses: Vec<&str> = slug.split('-').collect();
+    clauses.sort_unstable();
+    clauses
+}
+
+/// Versions the rights exist in close to the segment, the latest first among
+/// equally close ones
+fn suggest_version(rights: &str, version: Option<&str>, jurisdiction: Option<&str>) -> Vec<String> {
+    let Some(version) = version else {
+        return Vec::new();
+    };
+    let rights = Rights::from_slug(rights.as_bytes());
+    let jurisdiction = jurisdiction.and_then(|slug| Jurisdiction::from_slug(slug.as_bytes()));
+    let mut candidates: Vec<(usize, Version)> = Version::iter()
+        .filter(|candidate| match jurisdiction {
+            Some(jurisdiction) => {
+                jurisdiction.has_version(*candidate)
+                    && rights.is_some_and(|rights| rights.sole_version().is_none())
+            }
+            None => LICENSES.iter().any(|license| {
+                license.version_enum() == *candidate
+                    && rights.is_none_or(|rights| license.rights_enum() == rights)
+            }),
+        })
+        .map(|candidate| (edit_distance(version, candidate.slug()), candidate))
+        .filter(|(distance, _)| *distance <= 2)
+        .collect();
+    candidates.sort_by_key(|(distance, candidate)| (*distance, Reverse(*candidate)));
+    closest(candidates)
+        .map(|(_, candidate)| candidate.slug().to_string())
+        .collect()
+}
+
+/// Keep the first candidates at the smallest distance, given candidates sorted
+/// by distance
+fn closest<T>(candidates: Vec<(usize, T)>) -> impl Iterator<Item = (usize, T)> {
+    let smallest = candidates.first().map(|(distance, _)| *distance);
+    candidates
+        .into_iter()
+        .take_while(move |(distance, _)| Some(*distance) == smallest)
+        .take(MAX_SUGGESTIONS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suggestions(url: &str) -> Vec<String> {
+        License::from_url_detailed(url).unwrap_err().suggestions
+    }
+
+    #[test]
+    fn test_rights() {
+        for (segment, expected) in [
+            ("by-nc-as", vec!["by-nc-sa"]),
+            ("by-sa-nc", vec!["by-nc-sa"]),
+            ("BY-ND", vec!["by-nd"]),
+            ("cc-by", vec!["by"]),
+            ("by-nc-nd-sa", vec!["by-nc-sa", "by-nc-nd"]),
+            ("xyz", vec![]),
+        ] {
+            let url = format!("https://creativecommons.org/licenses/{}/4.0/", segment);
+            assert_eq!(suggestions(&url), expected, "{}", segment);
+        }
+    }
+
+    #[test]
+    fn test_version() {
+        for (url, expected) in [
+            ("licenses/by/4.O/", vec!["4.0"]),
+            ("licenses/by/5.0/", vec!["4.0", "3.0", "2.0"]),
+            ("licenses/by/2.1/", vec!["2.5", "2.0"]),
+            ("licenses/by-sa/2.2/es/", vec!["2.5", "2.1", "2.0"]),
+            ("publicdomain/zero/2.0/", vec!["1.0"]),
+            ("licenses/by/version-four/", vec![]),
+        ] {
+            let url = format!("https://creativecommons.org/{}", url);
+            assert_eq!(suggestions(&url), expected, "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_rejected() {
+        for (url, error, rejected) in [
+            (
+                "licenses/BY-XX/4.0/",
+                ParseError::InvalidRights,
+                Some("BY-XX"),
+            ),
+            (
+                "licenses/by/4.0/de/",
+                ParseError::InvalidJurisdiction,
+                Some("de"),
+            ),
+            (
+                "publicdomain/zero/2.0/",
+                ParseError::InvalidPublicDomainVersion,
+                Some("2.0"),
+            ),
+            ("licenses/by/", ParseError::MissingVersion, None),
+        ] {
+            let url = format!("https://creativecommons.org/{}", url);
+            let failure = License::from_url_detailed(&url).unwrap_err();
+            assert_eq!(failure.error(), error, "{}", url);
+            assert_eq!(failure.rejected(), rejected, "{}", url);
+            assert_eq!(failure.input(), url);
+        }
+        let failure = ParseFailure::new(
+            "//creativecommons.org/licenses/by-sa/40/",
+            ParseError::InvalidVersion,
+        );
+        assert_eq!(failure.span(), Some(37..39));
+        assert_eq!(failure.rejected(), Some("40"));
+        assert_eq!(failure.suggestions(), ["4.0"]);
+
+        let failure = ParseFailure::new("not a url\n", ParseError::InvalidUrl);
+        assert_eq!(failure.to_string(), "Invalid URL: \"not a url\\n\"");
+    }
+
+    #[test]
+    fn test_failure() {
+        let failure =
+            License::from_url_detailed("https://creativecommons.org/licenses/by/5.0/").unwrap_err();
+        assert_eq!(failure.error(), ParseError::InvalidVersion);
+        assert_eq!(
+            failure.input(),
+            "https://creativecommons.org/licenses/by/5.0/"
+        );
+        assert_eq!(failure.rejected(), Some("5.0"));
+        assert_eq!(
+            failure.to_string(),
+            "Invalid version string \"5.0\" in \"https://creativecommons.org/licenses/by/5.0/\", \
+            did you mean 4.0, 3.0 or 2.0?"
+        );
+        assert_eq!(ParseError::from(failure), ParseError::InvalidVersion);
+
+        let failure = License::from_url_detailed("https://example.org/").unwrap_err();
+        assert_eq!(failure.error(), ParseError::InvalidDomain);
+        assert_eq!(failure.rejected(), Some("example.org"));
+        assert!(failure.suggestions().is_empty());
+        assert_eq!(
+            failure.to_string(),
+            "Invalid domain \"example.org\" in \"https://example.org/\""
+        );
+
+        let failure = License::from_url_detailed("ftp://creativecommons.org/").unwrap_err();
+        assert_eq!(failure.error(), ParseError::InvalidScheme);
+        assert_eq!(failure.rejected(), Some("ftp"));
+
+        let failure = License::from_url_detailed("https://creativecommons.org/about/").unwrap_err();
+        assert_eq!(failure.error(), ParseError::InvalidUrl);
+        assert_eq!(failure.rejected(), None);
+
+        assert_eq!(
+            License::from_url_detailed("https://creativecommons.org/licenses/by/4.0/"),
+            Ok(License::CC_BY_4_0)
+        );
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let url = "http://creativecommons.org/licenses/by/4.0/";
+        let failure = License::parse_with(url, &ParseOptions::strict()).unwrap_err();
+        assert_eq!(failure.error(), ParseError::InvalidScheme);
+        assert_eq!(failure.input(), url);
+        assert_eq!(failure.rejected(), Some("http"));
+        assert_eq!(
+            License::parse_with(url, &ParseOptions::new()),
+            Ok(License::CC_BY_4_0)
+        );
+    }
+}
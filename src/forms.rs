@@ -0,0 +1,91 @@
+use crate::License;
+
+/// Every representation of a license that the crate can both emit and parse
+///
+/// Each form is guaranteed to parse back into the license it was produced from,
+/// so a license may be stored in any of them without losing information. New
+/// forms are added as fields when the crate learns to parse them.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct CanonicalForms {
+    /// Canonical creativecommons.org URL, parsed by [`License::from_url`]
+    pub url: String,
+    /// Short name, parsed by [`License::from_short_name`]
+    pub short: String,
+    /// SPDX identifier, parsed by [`License::from_spdx_id`], if the license is on
+    /// the SPDX License List
+    pub spdx_id: Option<String>,
+    /// Filename-safe identifier, parsed by [`License::from_identifier`]
+    pub identifier: String,
+}
+
+impl License {
+    /// Obtain every lossless representation of the license
+    ///
+    /// For every valid license `x`, `License::from_url(&x.canonical_forms().url)`
+    /// returns `x`, and likewise for every other form with its parser.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("http://www.creativecommons.org/licenses/by-sa/3.0")?;
+    /// let forms = license.canonical_forms();
+    /// assert_eq!(forms.url, "https://creativecommons.org/licenses/by-sa/3.0/");
+    /// assert_eq!(License::from_url(&forms.url)?, license);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn canonical_forms(&self) -> CanonicalForms {
+        CanonicalForms {
+            url: self.to_url(),
+            short: self.short(),
+            spdx_id: self.spdx_id(),
+            identifier: self.identifier().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::table::LICENSES;
+    use crate::License;
+
+    #[test]
+    fn test_round_trip() {
+        for license in LICENSES {
+            let forms = license.canonical_forms();
+            assert_eq!(License::from_url(&forms.url).as_ref(), Ok(license));
+            assert_eq!(License::from_short_name(&forms.short).as_ref(), Ok(license));
+            if let Some(spdx_id) = &forms.spdx_id {
+                assert_eq!(License::from_spdx_id(spdx_id).as_ref(), Ok(license));
+            }
+            assert_eq!(
+                License::from_identifier(&forms.identifier).as_ref(),
+                Ok(license)
+            );
+        }
+    }
+
+    #[test]
+    fn test_canonical_forms() {
+        assert_eq!(
+            License::from_url("https://creativecommons.org/publicdomain/zero/1.0")
+                .unwrap()
+                .canonical_forms()
+                .url,
+            "https://creativecommons.org/publicdomain/zero/1.0/"
+        );
+        assert_eq!(
+            License::from_url("http://creativecommons.org/licenses/by-nc-nd/2.5")
+                .unwrap()
+                .canonical_forms()
+                .url,
+            "https://creativecommons.org/licenses/by-nc-nd/2.5/"
+        );
+    }
+}
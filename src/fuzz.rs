@@ -0,0 +1,111 @@
+use crate::error::ParseError;
+use crate::options::ParseOptions;
+use crate::License;
+
+/// Check the parser invariants for an arbitrary input, panicking on violation
+///
+/// Intended to be driven by a fuzzer (see the `fuzz` directory of the repository),
+/// but usable from any property test. For every input that parses, the license
+/// must survive a round trip through its canonical URL and short name, render
+/// without panicking and parse identically in lenient mode; for every input,
+/// parsing must be deterministic.
+pub fn fuzz_check(input: &str) {
+    let result = License::from_url(input);
+    assert_eq!(
+        result,
+        License::from_url(input),
+        "parsing is not deterministic"
+    );
+    // look-alike domains are only told apart at runtime, the const parser
+    // reports them as any other foreign domain
+    let runtime = match License::parse(input, &ParseOptions::new()) {
+        Err(ParseError::SpoofedDomain) => Err(ParseError::InvalidDomain),
+        runtime => runtime,
+    };
+    assert_eq!(
+        runtime,
+        License::from_url_const(input),
+        "const and runtime parsing disagree"
+    );
+    if let Ok(license) = result {
+        let url = license.to_url();
+        assert_eq!(
+            License::from_url(&url),
+            Ok(license),
+            "canonical URL {} does not round-trip",
+            url
+        );
+        assert_eq!(
+            License::from_url_strict(&url),
+            Ok(license),
+            "canonical URL {} is rejected by the strict parser",
+            url
+        );
+        assert_eq!(
+            License::from_short_name(&license.short()),
+            Ok(license),
+            "short name does not round-trip"
+        );
+        assert!(license
+            .to_string()
+            .ends_with(&format!("({}).", license.short())));
+        assert!(!license.rights_full().is_empty());
+        assert_eq!(
+            License::from_url_lenient(input),
+            Ok(license),
+            "lenient parsing disagrees with strict parsing"
+        );
+    }
+    assert_eq!(
+        License::from_short_name(input),
+        License::from_short_name(input),
+        "short name parsing is not deterministic"
+    );
+    #[cfg(feature = "scan")]
+    for (license, range) in crate::Scanner::new(input) {
+        assert_eq!(
+            License::from_url(&input[range]),
+            Ok(license),
+            "scanned range does not parse into the reported license"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzz_check() {
+        for input in [
+            "",
+            "/",
+            "https://",
+            "https://creativecommons.org/",
+            "https://creativecommons.org/licenses/by/4.0/",
+            "http://www.creativecommons.org/licenses/by-nc-nd/2.5",
+            "https://creativecommons.org/publicdomain/zero/1.0/",
+            "https://creativecommons.org/publicdomain/zero/4.0/",
+            "https://creativecommons.org/licenses/by/4.0//",
+            "see https://creativecommons.org/licenses/by/4.0/. and http://creativecommons.org/",
+            "https://creativecommons.org/licenses/ñ/4.0/",
+            "https://xn--reativecommons-40l.org/licenses/by/4.0/",
+            "https://сreativecommons.org/licenses/by/4.0/",
+        ] {
+            fuzz_check(input);
+        }
+    }
+
+    #[test]
+    fn test_spoofed_domain() {
+        let input = "https://xn--reativecommons-40l.org/licenses/by/4.0/";
+        assert_eq!(
+            License::parse(input, &ParseOptions::new()),
+            Err(ParseError::SpoofedDomain)
+        );
+        assert_eq!(
+            License::from_url_const(input),
+            Err(ParseError::InvalidDomain)
+        );
+    }
+}
@@ -0,0 +1,478 @@
+use crate::element::Element;
+use crate::rights::Rights;
+use crate::table::LICENSES;
+use crate::version::Version;
+use crate::License;
+
+/// Most alternatives returned besides the best match
+const MAX_ALTERNATIVES: usize = 3;
+
+/// Words that say nothing about which license is meant
+const FILLER: &[&str] = &[
+    "a",
+    "an",
+    "and",
+    "code",
+    "deed",
+    "for",
+    "in",
+    "is",
+    "legal",
+    "license",
+    "licence",
+    "licensed",
+    "licenced",
+    "of",
+    "or",
+    "published",
+    "the",
+    "this",
+    "to",
+    "under",
+    "universal",
+    "v",
+    "ver",
+    "version",
+    "work",
+];
+
+/// Outcome of fuzzy license detection, see [`License::fuzzy_match`]
+#[derive(Debug, PartialEq, Clone)]
+pub struct FuzzyMatch {
+    license: License,
+    confidence: f32,
+    alternatives: Vec<(License, f32)>,
+}
+
+impl FuzzyMatch {
+    /// The most likely license
+    pub fn license(&self) -> License {
+        self.license
+    }
+
+    /// Confidence in the most likely license, from 0 to 1
+    ///
+    /// Only exact mentions of a license, as found by [`License::from_text`], have
+    /// a confidence of 1.
+    pub fn confidence(&self) -> f32 {
+        self.confidence
+    }
+
+    /// Less likely licenses with their confidence, most likely first
+    pub fn alternatives(&self) -> &[(License, f32)] {
+        &self.alternatives
+    }
+}
+
+impl License {
+    /// Find the license most likely meant by a messy description
+    ///
+    /// Descriptions that mention a license exactly, as accepted by
+    /// [`License::from_text`], match with full confidence. Otherwise the words of
+    /// the description are compared with the names of the clauses, public domain
+    /// tools and versions, tolerating typos, and every license is scored on how
+    /// well it fits. Returns `None` when nothing in the text points to a Creative
+    /// Commons license.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::License;
+    ///
+    /// let found = License::fuzzy_match("Creative commons attribution non commercial share alike v4").unwrap();
+    /// assert_eq!(found.license(), License::CC_BY_NC_SA_4_0);
+    /// assert!(found.confidence() > 0.8 && found.confidence() < 1.0);
+    /// assert!(found.alternatives().iter().all(|(_, confidence)| *confidence < found.confidence()));
+    /// assert!(License::fuzzy_match("All rights reserved").is_none());
+    /// ```
+    pub fn fuzzy_match(text: &str) -> Option<FuzzyMatch> {
+        if let Ok(license) = License::from_text(text) {
+            return Some(FuzzyMatch {
+                license,
+                confidence: 1.0,
+                alternatives: Vec::new(),
+            });
+        }
+        let clues = Clues::read(text);
+        let mut scored: Vec<(License, f32)> = LICENSES
+            .iter()
+            .map(|license| (*license, clues.score(license)))
+            .filter(|(_, score)| *score > 0.0)
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+        let mut scored = scored.into_iter();
+        let (license, confidence) = scored.next()?;
+        Some(FuzzyMatch {
+            license,
+            confidence,
+            alternatives: scored.take(MAX_ALTERNATIVES).collect(),
+        })
+    }
+}
+
+/// What the words of a description say about the license
+#[derive(Debug, Default)]
+struct Clues {
+    elements: Vec<Element>,
+    /// Whether clauses were only given as abbreviations, e.g. `by`
+    abbreviated: bool,
+    tool: Option<Rights>,
+    public_domain: bool,
+    version: Option<Version>,
+    /// Version suggested by a suffix such as `International`
+    suffix: Option<Version>,
+    /// Whether Creative Commons is mentioned
+    creative_commons: bool,
+    recognised: usize,
+    unknown: usize,
+}
+
+impl Clues {
+    fn read(text: &str) -> Self {
+        let text = text.to_lowercase();
+        let words: Vec<&str> = text
+            .split(|c: char| !(c.is_alphanumeric() || c == '.' || c == '+'))
+            .map(|word| word.trim_matches('.'))
+            .filter(|word| !word.is_empty())
+            .collect();
+        let mut clues = Clues {
+            abbreviated: true,
+            ..Clues::default()
+        };
+        let mut i = 0;
+        while i < words.len() {
+            let pair = words
+                .get(i + 1)
+                .filter(|next| is_alphabetic(words[i]) && is_alphabetic(next))
+                .map(|next| [words[i], next].concat());
+            let consumed = match pair.as_deref().map(|pair| clues.recognise(pair, false)) {
+                Some(true) => 2,
+                _ if clues.recognise(words[i], true) => 1,
+                _ => {
+                    if !FILLER.contains(&words[i]) {
+                        clues.unknown += 1;
+                    }
+                    1
+                }
+            };
+            i += consumed;
+        }
+        clues
+    }
+
+    /// Record what the word, or pair of words written together, says, returning
+    /// whether it was recognised
+    fn recognise(&mut self, word: &str, single: bool) -> bool {
+        // a typo per six letters, and at most one across a pair of words
+        let tolerance = word.chars().count() / 6;
+        let tolerance = if single { tolerance } else { tolerance.min(1) };
+        if let Some(version) = parse_version(word) {
+            self.version = Some(version);
+        } else if single && matches!(word, "by" | "nc" | "nd" | "sa") {
+            self.add_element(match word {
+                "by" => Element::Attribution,
+                "nc" => Element::NonCommercial,
+                "nd" => Element::NoDerivatives,
+                _ => Element::ShareAlike,
+            });
+        } else if single && word == "cc" {
+            self.creative_commons = true;
+        } else if let Some(keyword) = closest_keyword(word, tolerance) {
+            match keyword {
+                "creativecommons" | "creative" | "commons" => self.creative_commons = true,
+                "international" => self.suffix = Some(Version::Four),
+                "unported" => self.suffix = Some(Version::Three),
+                "attribution" => self.add_word_element(Element::Attribution),
+                "noncommercial" => self.add_word_element(Element::NonCommercial),
+                "noderivatives" | "noderivs" | "noderivative" => {
+                    self.add_word_element(Element::NoDerivatives)
+                }
+                "sharealike" => self.add_word_element(Element::ShareAlike),
+                "public" | "domain" | "publicdomain" => self.public_domain = true,
+                "cc0" | "zero" | "dedication" => self.set_tool(Rights::Zero),
+                "mark" | "pdm" | "domainmark" => self.set_tool(Rights::Mark),
+                "certification" | "pddc" => self.tool = Some(Rights::Certification),
+                "sampling" => self.set_tool(Rights::Sampling),
+                "sampling+" | "samplingplus" => self.tool = Some(Rights::SamplingPlus),
+                "developingnations" | "devnations" => self.tool = Some(Rights::DevNations),
+                _ => return false,
+            }
+        } else {
+            return false;
+        }
+        self.recognised += 1;
+        true
+    }
+
+    fn add_element(&mut self, element: Element) {
+        if !self.elements.contains(&element) {
+            self.elements.push(element);
+        }
+    }
+
+    fn add_word_element(&mut self, element: Element) {
+        self.abbreviated = false;
+        self.add_element(element);
+    }
+
+    /// Record a public domain tool or retired license, unless a more specific
+    /// one was found, e.g. Sampling+ rather than Sampling
+    fn set_tool(&mut self, rights: Rights) {
+        if self.tool.is_none() || self.tool == Some(Rights::Zero) {
+            self.tool = Some(rights);
+        }
+    }
+
+    /// Score how well the license fits the clues, from 0 to just below 1
+    fn score(&self, license: &License) -> f32 {
+        let rights = self.rights_score(license.rights_enum());
+        if rights == 0.0 {
+            return 0.0;
+        }
+        let version = match (self.version, self.suffix) {
+            (Some(version), _) if version == license.version_enum() => 1.0,
+            (Some(_), _) => 0.1,
+            (None, Some(suffix)) if suffix == license.version_enum() => 0.9,
+            (None, Some(Version::Three)) if license.version_enum() < Version::Three => 0.6,
+            (None, Some(_)) => 0.2,
+            (None, None) if is_latest(license) => 0.6,
+            (None, None) => 0.4,
+        };
+        let coverage = self.recognised as f32 / (self.recognised + self.unknown) as f32;
+        let context = if self.creative_commons { 1.0 } else { 0.8 };
+        (0.7 * rights + 0.3 * version) * (0.5 + 0.5 * coverage) * context * 0.99
+    }
+
+    fn rights_score(&self, rights: Rights) -> f32 {
+        if let Some(tool) = self.tool {
+            let tool = match tool {
+                Rights::SamplingPlus if self.elements.contains(&Element::NonCommercial) => {
+                    Rights::NcSamplingPlus
+                }
+                tool => tool,
+            };
+            return if tool == rights { 1.0 } else { 0.0 };
+        }
+        if self.elements.is_empty() {
+            return match rights {
+                _ if !self.public_domain => 0.0,
+                Rights::Zero | Rights::Mark => 0.8,
+                Rights::Certification => 0.5,
+                _ => 0.0,
+            };
+        }
+        if self.abbreviated && !self.creative_commons && self.elements.len() < 2 {
+            // a lone "by" is most likely just a word
+            return 0.0;
+        }
+        let expected = rights.elements();
+        if expected.is_empty() {
+            return 0.0;
+        }
+        let matched = self
+            .elements
+            .iter()
+            .filter(|e| expected.contains(e))
+            .count() as f32;
+        let extra = self
+            .elements
+            .iter()
+            .filter(|e| !expected.contains(e))
+            .count() as f32;
+        let missing: f32 = expected
+            .iter()
+            .filter(|e| !self.elements.contains(e))
+            // attribution is often left unsaid
+            .map(|e| if *e == Element::Attribution { 0.5 } else { 1.0 })
+            .sum();
+        matched / (matched + extra + missing)
+    }
+}
+
+/// Words recognised in descriptions, including pairs of words written together
+const KEYWORDS: &[&str] = &[
+    "creativecommons",
+    "creative",
+    "commons",
+    "international",
+    "unported",
+    "attribution",
+    "noncommercial",
+    "noderivatives",
+    "noderivs",
+    "noderivative",
+    "sharealike",
+    "public",
+    "domain",
+    "publicdomain",
+    "cc0",
+    "zero",
+    "dedication",
+    "mark",
+    "pdm",
+    "domainmark",
+    "certification",
+    "pddc",
+    "sampling",
+    "sampling+",
+    "samplingplus",
+    "developingnations",
+    "devnations",
+];
+
+/// Find the keyword the word most likely is, allowing as many typos as given
+fn closest_keyword(word: &str, tolerance: usize) -> Option<&'static str> {
+    KEYWORDS
+        .iter()
+        .map(|keyword| (*keyword, edit_distance(word, keyword)))
+        .filter(|(_, distance)| *distance <= tolerance)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(keyword, _)| keyword)
+}
+
+fn is_alphabetic(word: &str) -> bool {
+    word.chars().all(char::is_alphabetic)
+}
+
+/// Parse a version written as `4.0`, `4` or `v4`
+fn parse_version(word: &str) -> Option<Version> {
+    let word = word.strip_prefix('v').unwrap_or(word);
+    if !word.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let slug = if word.contains('.') {
+        word.to_owned()
+    } else {
+        format!("{}.0", word)
+    };
+    Version::from_slug(slug.as_bytes())
+}
+
+/// Whether no later version of the rights exists
+fn is_latest(license: &License) -> bool {
+    !LICENSES.iter().any(|other| {
+        other.rights_enum() == license.rights_enum()
+            && other.version_enum() > license.version_enum()
+    })
+}
+
+/// Count the insertions, deletions, substitutions and swaps of adjacent characters
+/// needed to turn one string into the other
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut before: Vec<usize> = Vec::new();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for i in 0..a.len() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for j in 0..b.len() {
+            let substitution = previous[j] + usize::from(a[i] != b[j]);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+            if i > 0 && j > 0 && a[i] == b[j - 1] && a[i - 1] == b[j] {
+                current[j + 1] = current[j + 1].min(before[j - 1] + 1);
+            }
+        }
+        before = std::mem::replace(&mut previous, current);
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn best(text: &str) -> Option<License> {
+        License::fuzzy_match(text).map(|found| found.license())
+    }
+
+    #[test]
+    fn test_exact() {
+        let found = License::fuzzy_match("Licensed under CC BY 4.0.").unwrap();
+        assert_eq!(found.license(), License::CC_BY_4_0);
+        assert_eq!(found.confidence(), 1.0);
+        assert!(found.alternatives().is_empty());
+    }
+
+    #[test]
+    fn test_fuzzy() {
+        for (text, license) in [
+            (
+                "Creative commons attribution non commercial share alike v4",
+                License::CC_BY_NC_SA_4_0,
+            ),
+            (
+                "Creative Comons Atribution Non-Comercial 4.0",
+                License::CC_BY_NC_4_0,
+            ),
+            ("Attribution-NoDerivs 2.5", License::CC_BY_ND_2_5),
+            (
+                "creative commons attribution sharealike unported",
+                License::CC_BY_SA_3_0,
+            ),
+            ("CC BY NC ND International", License::CC_BY_NC_ND_4_0),
+            ("creative commons by", License::CC_BY_4_0),
+            ("Creative Commons Zero", License::CC0_1_0),
+            ("public domain mark", License::PDM_1_0),
+            ("Public Domain Dedication and Certification", License::PDDC),
+            (
+                "creative commons sampling plus",
+                License::CC_SAMPLING_PLUS_1_0,
+            ),
+            (
+                "Creative Commons NonCommercial Sampling Plus",
+                License::CC_NC_SAMPLING_PLUS_1_0,
+            ),
+        ] {
+            assert_eq!(best(text), Some(license), "{}", text);
+            let confidence = License::fuzzy_match(text).unwrap().confidence();
+            assert!(
+                confidence > 0.5 && confidence < 1.0,
+                "{}: {}",
+                text,
+                confidence
+            );
+        }
+    }
+
+    #[test]
+    fn test_alternatives() {
+        let found = License::fuzzy_match("public domain").unwrap();
+        assert!(found.confidence() < 0.9);
+        let mut candidates: Vec<_> = found.alternatives().iter().map(|(l, _)| *l).collect();
+        candidates.push(found.license());
+        assert!(candidates.contains(&License::CC0_1_0));
+        assert!(candidates.contains(&License::PDM_1_0));
+        assert!(found
+            .alternatives()
+            .windows(2)
+            .all(|pair| pair[0].1 >= pair[1].1));
+        assert!(found.alternatives().len() <= MAX_ALTERNATIVES);
+    }
+
+    #[test]
+    fn test_noise() {
+        let clean = License::fuzzy_match("creative commons attribution 4.0").unwrap();
+        let noisy =
+            License::fuzzy_match("some creative commons attribution 4.0 maybe, ask the editor")
+                .unwrap();
+        assert_eq!(noisy.license(), clean.license());
+        assert!(noisy.confidence() < clean.confidence());
+    }
+
+    #[test]
+    fn test_nothing() {
+        for text in ["", "All rights reserved", "Published by Springer", "2024"] {
+            assert_eq!(best(text), None, "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("by-nc-as", "by-nc-sa"), 1);
+        assert_eq!(edit_distance("by-nc-as", "by-nc-nd"), 2);
+        assert_eq!(edit_distance("atribution", "attribution"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+}
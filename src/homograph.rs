@@ -0,0 +1,169 @@
+/// The Creative Commons domain, as compared against folded hosts
+const DOMAIN: &str = "creativecommons.org";
+
+/// Whether the host of the URL imitates the Creative Commons domain with
+/// look-alike Unicode characters, written directly or punycode-encoded, e.g.
+/// `сreativecommons.org` with a Cyrillic `с`
+///
+/// The genuine domain, with or without `www.`, is never reported as spoofed.
+pub(crate) fn is_spoofed_domain(url: &str) -> bool {
+    let host = match host(url) {
+        Some(host) => host.to_lowercase(),
+        None => return false,
+    };
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+    if host == DOMAIN {
+        return false;
+    }
+    let folded: Vec<String> = host
+        .split(['.', '\u{3002}', '\u{ff0e}', '\u{ff61}'])
+        .map(|label| {
+            let label = label
+                .strip_prefix("xn--")
+                .and_then(decode_punycode)
+                .unwrap_or_else(|| label.to_owned());
+            label.chars().map(fold).collect()
+        })
+        .collect();
+    let folded = folded.join(".");
+    folded.strip_prefix("www.").unwrap_or(&folded) == DOMAIN
+}
+
+/// Locate the host of the URL, after an optional scheme and before the path
+fn host(url: &str) -> Option<&str> {
+    let rest = match url.split_once("://") {
+        Some((scheme, rest)) if scheme.eq_ignore_ascii_case("https") => rest,
+        Some((scheme, rest)) if scheme.eq_ignore_ascii_case("http") => rest,
+        Some(_) => return None,
+        None => url.strip_prefix("//").unwrap_or(url),
+    };
+    rest.split(['/', '?', '#']).next()
+}
+
+/// Map a lowercase character to the ASCII letter it is commonly mistaken for
+fn fold(c: char) -> char {
+    match c {
+        // Latin, Cyrillic and Greek look-alikes
+        'ɑ' | 'а' | 'α' => 'a',
+        'с' | 'ϲ' => 'c',
+        'ԁ' => 'd',
+        'е' | 'ε' => 'e',
+        'ɡ' => 'g',
+        'һ' => 'h',
+        'ı' | 'і' | 'ι' => 'i',
+        'ј' => 'j',
+        'ӏ' => 'l',
+        'о' | 'ο' | 'σ' => 'o',
+        'р' | 'ρ' => 'p',
+        'ѕ' => 's',
+        'ѵ' | 'ν' => 'v',
+        'ԝ' => 'w',
+        'х' => 'x',
+        'у' => 'y',
+        // fullwidth forms
+        '\u{ff41}'..='\u{ff5a}' => char::from(b'a' + (c as u32 - 0xff41) as u8),
+        _ => c,
+    }
+}
+
+/// Decode a punycode label without its `xn--` prefix, as specified by RFC 3492
+fn decode_punycode(input: &str) -> Option<String> {
+    const BASE: u32 = 36;
+    const T_MIN: u32 = 1;
+    const T_MAX: u32 = 26;
+    let (basic, extended) = match input.rfind('-') {
+        Some(i) => (&input[..i], &input[i + 1..]),
+        None => ("", input),
+    };
+    if !basic.is_ascii() {
+        return None;
+    }
+    let mut output: Vec<char> = basic.chars().collect();
+    let mut code_point: u32 = 0x80;
+    let mut bias = 72;
+    let mut i: u32 = 0;
+    let mut digits = extended.bytes().peekable();
+    while digits.peek().is_some() {
+        let old_i = i;
+        let mut weight = 1;
+        let mut k = BASE;
+        loop {
+            let digit = match digits.next()? {
+                b @ b'a'..=b'z' => b - b'a',
+                b @ b'A'..=b'Z' => b - b'A',
+                b @ b'0'..=b'9' => b - b'0' + 26,
+                _ => return None,
+            } as u32;
+            i = i.checked_add(digit.checked_mul(weight)?)?;
+            let threshold = k.saturating_sub(bias).clamp(T_MIN, T_MAX);
+            if digit < threshold {
+                break;
+            }
+            weight = weight.checked_mul(BASE - threshold)?;
+            k += BASE;
+        }
+        let len = output.len() as u32 + 1;
+        bias = adapt(i - old_i, len, old_i == 0);
+        code_point = code_point.checked_add(i / len)?;
+        i %= len;
+        output.insert(i as usize, char::from_u32(code_point)?);
+        i += 1;
+    }
+    Some(output.into_iter().collect())
+}
+
+/// Adapt the bias after decoding a code point, as specified by RFC 3492
+fn adapt(delta: u32, len: u32, first: bool) -> u32 {
+    let mut delta = if first { delta / 700 } else { delta / 2 };
+    delta += delta / len;
+    let mut k = 0;
+    while delta > 35 * 26 / 2 {
+        delta /= 35;
+        k += 36;
+    }
+    k + 36 * delta / (delta + 38)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_punycode() {
+        assert_eq!(decode_punycode("mnchen-3ya").as_deref(), Some("münchen"));
+        assert_eq!(
+            decode_punycode("reativecommons-40l").as_deref(),
+            Some("сreativecommons")
+        );
+        assert_eq!(decode_punycode("ls8h").as_deref(), Some("💩"));
+        assert_eq!(decode_punycode("mnchen-3y!"), None);
+        assert_eq!(decode_punycode("mnchen-3"), None);
+    }
+
+    #[test]
+    fn test_is_spoofed_domain() {
+        for url in [
+            "https://сreativecommons.org/licenses/by/4.0/",
+            "https://creativecоmmons.org/licenses/by/4.0/",
+            "http://www.СREATIVECOMMONS.ORG/licenses/by/4.0/",
+            "https://xn--reativecommons-40l.org/licenses/by/4.0/",
+            "https://www.xn--creativecmmons-3zl.org/",
+            "https://ｃreativecommons.org/licenses/by/4.0/",
+            "//creativecommonѕ.org/licenses/by/4.0/",
+            "creativecommons。org",
+        ] {
+            assert!(is_spoofed_domain(url), "{}", url);
+        }
+        for url in [
+            "https://creativecommons.org/licenses/by/4.0/",
+            "https://www.creativecommons.org/licenses/by/5.0/",
+            "https://CreativeCommons.org/",
+            "https://example.org/licenses/by/4.0/",
+            "https://xn--mnchen-3ya.de/",
+            "ftp://сreativecommons.org/",
+            "",
+        ] {
+            assert!(!is_spoofed_domain(url), "{}", url);
+        }
+    }
+}
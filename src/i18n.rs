@@ -0,0 +1,290 @@
+use crate::error::ParseError;
+use std::sync::OnceLock;
+
+/// Environment variables naming the locale of messages, by precedence
+const LOCALE_VARIABLES: [&str; 3] = ["LC_ALL", "LC_MESSAGES", "LANG"];
+
+static LANGUAGE: OnceLock<String> = OnceLock::new();
+static LOCALE_LANGUAGE: OnceLock<Option<String>> = OnceLock::new();
+
+/// Descriptions of every error in order of code, per language
+const TRANSLATIONS: &[(&str, &[&str])] = &[
+    (
+        "de",
+        &[
+            "Ungültige URL",
+            "Ungültige Rechteangabe",
+            "Ungültige Versionsangabe",
+            "Die Version von Gemeinfreiheitswerkzeugen muss 1.0 sein",
+            "Ungültiges Datum",
+            "Ungültiger Lizenzcode",
+            "Ungültige Nomenklatur",
+            "Ungültige Rechtsordnung",
+            "Nachahmung der Creative-Commons-Domain",
+            "Keine Lizenz gefunden",
+            "Ungültiges URL-Schema",
+            "Ungültige Domain",
+            "Fehlende Version",
+            "Unerwartete Segmente nach der Lizenz",
+        ],
+    ),
+    (
+        "es",
+        &[
+            "URL no válida",
+            "Cadena de derechos no válida",
+            "Cadena de versión no válida",
+            "La versión de las herramientas de dominio público debe ser 1.0",
+            "Fecha no válida",
+            "Código de licencia no válido",
+            "Nomenclatura no válida",
+            "Jurisdicción no válida",
+            "Imitación del dominio de Creative Commons",
+            "No se encontró ninguna licencia",
+            "Esquema de URL no válido",
+            "Dominio no válido",
+            "Falta la versión",
+            "Segmentos inesperados después de la licencia",
+        ],
+    ),
+    (
+        "fr",
+        &[
+            "URL invalide",
+            "Chaîne de droits invalide",
+            "Chaîne de version invalide",
+            "La version des outils du domaine public doit être 1.0",
+            "Date invalide",
+            "Code de licence invalide",
+            "Nomenclature invalide",
+            "Juridiction invalide",
+            "Imitation du domaine de Creative Commons",
+            "Aucune licence trouvée",
+            "Schéma d'URL invalide",
+            "Domaine invalide",
+            "Version manquante",
+            "Segments inattendus après la licence",
+        ],
+    ),
+    (
+        "it",
+        &[
+            "URL non valido",
+            "Stringa dei diritti non valida",
+            "Stringa della versione non valida",
+            "La versione degli strumenti di pubblico dominio deve essere 1.0",
+            "Data non valida",
+            "Codice di licenza non valido",
+            "Nomenclatura non valida",
+            "Giurisdizione non valida",
+            "Imitazione del dominio di Creative Commons",
+            "Nessuna licenza trovata",
+            "Schema dell'URL non valido",
+            "Dominio non valido",
+            "Versione mancante",
+            "Segmenti inattesi dopo la licenza",
+        ],
+    ),
+    (
+        "pt",
+        &[
+            "URL inválida",
+            "Cadeia de direitos inválida",
+            "Cadeia de versão inválida",
+            "A versão das ferramentas de domínio público deve ser 1.0",
+            "Data inválida",
+            "Código de licença inválido",
+            "Nomenclatura inválida",
+            "Jurisdição inválida",
+            "Imitação do domínio da Creative Commons",
+            "Nenhuma licença encontrada",
+            "Esquema de URL inválido",
+            "Domínio inválido",
+            "Versão ausente",
+            "Segmentos inesperados após a licença",
+        ],
+    ),
+];
+
+impl ParseError {
+    /// Languages error descriptions are translated into, besides English
+    pub const LANGUAGES: &'static [&'static str] = &["de", "es", "fr", "it", "pt"];
+
+    /// Obtain the description of the error in the given language
+    ///
+    /// The language is a tag such as `fr`, `pt-BR` or `pt_BR`, matched on its
+    /// primary subtag. Languages without a translation, see
+    /// [`ParseError::LANGUAGES`], fall back to the English
+    /// [description](ParseError::description).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{License, ParseError};
+    ///
+    /// let error = License::from_url("https://creativecommons.org/licenses/by/5.0/").unwrap_err();
+    /// assert_eq!(error.description_in("fr"), "Chaîne de version invalide");
+    /// assert_eq!(error.description_in("pt-BR"), "Cadeia de versão inválida");
+    /// assert_eq!(error.description_in("ja"), "Invalid version string");
+    /// ```
+    pub fn description_in(&self, language: &str) -> &'static str {
+        let primary = language.split(['-', '_']).next().unwrap_or(language);
+        TRANSLATIONS
+            .iter()
+            .find(|(tag, _)| tag.eq_ignore_ascii_case(primary))
+            .and_then(|(_, descriptions)| descriptions.get(*self as usize))
+            .copied()
+            .unwrap_or_else(|| self.description())
+    }
+
+    /// Obtain the description of the error in the language of the process, see
+    /// [`ParseError::language`]
+    pub fn localized_description(&self) -> &'static str {
+        self.description_in(ParseError::language())
+    }
+
+    /// Obtain the language errors are described in by
+    /// [`ParseError::localized_description`]
+    ///
+    /// This is the language set with [`ParseError::set_language`], or else the
+    /// language of the process locale, read from the `LC_ALL`, `LC_MESSAGES` and
+    /// `LANG` environment variables in that order, or else `en`.
+    ///
+    /// Only error descriptions are localized; license names and notices are
+    /// always in English.
+    pub fn language() -> &'static str {
+        if let Some(language) = LANGUAGE.get() {
+            return language;
+        }
+        LOCALE_LANGUAGE
+            .get_or_init(|| locale_language(|name| std::env::var(name).ok()))
+            .as_deref()
+            .unwrap_or("en")
+    }
+
+    /// Set the language errors are described in for the whole process,
+    /// overriding the process locale
+    ///
+    /// The language can only be set once; later calls return the rejected
+    /// language.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{License, ParseError};
+    ///
+    /// ParseError::set_language("es").unwrap();
+    /// assert!(ParseError::set_language("fr").is_err());
+    ///
+    /// let error = License::from_url("https://creativecommons.org/licenses/by/5.0/").unwrap_err();
+    /// assert_eq!(error.localized_description(), "Cadena de versión no válida");
+    /// ```
+    pub fn set_language(language: &str) -> Result<(), String> {
+        LANGUAGE.set(language.to_string())
+    }
+}
+
+/// The language of the locale named by the first set variable, e.g. `pt_BR` for
+/// `pt_BR.UTF-8`, or `None` for the `C` and `POSIX` locales
+fn locale_language(var: impl Fn(&str) -> Option<String>) -> Option<String> {
+    let locale = LOCALE_VARIABLES
+        .iter()
+        .filter_map(|name| var(name))
+        .find(|locale| !locale.is_empty())?;
+    let language = locale.split(['.', '@']).next().unwrap_or(&locale);
+    match language {
+        "" | "C" | "POSIX" => None,
+        _ => Some(language.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translations_are_complete() {
+        let tags: Vec<&str> = TRANSLATIONS.iter().map(|(tag, _)| *tag).collect();
+        assert_eq!(tags, ParseError::LANGUAGES);
+        for (tag, descriptions) in TRANSLATIONS {
+            assert_eq!(descriptions.len(), ParseError::ALL.len(), "{}", tag);
+            for (error, description) in ParseError::ALL.iter().zip(descriptions.iter()) {
+                assert!(!description.is_empty(), "{} {}", tag, error.code());
+                assert_ne!(
+                    *description,
+                    error.description(),
+                    "{} {}",
+                    tag,
+                    error.code()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_description_in() {
+        assert_eq!(ParseError::InvalidUrl.description_in("de"), "Ungültige URL");
+        assert_eq!(
+            ParseError::NotFound.description_in("es-419"),
+            "No se encontró ninguna licencia"
+        );
+        assert_eq!(
+            ParseError::MissingVersion.description_in("FR_ca"),
+            "Version manquante"
+        );
+        assert_eq!(
+            ParseError::TrailingSegments.description_in("it"),
+            "Segmenti inattesi dopo la licenza"
+        );
+        assert_eq!(
+            ParseError::InvalidPublicDomainVersion.description_in("pt_PT"),
+            "A versão das ferramentas de domínio público deve ser 1.0"
+        );
+    }
+
+    #[test]
+    fn test_locale_language() {
+        let environment = |variables: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                variables
+                    .iter()
+                    .find(|(variable, _)| *variable == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert_eq!(
+            locale_language(environment(&[("LANG", "pt_BR.UTF-8")])),
+            Some("pt_BR".to_string())
+        );
+        assert_eq!(
+            locale_language(environment(&[
+                ("LANG", "de_DE.UTF-8"),
+                ("LC_ALL", "fr_FR@euro")
+            ])),
+            Some("fr_FR".to_string())
+        );
+        assert_eq!(
+            locale_language(environment(&[
+                ("LC_ALL", ""),
+                ("LC_MESSAGES", "it"),
+                ("LANG", "de")
+            ])),
+            Some("it".to_string())
+        );
+        assert_eq!(locale_language(environment(&[("LANG", "C.UTF-8")])), None);
+        assert_eq!(locale_language(environment(&[("LC_ALL", "POSIX")])), None);
+        assert_eq!(locale_language(environment(&[])), None);
+    }
+
+    #[test]
+    fn test_fallback() {
+        for language in ["en", "en-GB", "ja", "", "-fr", "french"] {
+            assert_eq!(
+                ParseError::InvalidRights.description_in(language),
+                "Invalid rights string",
+                "{}",
+                language
+            );
+        }
+    }
+}
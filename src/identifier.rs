@@ -0,0 +1,141 @@
+use crate::error::ParseError;
+use crate::jurisdiction::Jurisdiction;
+use crate::table::{self, LICENSES};
+use crate::License;
+
+impl License {
+    /// Obtain a filename-safe identifier of the license, e.g. "cc-by-nc-sa-4.0"
+    ///
+    /// The identifier is lowercase, made only of ASCII letters, digits, hyphens and
+    /// dots, unique per license and guaranteed to stay the same across crate
+    /// versions. CC0 is identified as "cc0-1.0" and the Public Domain Dedication and
+    /// Certification as "cc-pddc". Ported licenses end with their jurisdiction, e.g.
+    /// "cc-by-3.0-de".
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::License;
+    ///
+    /// assert_eq!(License::CC_BY_NC_SA_4_0.identifier(), "cc-by-nc-sa-4.0");
+    /// assert_eq!(License::CC0_1_0.identifier(), "cc0-1.0");
+    /// ```
+    pub const fn identifier(&self) -> &'static str {
+        table::canonical_identifier(self)
+    }
+
+    /// Parse a Creative Commons license from its identifier, see [`License::identifier`]
+    ///
+    /// Only exact identifiers are accepted, without any normalisation of case or
+    /// whitespace.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// assert_eq!(License::from_identifier("cc-by-sa-2.5")?, License::CC_BY_SA_2_5);
+    /// assert_eq!(License::from_identifier("cc-by-sa-5.0"), Err(ParseError::InvalidVersion));
+    /// assert_eq!(License::from_identifier("CC-BY-SA-2.5"), Err(ParseError::InvalidRights));
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn from_identifier(identifier: &str) -> Result<Self, ParseError> {
+        if let Some(license) = LICENSES
+            .iter()
+            .find(|license| license.identifier() == identifier)
+        {
+            return Ok(*license);
+        }
+        if let Some((unported, port)) = identifier.rsplit_once('-') {
+            if let Some(license) = LICENSES
+                .iter()
+                .find(|license| license.identifier() == unported)
+            {
+                return match Jurisdiction::from_slug(port.as_bytes()) {
+                    Some(jurisdiction) => {
+                        License::ported(license.rights_enum(), license.version_enum(), jurisdiction)
+                    }
+                    None => Err(ParseError::InvalidJurisdiction),
+                };
+            }
+        }
+        let known_rights = identifier.rsplit_once('-').is_some_and(|(rights, _)| {
+            LICENSES.iter().any(|license| {
+                license
+                    .identifier()
+                    .strip_suffix(license.version_slug())
+                    .and_then(|prefix| prefix.strip_suffix('-'))
+                    == Some(rights)
+            })
+        });
+        if known_rights {
+            Err(ParseError::InvalidVersion)
+        } else {
+            Err(ParseError::InvalidRights)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rights::Rights;
+    use crate::version::Version;
+
+    #[test]
+    fn test_identifier() {
+        for (i, license) in LICENSES.iter().enumerate() {
+            let identifier = license.identifier();
+            assert!(identifier
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'.'));
+            assert!(LICENSES[i + 1..]
+                .iter()
+                .all(|other| other.identifier() != identifier));
+            assert_eq!(License::from_identifier(identifier), Ok(*license));
+        }
+        assert_eq!(License::CC_BY_1_0.identifier(), "cc-by-1.0");
+        assert_eq!(License::PDDC.identifier(), "cc-pddc");
+    }
+
+    #[test]
+    fn test_ported() {
+        let license =
+            License::ported(Rights::ByNcSa, Version::TwoFive, Jurisdiction::Scotland).unwrap();
+        assert_eq!(license.identifier(), "cc-by-nc-sa-2.5-scotland");
+        assert_eq!(License::from_identifier(license.identifier()), Ok(license));
+        assert_eq!(
+            License::from_identifier("cc-by-3.0-xx"),
+            Err(ParseError::InvalidJurisdiction)
+        );
+        assert_eq!(
+            License::from_identifier("cc0-1.0-de"),
+            Err(ParseError::InvalidJurisdiction)
+        );
+    }
+
+    #[test]
+    fn test_from_identifier_errors() {
+        assert_eq!(
+            License::from_identifier("cc-by-nc-2.6"),
+            Err(ParseError::InvalidVersion)
+        );
+        assert_eq!(
+            License::from_identifier("cc0-4.0"),
+            Err(ParseError::InvalidVersion)
+        );
+        assert_eq!(
+            License::from_identifier("cc-by-xx-4.0"),
+            Err(ParseError::InvalidRights)
+        );
+        assert_eq!(
+            License::from_identifier(" cc-by-4.0"),
+            Err(ParseError::InvalidRights)
+        );
+        assert_eq!(License::from_identifier(""), Err(ParseError::InvalidRights));
+    }
+}
@@ -0,0 +1,246 @@
+use crate::error::ParseError;
+use crate::status::Status;
+use crate::License;
+use std::fmt;
+
+/// A license or other legal tool that works like a Creative Commons license
+///
+/// Implement this trait to plug other open-content licenses, such as the Open
+/// Government Licence or the Free Art License, into the same formatting and
+/// policy machinery as [`License`], and register a parser for them with an
+/// [`InstrumentRegistry`].
+pub trait RightsInstrument: fmt::Debug + Send + Sync {
+    /// Stable key identifying the instrument, e.g. "ogl-uk/3.0"
+    ///
+    /// Keys must be unique across every registered instrument and should follow
+    /// the `<family>/<version>` shape of [`License::canonical_key`].
+    fn key(&self) -> &str;
+
+    /// Write the name of the instrument, e.g. "Open Government Licence v3.0"
+    fn write_title_to(&self, w: &mut dyn fmt::Write) -> fmt::Result;
+
+    /// Write the short name of the instrument, e.g. "OGL-UK-3.0"
+    fn write_short_to(&self, w: &mut dyn fmt::Write) -> fmt::Result;
+
+    /// Obtain the canonical URL of the instrument
+    fn url(&self) -> String;
+
+    /// Obtain whether the instrument is current or has been retired
+    fn status(&self) -> Status {
+        Status::Active
+    }
+
+    /// Obtain the Creative Commons license, if the instrument is one
+    fn as_license(&self) -> Option<&License> {
+        None
+    }
+
+    /// Write the full description of the instrument, i.e. its title followed by its
+    /// short name in parentheses
+    fn write_to(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        self.write_title_to(w)?;
+        w.write_str(" (")?;
+        self.write_short_to(w)?;
+        w.write_str(").")
+    }
+
+    /// Obtain the short name of the instrument
+    fn short(&self) -> String {
+        let mut short = String::new();
+        self.write_short_to(&mut short)
+            .expect("writing to a String cannot fail");
+        short
+    }
+}
+
+impl RightsInstrument for License {
+    fn key(&self) -> &str {
+        self.canonical_key()
+    }
+
+    fn write_title_to(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        License::write_title_to(self, w)
+    }
+
+    fn write_short_to(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        License::write_short_to(self, w)
+    }
+
+    fn url(&self) -> String {
+        self.to_url()
+    }
+
+    fn status(&self) -> Status {
+        License::status(self)
+    }
+
+    fn as_license(&self) -> Option<&License> {
+        Some(self)
+    }
+
+    fn write_to(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        License::write_to(self, w)
+    }
+}
+
+type InstrumentParser = Box<dyn Fn(&str) -> Option<Box<dyn RightsInstrument>> + Send + Sync>;
+
+/// Parser for Creative Commons licenses and any registered rights instruments
+///
+/// # Example
+///
+/// ```rust
+/// use cc_license::{InstrumentRegistry, ParseError, RightsInstrument};
+/// use std::fmt;
+///
+/// #[derive(Debug)]
+/// struct OpenGovernmentLicence;
+///
+/// impl RightsInstrument for OpenGovernmentLicence {
+///     fn key(&self) -> &str {
+///         "ogl-uk/3.0"
+///     }
+///
+///     fn write_title_to(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+///         w.write_str("Open Government Licence v3.0")
+///     }
+///
+///     fn write_short_to(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+///         w.write_str("OGL-UK-3.0")
+///     }
+///
+///     fn url(&self) -> String {
+///         "https://www.nationalarchives.gov.uk/doc/open-government-licence/version/3/".to_string()
+///     }
+/// }
+///
+/// let registry = InstrumentRegistry::new().register(|url| {
+///     url.contains("nationalarchives.gov.uk/doc/open-government-licence/version/3")
+///         .then(|| Box::new(OpenGovernmentLicence) as Box<dyn RightsInstrument>)
+/// });
+///
+/// let ogl = registry.parse("https://www.nationalarchives.gov.uk/doc/open-government-licence/version/3/")?;
+/// assert_eq!(ogl.short(), "OGL-UK-3.0");
+/// let by = registry.parse("https://creativecommons.org/licenses/by/4.0/")?;
+/// assert_eq!(by.key(), "by/4.0");
+/// assert!(by.as_license().is_some());
+/// # Ok::<(), ParseError>(())
+/// ```
+#[derive(Default)]
+pub struct InstrumentRegistry {
+    parsers: Vec<InstrumentParser>,
+}
+
+impl InstrumentRegistry {
+    /// Create a registry that only recognises Creative Commons licenses
+    pub fn new() -> Self {
+        InstrumentRegistry::default()
+    }
+
+    /// Register a parser returning an instrument for the URLs it recognises
+    ///
+    /// Parsers are tried in order of registration, after Creative Commons URLs.
+    pub fn register<F>(mut self, parser: F) -> Self
+    where
+        F: Fn(&str) -> Option<Box<dyn RightsInstrument>> + Send + Sync + 'static,
+    {
+        self.parsers.push(Box::new(parser));
+        self
+    }
+
+    /// Parse a URL into a Creative Commons license or a registered instrument
+    ///
+    /// When no registered parser recognises the URL, the error obtained while
+    /// parsing it as a Creative Commons license is returned.
+    pub fn parse(&self, url: &str) -> Result<Box<dyn RightsInstrument>, ParseError> {
+        let error = match License::from_url(url) {
+            Ok(license) => return Ok(Box::new(license)),
+            Err(e) => e,
+        };
+        self.parsers
+            .iter()
+            .find_map(|parser| parser(url))
+            .ok_or(error)
+    }
+}
+
+impl fmt::Debug for InstrumentRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InstrumentRegistry")
+            .field("parsers", &self.parsers.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct FreeArtLicense;
+
+    impl RightsInstrument for FreeArtLicense {
+        fn key(&self) -> &str {
+            "fal/1.3"
+        }
+
+        fn write_title_to(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+            w.write_str("Free Art License 1.3")
+        }
+
+        fn write_short_to(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+            w.write_str("FAL 1.3")
+        }
+
+        fn url(&self) -> String {
+            "https://artlibre.org/licence/lal/en/".to_string()
+        }
+    }
+
+    fn registry() -> InstrumentRegistry {
+        InstrumentRegistry::new().register(|url| {
+            (url == "https://artlibre.org/licence/lal/en/")
+                .then(|| Box::new(FreeArtLicense) as Box<dyn RightsInstrument>)
+        })
+    }
+
+    #[test]
+    fn test_license_instrument() {
+        let instrument = registry()
+            .parse("https://creativecommons.org/licenses/by-sa/3.0/")
+            .unwrap();
+        let license = License::from_url("https://creativecommons.org/licenses/by-sa/3.0/").unwrap();
+        assert_eq!(instrument.key(), "by-sa/3.0");
+        assert_eq!(instrument.short(), license.short());
+        assert_eq!(instrument.url(), license.to_url());
+        assert_eq!(instrument.as_license(), Some(&license));
+        let mut description = String::new();
+        instrument.write_to(&mut description).unwrap();
+        assert_eq!(description, license.to_string());
+    }
+
+    #[test]
+    fn test_registered_instrument() {
+        let registry = registry();
+        let instrument = registry
+            .parse("https://artlibre.org/licence/lal/en/")
+            .unwrap();
+        assert_eq!(instrument.key(), "fal/1.3");
+        assert_eq!(instrument.status(), Status::Active);
+        assert!(instrument.as_license().is_none());
+        let mut description = String::new();
+        instrument.write_to(&mut description).unwrap();
+        assert_eq!(description, "Free Art License 1.3 (FAL 1.3).");
+
+        assert_eq!(
+            registry
+                .parse("https://creativecommons.org/licenses/by/5.0/")
+                .unwrap_err(),
+            ParseError::InvalidVersion
+        );
+        assert_eq!(
+            registry.parse("https://example.com/").unwrap_err(),
+            ParseError::InvalidDomain
+        );
+    }
+}
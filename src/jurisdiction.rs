@@ -0,0 +1,303 @@
+use crate::error::ParseError;
+use crate::version::Version;
+use std::fmt;
+use std::str::FromStr;
+
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+macro_rules! jurisdictions {
+    ($d: tt $($variant: ident $slug: literal $code: expr, $name: literal [$($version: ident),+],)+) => {
+        /// Jurisdiction a license was ported to
+        ///
+        /// Between versions 1.0 and 3.0, Creative Commons published ports of its
+        /// licenses adapted to the law of specific jurisdictions, identified by an
+        /// extra segment of the URL, e.g. `licenses/by/3.0/de/`. Every jurisdiction
+        /// Creative Commons ever ported to is included, along with the versions
+        /// that were ported to it.
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+        #[non_exhaustive]
+        pub enum Jurisdiction {
+            $(
+                #[doc = $name]
+                $variant,
+            )+
+        }
+
+        impl Jurisdiction {
+            /// Every jurisdiction, in order
+            const ALL: &'static [Jurisdiction] = &[$(Jurisdiction::$variant,)+];
+
+            /// Iterate over every jurisdiction, in order
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// use cc_license::{Jurisdiction, Version};
+            ///
+            /// let ported_to_3_0 = Jurisdiction::iter().filter(|j| j.versions().contains(&Version::Three));
+            /// assert!(ported_to_3_0.count() > 30);
+            /// ```
+            pub fn iter() -> impl DoubleEndedIterator<Item = Jurisdiction> + ExactSizeIterator {
+                Jurisdiction::ALL.iter().copied()
+            }
+
+            pub(crate) const fn from_slug(slug: &[u8]) -> Option<Self> {
+                $(
+                    if bytes_eq(slug, $slug.as_bytes()) {
+                        return Some(Jurisdiction::$variant);
+                    }
+                )+
+                None
+            }
+
+            /// Obtain the URL segment identifying the jurisdiction, e.g. "de"
+            pub const fn slug(&self) -> &'static str {
+                match self {
+                    $(
+                        Jurisdiction::$variant => $slug,
+                    )+
+                }
+            }
+
+            /// Obtain the name of the jurisdiction, e.g. "Germany" or "UK: Scotland"
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $(
+                        Jurisdiction::$variant => $name,
+                    )+
+                }
+            }
+
+            /// Obtain the ISO 3166 code of the jurisdiction, e.g. "DE" or "GB-SCT"
+            ///
+            /// `None` for the port to intergovernmental organisations, which is not
+            /// a territory.
+            pub const fn code(&self) -> Option<&'static str> {
+                match self {
+                    $(
+                        Jurisdiction::$variant => $code,
+                    )+
+                }
+            }
+
+            /// Obtain the versions of the licenses ported to the jurisdiction, in
+            /// chronological order
+            pub const fn versions(&self) -> &'static [Version] {
+                match self {
+                    $(
+                        Jurisdiction::$variant => &[$(Version::$version),+],
+                    )+
+                }
+            }
+        }
+
+        /// Match every jurisdiction, concatenating the given literals with its slug
+        macro_rules! ported {
+            ($d jurisdiction: expr, $d ($d part: literal),+) => {
+                match $d jurisdiction {
+                    $(
+                        Jurisdiction::$variant => concat!($d ($d part,)+ $slug),
+                    )+
+                }
+            };
+        }
+        pub(crate) use ported;
+    }
+}
+
+jurisdictions! {
+    $
+    Argentina "ar" Some("AR"), "Argentina" [TwoFive],
+    Australia "au" Some("AU"), "Australia" [Two, TwoOne, TwoFive, Three],
+    Austria "at" Some("AT"), "Austria" [Two, Three],
+    Belgium "be" Some("BE"), "Belgium" [Two, Three],
+    Brazil "br" Some("BR"), "Brazil" [Two, TwoFive, Three],
+    Bulgaria "bg" Some("BG"), "Bulgaria" [TwoFive],
+    Canada "ca" Some("CA"), "Canada" [Two, TwoFive],
+    Chile "cl" Some("CL"), "Chile" [Two, Three],
+    China "cn" Some("CN"), "China Mainland" [TwoFive, Three],
+    Colombia "co" Some("CO"), "Colombia" [TwoFive],
+    CostaRica "cr" Some("CR"), "Costa Rica" [Three],
+    Croatia "hr" Some("HR"), "Croatia" [Two, TwoFive, Three],
+    CzechRepublic "cz" Some("CZ"), "Czech Republic" [Three],
+    Denmark "dk" Some("DK"), "Denmark" [TwoFive],
+    Ecuador "ec" Some("EC"), "Ecuador" [Three],
+    Egypt "eg" Some("EG"), "Egypt" [Three],
+    Estonia "ee" Some("EE"), "Estonia" [Three],
+    Finland "fi" Some("FI"), "Finland" [One],
+    France "fr" Some("FR"), "France" [Two, Three],
+    Germany "de" Some("DE"), "Germany" [Two, Three],
+    Greece "gr" Some("GR"), "Greece" [Three],
+    Guatemala "gt" Some("GT"), "Guatemala" [Three],
+    HongKong "hk" Some("HK"), "Hong Kong" [TwoFive, Three],
+    Hungary "hu" Some("HU"), "Hungary" [TwoFive],
+    India "in" Some("IN"), "India" [TwoFive],
+    Igo "igo" None, "IGO" [Three],
+    Ireland "ie" Some("IE"), "Ireland" [Three],
+    Israel "il" Some("IL"), "Israel" [One, TwoFive],
+    Italy "it" Some("IT"), "Italy" [Two, TwoFive, Three],
+    Japan "jp" Some("JP"), "Japan" [Two, TwoOne],
+    Korea "kr" Some("KR"), "Korea" [Two],
+    Luxembourg "lu" Some("LU"), "Luxembourg" [Three],
+    Macedonia "mk" Some("MK"), "Macedonia" [TwoFive],
+    Malaysia "my" Some("MY"), "Malaysia" [TwoFive],
+    Malta "mt" Some("MT"), "Malta" [TwoFive],
+    Mexico "mx" Some("MX"), "Mexico" [TwoFive],
+    Netherlands "nl" Some("NL"), "Netherlands" [One, Two, TwoFive, Three],
+    NewZealand "nz" Some("NZ"), "New Zealand" [Three],
+    Norway "no" Some("NO"), "Norway" [Three],
+    Peru "pe" Some("PE"), "Peru" [TwoFive],
+    Philippines "ph" Some("PH"), "Philippines" [Three],
+    Poland "pl" Some("PL"), "Poland" [TwoFive, Three],
+    Portugal "pt" Some("PT"), "Portugal" [TwoFive, Three],
+    PuertoRico "pr" Some("PR"), "Puerto Rico" [Three],
+    Romania "ro" Some("RO"), "Romania" [Three],
+    Serbia "rs" Some("RS"), "Serbia" [Three],
+    Singapore "sg" Some("SG"), "Singapore" [Three],
+    Slovenia "si" Some("SI"), "Slovenia" [TwoFive],
+    SouthAfrica "za" Some("ZA"), "South Africa" [TwoFive],
+    Spain "es" Some("ES"), "Spain" [Two, TwoOne, TwoFive, Three],
+    Sweden "se" Some("SE"), "Sweden" [TwoFive],
+    Switzerland "ch" Some("CH"), "Switzerland" [Two, TwoFive, Three],
+    Taiwan "tw" Some("TW"), "Taiwan" [Two, Three],
+    Thailand "th" Some("TH"), "Thailand" [Three],
+    Uganda "ug" Some("UG"), "Uganda" [Three],
+    EnglandAndWales "uk" Some("GB-EAW"), "UK: England & Wales" [Two],
+    Scotland "scotland" Some("GB-SCT"), "UK: Scotland" [TwoFive],
+    UnitedStates "us" Some("US"), "United States" [Three],
+    Vietnam "vn" Some("VN"), "Vietnam" [Three],
+}
+
+impl Jurisdiction {
+    /// Whether licenses of the version were ported to the jurisdiction
+    pub(crate) const fn has_version(&self, version: Version) -> bool {
+        let versions = self.versions();
+        let mut i = 0;
+        while i < versions.len() {
+            if versions[i] as u8 == version as u8 {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+}
+
+impl FromStr for Jurisdiction {
+    type Err = ParseError;
+
+    /// Parse the URL segment of the jurisdiction, ignoring ASCII case
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Jurisdiction::from_slug(s.to_ascii_lowercase().as_bytes())
+            .ok_or(ParseError::InvalidJurisdiction)
+    }
+}
+
+impl fmt::Display for Jurisdiction {
+    /// Format the slug in upper case, as in short names, e.g. "DE"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.slug().chars() {
+            fmt::Write::write_char(f, c.to_ascii_uppercase())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_slug() {
+        assert_eq!(Jurisdiction::from_slug(b"de"), Some(Jurisdiction::Germany));
+        assert_eq!(
+            Jurisdiction::from_slug(b"scotland"),
+            Some(Jurisdiction::Scotland)
+        );
+        assert_eq!(Jurisdiction::from_slug(b"DE"), None);
+        assert_eq!(Jurisdiction::from_slug(b"deed"), None);
+        assert_eq!(Jurisdiction::from_slug(b""), None);
+    }
+
+    #[test]
+    fn test_slug_round_trip() {
+        for jurisdiction in [
+            Jurisdiction::Argentina,
+            Jurisdiction::EnglandAndWales,
+            Jurisdiction::Vietnam,
+        ] {
+            assert_eq!(
+                Jurisdiction::from_slug(jurisdiction.slug().as_bytes()),
+                Some(jurisdiction)
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(Jurisdiction::Germany.to_string(), "DE");
+        assert_eq!(Jurisdiction::Scotland.to_string(), "SCOTLAND");
+        assert_eq!(Jurisdiction::EnglandAndWales.name(), "UK: England & Wales");
+    }
+
+    #[test]
+    fn test_table() {
+        assert_eq!(Jurisdiction::iter().len(), 59);
+        for (i, jurisdiction) in Jurisdiction::iter().enumerate() {
+            assert!(Jurisdiction::iter()
+                .skip(i + 1)
+                .all(|other| other.slug() != jurisdiction.slug()
+                    && other.name() != jurisdiction.name()));
+            assert_eq!(jurisdiction.slug().parse(), Ok(jurisdiction));
+            let versions = jurisdiction.versions();
+            assert!(!versions.is_empty());
+            assert!(versions.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(!versions.contains(&Version::Four));
+            for version in Version::iter() {
+                assert_eq!(
+                    jurisdiction.has_version(version),
+                    versions.contains(&version)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_codes() {
+        assert_eq!(Jurisdiction::Germany.code(), Some("DE"));
+        assert_eq!(Jurisdiction::Scotland.code(), Some("GB-SCT"));
+        assert_eq!(Jurisdiction::EnglandAndWales.code(), Some("GB-EAW"));
+        assert_eq!(Jurisdiction::Igo.code(), None);
+        assert_eq!(Jurisdiction::Igo.name(), "IGO");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("DE".parse(), Ok(Jurisdiction::Germany));
+        assert_eq!("Scotland".parse(), Ok(Jurisdiction::Scotland));
+        assert_eq!(
+            "gb".parse::<Jurisdiction>(),
+            Err(ParseError::InvalidJurisdiction)
+        );
+    }
+
+    #[test]
+    fn test_ported() {
+        assert_eq!(
+            ported!(Jurisdiction::Japan, "by-sa/", "2.0/"),
+            "by-sa/2.0/jp"
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use crate::error::ParseError;
+use crate::options::ParseOptions;
+use crate::License;
+
+impl License {
+    /// Parse a Creative Commons license from a URL found in messy metadata
+    ///
+    /// The URL is normalised before being parsed like [`License::from_url`]:
+    /// percent-encoded ASCII characters such as `%2F` are decoded, whitespace and
+    /// invisible characters such as zero-width spaces are removed,
+    /// as are surrounding quotes, brackets and trailing punctuation picked up when
+    /// copying the URL from text. ASCII case is ignored and a missing scheme,
+    /// including in scheme-relative URLs starting with `//`, defaults to `https`.
+    /// [`License::from_url`] itself stays strict.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url_lenient(" creativecommons.org/licenses/BY-SA/4.0/ ")?;
+    /// assert_eq!(license, License::CC_BY_SA_4_0);
+    /// assert_eq!(
+    ///     License::from_url_lenient("<https://creativecommons.org/licenses/by/4.0/>.")?,
+    ///     License::CC_BY_4_0
+    /// );
+    /// let license = License::from_url_lenient("//creativecommons.org/licenses/by/4.0/")?;
+    /// assert_eq!(license.to_url(), "https://creativecommons.org/licenses/by/4.0/");
+    /// assert_eq!(
+    ///     License::from_url_lenient("https://creativecommons.org/licenses%2Fby-nc%2F4.0%2F")?,
+    ///     License::CC_BY_NC_4_0
+    /// );
+    /// assert!(License::from_url("creativecommons.org/licenses/by-sa/4.0/").is_err());
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn from_url_lenient(url: &str) -> Result<Self, ParseError> {
+        let options = ParseOptions {
+            lenient: true,
+            ..*ParseOptions::global_default()
+        };
+        License::parse(url, &options)
+    }
+}
+
+/// Undo the damage commonly done to URLs by copying and pasting them
+pub(crate) fn normalise(url: &str) -> String {
+    let url: String = percent_decode(url)
+        .chars()
+        .filter(|c| !c.is_whitespace() && !is_invisible(*c))
+        .collect();
+    let url = url
+        .trim_start_matches(is_delimiter)
+        .trim_end_matches(|c| is_delimiter(c) || matches!(c, '.' | ',' | ';' | ':'));
+    let url = url.to_ascii_lowercase();
+    // keep any scheme, so that an unsupported one is reported as such
+    if url.split_once("://").is_some_and(|(scheme, _)| {
+        !scheme.is_empty() && scheme.bytes().all(|b| b.is_ascii_alphabetic())
+    }) {
+        return url;
+    }
+    let host = url.strip_prefix("//").unwrap_or(&url);
+    format!("https://{}", host)
+}
+
+/// Decode percent-encoded ASCII characters, once
+///
+/// Encoded bytes outside ASCII are left encoded, so that the result stays valid
+/// UTF-8 and still fails to parse as it should.
+pub(crate) fn percent_decode(url: &str) -> String {
+    let mut decoded = String::with_capacity(url.len());
+    let mut rest = url;
+    while let Some(start) = rest.find('%') {
+        decoded.push_str(&rest[..start]);
+        let escape = rest[start + 1..]
+            .get(..2)
+            .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+            .map(|hex| u8::from_str_radix(hex, 16).unwrap())
+            .filter(u8::is_ascii);
+        match escape {
+            Some(byte) => {
+                decoded.push(char::from(byte));
+                rest = &rest[start + 3..];
+            }
+            None => {
+                decoded.push('%');
+                rest = &rest[start + 1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Whether the character is a quote or bracket commonly wrapped around URLs
+fn is_delimiter(c: char) -> bool {
+    matches!(c, '"' | '\'' | '`' | '<' | '>' | '(' | ')' | '[' | ']')
+}
+
+/// Whether the character is invisible when rendered, such as a zero-width space
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00ad}' | '\u{200b}' | '\u{200c}' | '\u{200d}' | '\u{2060}' | '\u{feff}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalise() {
+        for url in [
+            "https://creativecommons.org/licenses/by/4.0/",
+            "  https://creativecommons.org/licenses/by/4.0/\n",
+            "creativecommons.org/licenses/by/4.0/",
+            "www.creativecommons.org/licenses/by/4.0",
+            "//creativecommons.org/licenses/by/4.0/",
+            "<//www.creativecommons.org/licenses/by/4.0/>",
+            "HTTPS://CREATIVECOMMONS.ORG/LICENSES/BY/4.0/",
+            "\"https://creativecommons.org/licenses/by/4.0/\"",
+            "(https://creativecommons.org/licenses/by/4.0/),",
+            "https://creativecommons.org/licenses/by/4.0/.",
+            "\u{feff}https://creativecommons.org/licenses/\u{200b}by/4.0/",
+            "https://creativecommons.org/licenses/\nby/4.0/",
+            "\u{a0}https://creativecommons.org/licenses/by/4.0/\u{a0}",
+        ] {
+            assert_eq!(
+                License::from_url_lenient(url),
+                Ok(License::CC_BY_4_0),
+                "{:?}",
+                url
+            );
+        }
+    }
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode("licenses%2Fby%2f4.0%2F"), "licenses/by/4.0/");
+        assert_eq!(percent_decode("https%3A%2F%2Fcc"), "https://cc");
+        assert_eq!(percent_decode("%252F"), "%2F");
+        assert_eq!(percent_decode("%C3%A9%"), "%C3%A9%");
+        assert_eq!(percent_decode("%2"), "%2");
+        assert_eq!(percent_decode("%+1%é"), "%+1%é");
+        assert_eq!(
+            License::from_url_lenient("https%3A%2F%2Fcreativecommons.org%2Flicenses%2Fby%2F4.0%2F"),
+            Ok(License::CC_BY_4_0)
+        );
+        assert_eq!(
+            License::from_url_lenient("https://creativecommons.org/licenses/by%252F4.0/"),
+            Err(ParseError::MissingVersion)
+        );
+    }
+
+    #[test]
+    fn test_still_rejects() {
+        assert_eq!(
+            License::from_url_lenient("creativecommons.org/licenses/by/5.0/"),
+            Err(ParseError::InvalidVersion)
+        );
+        assert_eq!(
+            License::from_url_lenient("example.org/licenses/by/4.0/"),
+            Err(ParseError::InvalidDomain)
+        );
+        assert_eq!(
+            License::from_url_lenient("ftp://creativecommons.org/licenses/by/4.0/"),
+            Err(ParseError::InvalidScheme)
+        );
+        assert_eq!(
+            License::from_url_lenient("///creativecommons.org/licenses/by/4.0/"),
+            Err(ParseError::InvalidDomain)
+        );
+        assert_eq!(
+            License::from_url_lenient(" сreativecommons.org/licenses/by/4.0/\u{200b}"),
+            Err(ParseError::SpoofedDomain)
+        );
+        assert_eq!(
+            License::from_url_lenient(""),
+            Err(ParseError::InvalidDomain)
+        );
+    }
+}
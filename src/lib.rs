@@ -1,24 +1,213 @@
+#[cfg(feature = "badges")]
+mod badge;
+mod batch;
+mod builder;
+mod cache;
+mod changes;
+mod chooser;
+mod code;
+#[cfg(feature = "corpus")]
+mod corpus;
+mod element;
+mod eq;
 mod error;
+mod explanation;
+mod failure;
+mod forms;
+mod fuzz;
+mod fuzzy;
+mod homograph;
+#[cfg(feature = "i18n")]
+mod i18n;
+mod identifier;
+mod instrument;
+mod jurisdiction;
+mod lenient;
+mod macros;
+mod map;
 mod nomenclature;
+mod options;
+mod parser;
+mod policy;
+#[cfg(feature = "rdf")]
+mod rdfa;
+mod record;
+mod redirect;
 mod rights;
+#[cfg(feature = "scan")]
+mod scan;
+mod schedule;
+mod short;
+mod spdx;
+mod status;
+mod table;
+#[cfg(feature = "terminal")]
+mod terminal;
+mod text;
+mod unknown;
+mod validator;
 mod version;
+mod view;
 
-pub use crate::error::ParseError;
-use crate::nomenclature::Nomenclature;
-use crate::rights::Rights;
-use crate::version::Version;
-use regex::Regex;
+#[cfg(feature = "parallel")]
+pub use crate::batch::parse_batch_par;
+pub use crate::batch::{parse_batch, BatchReport, ParsedBatch};
+pub use crate::builder::LicenseBuilder;
+pub use crate::cache::CachedParser;
+pub use crate::changes::{changes_between, Change};
+#[cfg(feature = "corpus")]
+pub use crate::corpus::{corpus, corpus_coverage, CorpusEntry, Coverage, CoverageReport};
+pub use crate::element::{Element, ElementFlags};
+pub use crate::error::{Error, ParseError};
+pub use crate::explanation::{Explanation, Term};
+pub use crate::failure::ParseFailure;
+pub use crate::forms::CanonicalForms;
+pub use crate::fuzz::fuzz_check;
+pub use crate::fuzzy::FuzzyMatch;
+pub use crate::instrument::{InstrumentRegistry, RightsInstrument};
+pub use crate::jurisdiction::Jurisdiction;
+pub use crate::map::LicenseMap;
+pub use crate::nomenclature::Nomenclature;
+pub use crate::options::{JurisdictionPolicy, ParseOptions, VersionPolicy};
+pub use crate::policy::{Decision, LicenseAllowlist};
+#[cfg(feature = "rdf")]
+pub use crate::rdfa::{read_rdfa, Attribution};
+pub use crate::record::{RecordError, RightsRecord};
+pub use crate::rights::Rights;
+#[cfg(feature = "scan")]
+pub use crate::scan::{Scanner, StreamScanner};
+pub use crate::schedule::{Date, EffectiveLicense, LicenseSchedule};
+pub use crate::status::Status;
+pub use crate::unknown::{Recognition, UnknownLicense};
+pub use crate::validator::Validator;
+pub use crate::version::Version;
+pub use crate::view::LicenseRef;
+use std::fmt;
 use std::str::FromStr;
 
-const CC_REGEX: &str = r"^https?://(www\.)?creativecommons\.org/(licenses|publicdomain)/(?P<rights>[^/]+)/(?P<version>[^/]+)/?$";
+/// Check whether a string has the shape of a Creative Commons license URL
+///
+/// This only checks the scheme, domain and path structure, without validating
+/// the rights and version segments, and never allocates or constructs a
+/// [`License`]. It is meant as a cheap filter ahead of full parsing.
+///
+/// # Example
+///
+/// ```rust
+/// use cc_license::is_cc_license_url;
+///
+/// assert!(is_cc_license_url("https://creativecommons.org/licenses/by/4.0/"));
+/// assert!(is_cc_license_url("https://creativecommons.org/licenses/by/5.0/"));
+/// assert!(!is_cc_license_url("https://creativecommons.org/about/"));
+/// assert!(!is_cc_license_url("https://example.org/licenses/by/4.0/"));
+/// ```
+pub const fn is_cc_license_url(url: &str) -> bool {
+    parser::split_url(url.as_bytes()).is_ok()
+}
 
-#[derive(Debug, PartialEq)]
+/// A Creative Commons license
+///
+/// `License` is `Copy` and guaranteed to occupy two bytes, with a niche that keeps
+/// `Option<License>` at two bytes as well, so it can be stored inline in large
+/// in-memory collections without allocation or indirection.
+///
+/// Licenses are ordered by rights, following the order of the [`Rights`]
+/// variants, then chronologically by [`Version`], then by [`Jurisdiction`] with
+/// the unported license first. The order is stable across crate versions, so it
+/// can be relied on for sorted exports.
+///
+/// Every valid unported license is also available as an associated constant, such
+/// as [`License::CC_BY_4_0`] or [`License::CC0_1_0`]. Licenses ported to a
+/// jurisdiction are created with [`License::ported`].
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub struct License {
-    rights: Rights,
-    version: Version,
+    /// Rights in the high bits and version in the low three bits, so that
+    /// licenses order by rights then version
+    packed: u8,
+    jurisdiction: Option<Jurisdiction>,
+}
+
+impl fmt::Debug for License {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("License")
+            .field("rights", &self.rights_enum())
+            .field("version", &self.version_enum())
+            .field("jurisdiction", &self.jurisdiction)
+            .finish()
+    }
 }
 
 impl License {
+    /// Assemble a license from its parts, without validating them
+    pub(crate) const fn from_parts(
+        rights: Rights,
+        version: Version,
+        jurisdiction: Option<Jurisdiction>,
+    ) -> License {
+        License {
+            packed: (rights as u8) << 3 | version as u8,
+            jurisdiction,
+        }
+    }
+
+    /// Create a license from its rights and version
+    ///
+    /// Fails with [`ParseError::InvalidPublicDomainVersion`] for CC0 or the Public
+    /// Domain Mark with a version other than 1.0, and with [`ParseError::InvalidVersion`] for the Public Domain
+    /// Dedication and Certification with a version other than 1.0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{License, ParseError, Rights, Version};
+    ///
+    /// let license = License::new(Rights::BySa, Version::Four)?;
+    /// assert_eq!(license.short(), "CC BY-SA 4.0".to_string());
+    /// assert_eq!(
+    ///     License::new(Rights::Zero, Version::Four),
+    ///     Err(ParseError::InvalidPublicDomainVersion)
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub const fn new(rights: Rights, version: Version) -> Result<Self, ParseError> {
+        let license = License::from_parts(rights, version, None);
+        match license.validate() {
+            Ok(()) => Ok(license),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Create a license ported to a jurisdiction
+    ///
+    /// Fails like [`License::new`], and with [`ParseError::InvalidJurisdiction`]
+    /// for licenses that were never ported to the jurisdiction, including every
+    /// 4.0 license and every public domain tool.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{Jurisdiction, License, ParseError, Rights, Version};
+    ///
+    /// let license = License::ported(Rights::By, Version::Three, Jurisdiction::Germany)?;
+    /// assert_eq!(license.short(), "CC BY 3.0 DE");
+    /// assert_eq!(
+    ///     License::ported(Rights::By, Version::Four, Jurisdiction::Germany),
+    ///     Err(ParseError::InvalidJurisdiction)
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub const fn ported(
+        rights: Rights,
+        version: Version,
+        jurisdiction: Jurisdiction,
+    ) -> Result<Self, ParseError> {
+        let license = License::from_parts(rights, version, Some(jurisdiction));
+        match license.validate() {
+            Ok(()) => Ok(license),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Parse a Creative Commons license from a URL
     ///
     /// # Example
@@ -35,20 +224,223 @@ impl License {
     /// # run().unwrap();
     /// ```
     pub fn from_url(url: &str) -> Result<Self, ParseError> {
-        let re = Regex::new(CC_REGEX).unwrap();
-        let captures = re.captures(url).ok_or(ParseError::InvalidUrl)?;
-        let rights = captures
-            .name("rights")
-            .ok_or(ParseError::InvalidUrl)
-            .and_then(|r| Rights::from_str(r.as_str()))?;
-        let version = captures
-            .name("version")
-            .ok_or(ParseError::InvalidUrl)
-            .and_then(|v| Version::from_str(v.as_str()))?;
-
-        let license = License { rights, version };
-        license.check()?;
-        Ok(license)
+        License::parse(url, ParseOptions::global_default())
+    }
+
+    /// Check whether the URL is a valid license URL without building the license
+    ///
+    /// Equivalent to `License::from_url(url).is_ok()`, for form validation and
+    /// filters that only need a yes or no. URLs outside the Creative Commons domain
+    /// are rejected before the path is looked at.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::License;
+    ///
+    /// assert!(License::is_valid_url("https://creativecommons.org/licenses/by-sa/4.0/"));
+    /// assert!(!License::is_valid_url("https://creativecommons.org/licenses/by-sa/5.0/"));
+    /// assert!(!License::is_valid_url("https://example.org/licenses/by-sa/4.0/"));
+    /// ```
+    pub fn is_valid_url(url: &str) -> bool {
+        License::matches_cc_domain(url) && License::from_url(url).is_ok()
+    }
+
+    /// Check whether the URL points to the Creative Commons website
+    ///
+    /// Accepts `http` and `https`, with or without the `www.` prefix, and any path.
+    /// Subdomains, ports and userinfo are rejected, as by the parser.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::License;
+    ///
+    /// assert!(License::matches_cc_domain("https://creativecommons.org/about/"));
+    /// assert!(!License::matches_cc_domain("https://creativecommons.org.example/"));
+    /// ```
+    pub const fn matches_cc_domain(url: &str) -> bool {
+        parser::matches_cc_domain(url.as_bytes())
+    }
+
+    /// Parse a Creative Commons license from a URL using the given options
+    ///
+    /// This is the single entry point for every parsing policy, see
+    /// [`ParseOptions`]. Failures are reported as a [`ParseFailure`] holding the
+    /// input and the segment of it that was rejected, which converts into the
+    /// plain [`ParseError`] with `?`. URLs whose host imitates the Creative Commons
+    /// domain with look-alike Unicode characters, written directly or
+    /// punycode-encoded, fail with [`ParseError::SpoofedDomain`] rather than
+    /// [`ParseError::InvalidDomain`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{License, ParseError, ParseOptions};
+    ///
+    /// let mut options = ParseOptions::new();
+    /// options.allow_www = false;
+    /// assert!(License::parse_with("https://creativecommons.org/licenses/by/4.0/", &options).is_ok());
+    /// let failure = License::parse_with("https://www.creativecommons.org/licenses/by/4.0/", &options).unwrap_err();
+    /// assert_eq!(failure.error(), ParseError::InvalidDomain);
+    /// assert_eq!(failure.rejected(), Some("www.creativecommons.org"));
+    /// let failure = License::parse_with("https://xn--reativecommons-40l.org/licenses/by/4.0/", &options).unwrap_err();
+    /// assert_eq!(failure.error(), ParseError::SpoofedDomain);
+    /// ```
+    pub fn parse_with(url: &str, options: &ParseOptions) -> Result<Self, ParseFailure> {
+        License::parse(url, options).map_err(|error| ParseFailure::new(url, error))
+    }
+
+    /// Parse a license URL using the given options, failing with the plain error
+    pub(crate) fn parse(url: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        let unwrapped;
+        let url = if options.unwrap_redirects {
+            unwrapped = redirect::unwrap(url);
+            &unwrapped
+        } else {
+            url
+        };
+        let normalised;
+        let url = if options.lenient {
+            normalised = lenient::normalise(url);
+            &normalised
+        } else {
+            url
+        };
+        match parser::parse_url(url, options) {
+            Err(ParseError::InvalidDomain) if homograph::is_spoofed_domain(url) => {
+                Err(ParseError::SpoofedDomain)
+            }
+            result => result,
+        }
+    }
+
+    /// Parse a Creative Commons license from its canonical URL only
+    ///
+    /// Rejects `http` with [`ParseError::InvalidScheme`], the `www.` prefix with
+    /// [`ParseError::InvalidDomain`], URLs of the license's pages with
+    /// [`ParseError::TrailingSegments`] and a missing trailing slash with
+    /// [`ParseError::InvalidUrl`], so that only the form
+    /// produced by [`License::to_url`] passes, whatever the global default
+    /// [`ParseOptions`]. See [`ParseOptions::strict`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{License, ParseError};
+    ///
+    /// assert_eq!(
+    ///     License::from_url_strict("https://creativecommons.org/licenses/by/4.0/"),
+    ///     Ok(License::CC_BY_4_0)
+    /// );
+    /// assert_eq!(
+    ///     License::from_url_strict("http://www.creativecommons.org/licenses/by/4.0"),
+    ///     Err(ParseError::InvalidScheme)
+    /// );
+    /// ```
+    pub const fn from_url_strict(url: &str) -> Result<Self, ParseError> {
+        parser::parse_url(url, &ParseOptions::strict())
+    }
+
+    /// Parse a Creative Commons license from a URL in a const context
+    ///
+    /// This is the function behind the [`cc_license!`] macro, which turns a parse
+    /// failure into a compile-time error. It always uses the default
+    /// [`ParseOptions`], since the global default is not available at compile time.
+    /// Look-alike domains are not detected in const contexts: they fail with
+    /// [`ParseError::InvalidDomain`] rather than [`ParseError::SpoofedDomain`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{License, ParseError};
+    ///
+    /// const LICENSE: Result<License, ParseError> =
+    ///     License::from_url_const("https://creativecommons.org/licenses/by-sa/4.0/");
+    /// assert_eq!(LICENSE.unwrap().short(), "CC BY-SA 4.0".to_string());
+    /// ```
+    pub const fn from_url_const(url: &str) -> Result<Self, ParseError> {
+        parser::parse_url(url, &ParseOptions::new())
+    }
+
+    /// Look up the interned instance of an unported license
+    ///
+    /// Every valid unported license exists exactly once in a static table, so the returned
+    /// references can be held cheaply and compared by pointer. Returns `None` for
+    /// invalid combinations, such as CC0 with a version other than 1.0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{License, Rights, Version};
+    ///
+    /// let license = License::get(Rights::ByNc, Version::Four).unwrap();
+    /// assert_eq!(license.short(), "CC BY-NC 4.0".to_string());
+    /// assert!(std::ptr::eq(license, License::get(Rights::ByNc, Version::Four).unwrap()));
+    /// assert!(License::get(Rights::Zero, Version::Four).is_none());
+    /// ```
+    pub fn get(rights: Rights, version: Version) -> Option<&'static License> {
+        table::index(rights, version).map(|index| &table::LICENSES[index])
+    }
+
+    /// Obtain every valid unported license, in order
+    ///
+    /// Includes every version of the six attribution licenses, CC0 1.0 and the
+    /// retired Public Domain Dedication and Certification, sorted as described on
+    /// [`License`]. Licenses ported to a jurisdiction are not included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::License;
+    ///
+    /// let options: Vec<String> = License::all().iter().map(License::short).collect();
+    /// assert_eq!(options.first().unwrap(), "CC BY 1.0");
+    /// assert!(options.contains(&"CC0 1.0".to_string()));
+    /// ```
+    pub fn all() -> &'static [License] {
+        table::LICENSES
+    }
+
+    /// Obtain the newest version of the license with the given rights
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{License, Rights};
+    ///
+    /// assert_eq!(License::latest(Rights::ByNcSa), License::CC_BY_NC_SA_4_0);
+    /// assert_eq!(License::latest(Rights::Zero), License::CC0_1_0);
+    /// ```
+    pub fn latest(rights: Rights) -> License {
+        *table::LICENSES
+            .iter()
+            .rfind(|license| license.rights_enum() == rights)
+            .expect("every rights module has at least one version")
+    }
+
+    /// Obtain the license with the same rights in another version
+    ///
+    /// Ported licenses stay ported to the same jurisdiction. Fails like
+    /// [`License::new`] and [`License::ported`] when the version does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{License, ParseError, Version};
+    ///
+    /// assert_eq!(License::CC_BY_SA_2_5.with_version(Version::Four)?, License::CC_BY_SA_4_0);
+    /// assert_eq!(
+    ///     License::CC0_1_0.with_version(Version::Four),
+    ///     Err(ParseError::InvalidPublicDomainVersion)
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub const fn with_version(&self, version: Version) -> Result<License, ParseError> {
+        match self.jurisdiction {
+            Some(jurisdiction) => License::ported(self.rights_enum(), version, jurisdiction),
+            None => License::new(self.rights_enum(), version),
+        }
     }
 
     /// Obtain the abbreviated rights string from a license
@@ -67,7 +459,7 @@ impl License {
     /// # run().unwrap();
     /// ```
     pub fn rights(&self) -> String {
-        self.rights.to_string()
+        self.rights_enum().to_string()
     }
 
     /// Obtain the rights string from a license
@@ -86,7 +478,7 @@ impl License {
     /// # run().unwrap();
     /// ```
     pub fn rights_full(&self) -> String {
-        self.rights.full_text().to_string()
+        self.rights_enum().full_text().to_string()
     }
 
     /// Obtain the version string from a license
@@ -105,7 +497,182 @@ impl License {
     /// # run().unwrap();
     /// ```
     pub fn version(&self) -> String {
-        self.version.to_string()
+        self.version_enum().to_string()
+    }
+
+    /// Obtain the rights of the license as a typed value
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::{License, Rights};
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("https://creativecommons.org/licenses/by-nc/4.0/")?;
+    /// match license.rights_enum() {
+    ///     Rights::ByNc | Rights::ByNcSa | Rights::ByNcNd => {}
+    ///     _ => panic!("expected a non-commercial license"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub const fn rights_enum(&self) -> Rights {
+        Rights::ALL[(self.packed >> 3) as usize]
+    }
+
+    /// Obtain the rights component of the canonical URL, e.g. "by-nc-sa"
+    ///
+    /// This is "zero" for CC0 and "publicdomain" for the Public Domain Dedication and
+    /// Certification.
+    pub const fn rights_slug(&self) -> &'static str {
+        match self.rights_enum() {
+            Rights::Certification => "publicdomain",
+            _ => self.rights_enum().slug(),
+        }
+    }
+
+    /// Obtain the version component of the canonical URL, e.g. "4.0"
+    ///
+    /// The Public Domain Dedication and Certification has no version in its URL,
+    /// but is reported as "1.0" like its [`License::version`].
+    pub const fn version_slug(&self) -> &'static str {
+        self.version_enum().slug()
+    }
+
+    /// Obtain the rights family of the license, shared by all its versions
+    pub const fn family(&self) -> Rights {
+        self.rights_enum()
+    }
+
+    /// Whether two licenses have the same rights, regardless of their version
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::License;
+    ///
+    /// assert!(License::CC_BY_3_0.same_family(&License::CC_BY_4_0));
+    /// assert!(!License::CC_BY_4_0.same_family(&License::CC_BY_SA_4_0));
+    /// ```
+    pub fn same_family(&self, other: &License) -> bool {
+        self.rights_enum() == other.rights_enum()
+    }
+
+    /// Obtain the version of the license as a typed value
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::{License, Version};
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("https://creativecommons.org/licenses/by-nc/4.0/")?;
+    /// assert_eq!(license.version_enum(), Version::Four);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub const fn version_enum(&self) -> Version {
+        Version::ALL[(self.packed & 0b111) as usize]
+    }
+
+    /// Obtain the jurisdiction the license was ported to, if any
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::{Jurisdiction, License};
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("http://creativecommons.org/licenses/by-sa/2.0/uk/")?;
+    /// assert_eq!(license.jurisdiction(), Some(Jurisdiction::EnglandAndWales));
+    /// assert_eq!(License::CC_BY_SA_2_0.jurisdiction(), None);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub const fn jurisdiction(&self) -> Option<Jurisdiction> {
+        self.jurisdiction
+    }
+
+    /// Obtain whether the license was ported to a jurisdiction
+    pub const fn is_ported(&self) -> bool {
+        self.jurisdiction.is_some()
+    }
+
+    /// Obtain whether the license is one of the 3.0 ports for intergovernmental
+    /// organisations
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("https://creativecommons.org/licenses/by-nc-sa/3.0/igo/")?;
+    /// assert!(license.is_igo());
+    /// assert_eq!(
+    ///     license.to_string(),
+    ///     "Creative Commons Attribution-NonCommercial-ShareAlike 3.0 IGO license (CC BY-NC-SA 3.0 IGO)."
+    /// );
+    /// assert!(!License::CC_BY_NC_SA_3_0.is_igo());
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub const fn is_igo(&self) -> bool {
+        matches!(self.jurisdiction, Some(Jurisdiction::Igo))
+    }
+
+    /// Obtain whether the license is the Public Domain Mark
+    ///
+    /// The Public Domain Mark is not a license nor a dedication like CC0: it labels
+    /// works that are already free of known copyright restrictions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let mark = License::from_url("https://creativecommons.org/publicdomain/mark/1.0/")?;
+    /// assert!(mark.is_public_domain_mark());
+    /// assert_eq!(mark.to_string(), "Public Domain Mark 1.0 (PDM 1.0).");
+    /// assert!(!License::CC0_1_0.is_public_domain_mark());
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub const fn is_public_domain_mark(&self) -> bool {
+        matches!(self.rights_enum(), Rights::Mark)
+    }
+
+    /// Obtain the unported license the license was ported from
+    pub const fn unported(&self) -> License {
+        License::from_parts(self.rights_enum(), self.version_enum(), None)
+    }
+
+    /// Obtain the wording qualifying the version of the license in its title
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{License, Nomenclature};
+    ///
+    /// assert_eq!(License::CC_BY_SA_3_0.nomenclature(), Nomenclature::Unported);
+    /// assert_eq!(
+    ///     format!("{} {}", License::CC_BY_4_0.version(), License::CC_BY_4_0.nomenclature()),
+    ///     "4.0 International"
+    /// );
+    /// ```
+    pub fn nomenclature(&self) -> Nomenclature {
+        Nomenclature::from(self)
     }
 
     /// Obtain the abbreviation of the license
@@ -124,12 +691,303 @@ impl License {
     /// # run().unwrap();
     /// ```
     pub fn short(&self) -> String {
-        format!("{} {}", self.rights, self.version)
+        let mut short = String::new();
+        self.write_short_to(&mut short)
+            .expect("writing to a String cannot fail");
+        short
+    }
+
+    /// Write the full description of the license without intermediate allocations
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("https://creativecommons.org/licenses/by/3.0/")?;
+    /// let mut export = String::from("License: ");
+    /// license.write_to(&mut export).unwrap();
+    /// assert_eq!(export, "License: Creative Commons Attribution 3.0 Unported license (CC BY 3.0).");
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn write_to<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        self.write_title_to(w)?;
+        if !self.rights_enum().is_unversioned() && !matches!(self.rights_enum(), Rights::Mark) {
+            w.write_str(" license")?;
+        }
+        w.write_str(" (")?;
+        self.write_short_to(w)?;
+        w.write_str(").")
+    }
+
+    /// Write the name of the license, e.g. "Creative Commons Attribution 4.0 International"
+    pub(crate) fn write_title_to<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        if self.rights_enum().is_unversioned() {
+            return write!(w, "Creative Commons {}", self.rights_enum().full_text());
+        }
+        if matches!(self.rights_enum(), Rights::Mark) {
+            return write!(
+                w,
+                "{} {}",
+                self.rights_enum().full_text(),
+                self.version_enum()
+            );
+        }
+        if self.rights_enum().is_sampling() || matches!(self.rights_enum(), Rights::DevNations) {
+            return write!(
+                w,
+                "Creative Commons {} {}",
+                self.rights_enum().full_text(),
+                self.version_enum()
+            );
+        }
+        match self.jurisdiction {
+            Some(jurisdiction) => write!(
+                w,
+                "Creative Commons {} {} {}",
+                self.rights_enum().full_text(),
+                self.version_enum(),
+                jurisdiction.name(),
+            ),
+            None => write!(
+                w,
+                "Creative Commons {} {} {}",
+                self.rights_enum().full_text(),
+                self.version_enum(),
+                Nomenclature::from(self),
+            ),
+        }
+    }
+
+    /// Write the abbreviation of the license without intermediate allocations
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("https://creativecommons.org/licenses/by-nd/4.0/")?;
+    /// let mut export = String::new();
+    /// license.write_short_to(&mut export).unwrap();
+    /// assert_eq!(export, "CC BY-ND 4.0");
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn write_short_to<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        if self.rights_enum().is_unversioned() {
+            return write!(w, "{}", self.rights_enum());
+        }
+        write!(w, "{} {}", self.rights_enum(), self.version_enum())?;
+        match self.jurisdiction {
+            Some(jurisdiction) => write!(w, " {}", jurisdiction),
+            None => Ok(()),
+        }
+    }
+
+    /// Obtain a stable key identifying the license
+    ///
+    /// The key is guaranteed to stay the same across crate versions and to be
+    /// unique per license, independently of any change to display strings, which
+    /// makes it suitable for deduplication maps, cache keys and database unique
+    /// constraints.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("http://www.creativecommons.org/licenses/by-nc-sa/4.0")?;
+    /// assert_eq!(license.canonical_key(), "by-nc-sa/4.0");
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub const fn canonical_key(&self) -> &'static str {
+        table::canonical_key(self)
+    }
+
+    /// Obtain whether the license is current or has been retired by Creative Commons
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::{License, Status};
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("http://creativecommons.org/licenses/publicdomain/")?;
+    /// assert_eq!(license.status(), Status::Retired);
+    /// assert_eq!(license.to_string(), "Creative Commons Public Domain Dedication and Certification (PDDC).".to_string());
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn status(&self) -> Status {
+        if self.rights_enum().is_retired() {
+            Status::Retired
+        } else {
+            Status::Active
+        }
+    }
+
+    /// Obtain whether the license has been retired by Creative Commons, see
+    /// [`License::status`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("http://creativecommons.org/licenses/devnations/2.0/")?;
+    /// assert!(license.is_retired());
+    /// assert_eq!(license.to_string(), "Creative Commons Developing Nations 2.0 license (CC DevNations 2.0).");
+    /// assert!(!License::CC_BY_2_0.is_retired());
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub const fn is_retired(&self) -> bool {
+        self.rights_enum().is_retired()
+    }
+
+    /// Obtain whether the instrument dedicates or marks works as public domain
+    /// rather than licensing them
+    ///
+    /// This holds for CC0, the Public Domain Mark and the retired Public Domain
+    /// Dedication and Certification.
+    pub const fn is_public_domain(&self) -> bool {
+        self.rights_enum().is_public_domain()
+    }
+
+    /// Obtain whether Creative Commons replaced the instrument with others
+    ///
+    /// Only the retired Public Domain Dedication and Certification is superseded,
+    /// see [`License::superseded_by`].
+    pub const fn is_superseded(&self) -> bool {
+        !self.superseded_by().is_empty()
     }
 
-    fn check(&self) -> Result<(), ParseError> {
-        if self.rights == Rights::Zero && self.version != Version::One {
-            return Err(ParseError::InvalidPublicDomainVersion);
+    /// Obtain the instruments Creative Commons recommends instead of this one
+    ///
+    /// The Public Domain Dedication and Certification is superseded by CC0 for
+    /// dedicating one's own works to the public domain, and by the Public Domain
+    /// Mark for labelling works already free of known copyright. Other licenses
+    /// are not superseded, even by later versions, and yield an empty slice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("http://creativecommons.org/licenses/publicdomain/")?;
+    /// assert!(license.is_public_domain());
+    /// assert!(license.is_superseded());
+    /// assert_eq!(license.superseded_by(), [License::CC0_1_0, License::PDM_1_0]);
+    /// assert!(License::CC_BY_2_0.superseded_by().is_empty());
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub const fn superseded_by(&self) -> &'static [License] {
+        match self.rights_enum() {
+            Rights::Certification => &[License::CC0_1_0, License::PDM_1_0],
+            _ => &[],
+        }
+    }
+
+    /// Obtain the canonical URL of the license
+    ///
+    /// The URL always uses `https`, no `www.` prefix and a trailing slash, and is
+    /// guaranteed to parse back into the same license with [`License::from_url`],
+    /// whatever the global default [`ParseOptions`], as long as it keeps the
+    /// jurisdiction of ported licenses.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("http://www.creativecommons.org/licenses/by-nc/2.0")?;
+    /// assert_eq!(license.to_url(), "https://creativecommons.org/licenses/by-nc/2.0/");
+    /// assert_eq!(License::from_url(&license.to_url())?, license);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn to_url(&self) -> String {
+        let mut url = String::new();
+        self.write_url_to(&mut url)
+            .expect("writing to a String cannot fail");
+        url
+    }
+
+    pub(crate) fn write_url_to<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        write!(w, "https://creativecommons.org/{}/", self.path())
+    }
+
+    /// Obtain the path of the canonical URL, e.g. "licenses/by-nc-sa/4.0"
+    ///
+    /// The path has no leading or trailing slash. The unversioned Public Domain
+    /// Dedication and Certification has the path "licenses/publicdomain".
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::License;
+    ///
+    /// let license = License::CC_BY_NC_SA_4_0;
+    /// assert_eq!(license.path(), "licenses/by-nc-sa/4.0");
+    /// assert_eq!(
+    ///     format!("https://licensebuttons.net/l/{}/{}/88x31.png", license.rights_slug(), license.version_slug()),
+    ///     "https://licensebuttons.net/l/by-nc-sa/4.0/88x31.png"
+    /// );
+    /// assert_eq!(License::CC0_1_0.path(), "publicdomain/zero/1.0");
+    /// ```
+    pub const fn path(&self) -> &'static str {
+        table::canonical_path(self)
+    }
+
+    /// Check that the rights and version form a license Creative Commons published
+    ///
+    /// These are the rules applied by every constructor, such as [`License::new`]
+    /// and the `TryFrom<(Rights, Version)>` implementation: the public domain
+    /// tools and the Sampling licenses only exist in version 1.0, the Developing
+    /// Nations license only in version 2.0, version 2.1
+    /// only exists as ports, and ported licenses only exist in the [versions](Jurisdiction::versions) ported to
+    /// their jurisdiction. Every `License` obtained from this crate passes.
+    pub const fn validate(&self) -> Result<(), ParseError> {
+        if let Some(sole) = self.rights_enum().sole_version() {
+            if sole as u8 != self.version_enum() as u8 {
+                return Err(match self.rights_enum() {
+                    Rights::Zero | Rights::Mark => ParseError::InvalidPublicDomainVersion,
+                    _ => ParseError::InvalidVersion,
+                });
+            }
+        }
+        if matches!(self.version_enum(), Version::TwoOne) && self.jurisdiction.is_none() {
+            return Err(ParseError::InvalidVersion);
+        }
+        if let Some(jurisdiction) = self.jurisdiction {
+            if self.rights_enum().sole_version().is_some()
+                || !jurisdiction.has_version(self.version_enum())
+            {
+                return Err(ParseError::InvalidJurisdiction);
+            }
         }
         Ok(())
     }
@@ -137,11 +995,16 @@ impl License {
 
 impl From<&License> for Nomenclature {
     fn from(license: &License) -> Self {
-        match license.rights {
-            Rights::Zero => Nomenclature::Universal,
-            _ => match license.version {
+        if license.jurisdiction.is_some() {
+            return Nomenclature::Ported;
+        }
+        match license.rights_enum() {
+            Rights::Zero | Rights::Mark => Nomenclature::Universal,
+            Rights::Certification => Nomenclature::Generic,
+            _ => match license.version_enum() {
                 Version::One => Nomenclature::Generic,
                 Version::Two => Nomenclature::Generic,
+                Version::TwoOne => Nomenclature::Generic,
                 Version::TwoFive => Nomenclature::Generic,
                 Version::Three => Nomenclature::Unported,
                 Version::Four => Nomenclature::International,
@@ -150,15 +1013,51 @@ impl From<&License> for Nomenclature {
     }
 }
 
-impl ToString for License {
-    fn to_string(&self) -> String {
-        format!(
-            "Creative Commons {} {} {} license ({}).",
-            self.rights_full(),
-            self.version,
-            Nomenclature::from(self),
-            self.short(),
-        )
+impl TryFrom<(Rights, Version)> for License {
+    type Error = ParseError;
+
+    /// Create a license from its rights and version, see [`License::new`]
+    fn try_from((rights, version): (Rights, Version)) -> Result<Self, Self::Error> {
+        License::new(rights, version)
+    }
+}
+
+impl FromStr for License {
+    type Err = ParseError;
+
+    /// Parse a license URL using the global default [`ParseOptions`], or a short
+    /// name such as "CC BY-NC 4.0" (see [`License::from_short_name`])
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains("://") {
+            License::from_url(s)
+        } else {
+            License::from_short_name(s)
+        }
+    }
+}
+
+impl fmt::Display for License {
+    /// Format the full description of the license, or its short name with the
+    /// alternate flag
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::License;
+    ///
+    /// let license = License::CC_BY_NC_4_0;
+    /// assert_eq!(format!("{:#}", license), "CC BY-NC 4.0");
+    /// assert_eq!(
+    ///     format!("{}", license),
+    ///     "Creative Commons Attribution-NonCommercial 4.0 International license (CC BY-NC 4.0)."
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            self.write_short_to(f)
+        } else {
+            self.write_to(f)
+        }
     }
 }
 
@@ -166,42 +1065,240 @@ impl ToString for License {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_cc_license_url() {
+        assert!(is_cc_license_url(
+            "http://www.creativecommons.org/publicdomain/zero/1.0"
+        ));
+        assert!(is_cc_license_url(
+            "https://creativecommons.org/licenses/attribution/4.0/"
+        ));
+        assert!(!is_cc_license_url("creativecommons.org/licenses/by/4.0/"));
+        assert!(is_cc_license_url(
+            "https://creativecommons.org/licenses/by/4.0/legalcode"
+        ));
+        assert!(!is_cc_license_url(
+            "https://creativecommons.org/licenses/by/4.0/faq"
+        ));
+        assert!(!is_cc_license_url(""));
+    }
+
+    #[test]
+    fn test_ported() {
+        let license = License::from_url("https://creativecommons.org/licenses/by/3.0/de/").unwrap();
+        assert_eq!(
+            license.to_string(),
+            "Creative Commons Attribution 3.0 Germany license (CC BY 3.0 DE)."
+        );
+        assert_eq!(
+            license.to_url(),
+            "https://creativecommons.org/licenses/by/3.0/de/"
+        );
+        assert_eq!(license.canonical_key(), "by/3.0/de");
+        assert_eq!(license.nomenclature(), Nomenclature::Ported);
+        assert_eq!(license.unported(), License::CC_BY_3_0);
+        assert!(license.is_ported());
+        assert!(!License::CC_BY_3_0.is_ported());
+        assert_eq!(
+            license.with_version(Version::Two).unwrap().to_url(),
+            "https://creativecommons.org/licenses/by/2.0/de/"
+        );
+        assert_eq!(
+            license.with_version(Version::TwoFive),
+            Err(ParseError::InvalidJurisdiction)
+        );
+        assert_eq!(
+            license.with_version(Version::Four),
+            Err(ParseError::InvalidJurisdiction)
+        );
+        assert!(license > License::CC_BY_3_0 && license < License::CC_BY_4_0);
+        assert_eq!(
+            License::from_url("http://creativecommons.org/licenses/by-nc-sa/2.5/scotland")
+                .unwrap()
+                .to_string(),
+            "Creative Commons Attribution-NonCommercial-ShareAlike 2.5 UK: Scotland license (CC BY-NC-SA 2.5 SCOTLAND)."
+        );
+    }
+
+    #[test]
+    fn test_igo() {
+        for rights in ["by", "by-sa", "by-nd", "by-nc", "by-nc-sa", "by-nc-nd"] {
+            let url = format!("https://creativecommons.org/licenses/{}/3.0/igo/", rights);
+            let license = License::from_url(&url).unwrap();
+            assert!(license.is_igo());
+            assert_eq!(license.to_url(), url);
+            assert!(license.short().ends_with(" 3.0 IGO"));
+        }
+        assert_eq!(
+            License::from_url("https://creativecommons.org/licenses/by/2.5/igo/"),
+            Err(ParseError::InvalidJurisdiction)
+        );
+        assert!(
+            !License::from_url("https://creativecommons.org/licenses/by/3.0/de/")
+                .unwrap()
+                .is_igo()
+        );
+    }
+
+    #[test]
+    fn test_superseded() {
+        let pddc = License::from_url("https://creativecommons.org/licenses/publicdomain").unwrap();
+        assert_eq!(pddc, License::PDDC);
+        assert!(pddc.is_public_domain());
+        assert!(pddc.is_superseded());
+        assert_eq!(pddc.status(), Status::Retired);
+        for license in pddc.superseded_by() {
+            assert!(license.is_public_domain());
+            assert!(!license.is_superseded());
+            assert_eq!(license.status(), Status::Active);
+        }
+        assert!(License::CC0_1_0.is_public_domain());
+        assert!(!License::CC_BY_4_0.is_public_domain());
+        assert!(!License::CC_BY_1_0.is_superseded());
+    }
+
+    #[test]
+    fn test_sampling() {
+        for (url, license, short) in [
+            (
+                "http://creativecommons.org/licenses/sampling/1.0/",
+                License::CC_SAMPLING_1_0,
+                "CC Sampling 1.0",
+            ),
+            (
+                "http://creativecommons.org/licenses/sampling+/1.0/",
+                License::CC_SAMPLING_PLUS_1_0,
+                "CC Sampling+ 1.0",
+            ),
+            (
+                "http://creativecommons.org/licenses/nc-sampling+/1.0/",
+                License::CC_NC_SAMPLING_PLUS_1_0,
+                "CC NC-Sampling+ 1.0",
+            ),
+        ] {
+            assert_eq!(License::from_url(url), Ok(license));
+            assert_eq!(license.status(), Status::Retired);
+            assert_eq!(license.short(), short);
+            assert_eq!(License::from_short_name(short), Ok(license));
+            assert_eq!(license.spdx_id(), None);
+        }
+        assert_eq!(
+            License::CC_SAMPLING_PLUS_1_0.to_string(),
+            "Creative Commons Sampling Plus 1.0 license (CC Sampling+ 1.0)."
+        );
+        assert_eq!(
+            License::from_url("https://creativecommons.org/licenses/sampling+/2.0/"),
+            Err(ParseError::InvalidVersion)
+        );
+        assert_eq!(
+            License::from_url("https://creativecommons.org/licenses/sampling+/1.0/br/"),
+            Err(ParseError::InvalidJurisdiction)
+        );
+    }
+
+    #[test]
+    fn test_dev_nations() {
+        let license =
+            License::from_url("https://creativecommons.org/licenses/devnations/2.0/").unwrap();
+        assert_eq!(license, License::CC_DEVNATIONS_2_0);
+        assert!(license.is_retired());
+        assert_eq!(license.short(), "CC DevNations 2.0");
+        assert_eq!(license.spdx_id(), None);
+        assert_eq!(License::from_short_name("cc devnations 2.0"), Ok(license));
+        assert_eq!(
+            License::new(Rights::DevNations, Version::One),
+            Err(ParseError::InvalidVersion)
+        );
+        assert_eq!(License::latest(Rights::DevNations), license);
+    }
+
+    #[test]
+    fn test_without_attribution() {
+        for (slug, license, short) in [
+            ("sa", License::CC_SA_1_0, "CC SA 1.0"),
+            ("nd", License::CC_ND_1_0, "CC ND 1.0"),
+            ("nc", License::CC_NC_1_0, "CC NC 1.0"),
+            ("nc-sa", License::CC_NC_SA_1_0, "CC NC-SA 1.0"),
+            ("nd-nc", License::CC_ND_NC_1_0, "CC ND-NC 1.0"),
+        ] {
+            let url = format!("http://creativecommons.org/licenses/{}/1.0/", slug);
+            assert_eq!(License::from_url(&url), Ok(license));
+            assert!(license.is_retired());
+            assert_eq!(license.short(), short);
+            assert_eq!(license.nomenclature(), Nomenclature::Generic);
+            let url = format!("http://creativecommons.org/licenses/{}/2.0/", slug);
+            assert_eq!(License::from_url(&url), Err(ParseError::InvalidVersion));
+        }
+        assert_eq!(
+            License::CC_ND_NC_1_0.to_string(),
+            "Creative Commons NoDerivs-NonCommercial 1.0 Generic license (CC ND-NC 1.0)."
+        );
+        assert_eq!(
+            License::ported(Rights::Nc, Version::One, Jurisdiction::Finland),
+            Err(ParseError::InvalidJurisdiction)
+        );
+    }
+
+    #[test]
+    fn test_public_domain_mark() {
+        let mark = License::from_url("http://creativecommons.org/publicdomain/mark/1.0/").unwrap();
+        assert_eq!(mark, License::PDM_1_0);
+        assert!(mark.is_public_domain_mark());
+        assert_ne!(mark, License::CC0_1_0);
+        assert_eq!(mark.rights_enum(), Rights::Mark);
+        assert_eq!(mark.short(), "PDM 1.0");
+        assert_eq!(mark.to_string(), "Public Domain Mark 1.0 (PDM 1.0).");
+        assert_eq!(
+            mark.to_url(),
+            "https://creativecommons.org/publicdomain/mark/1.0/"
+        );
+        assert_eq!(mark.canonical_key(), "mark/1.0");
+        assert_eq!(mark.spdx_id().as_deref(), Some("CC-PDM-1.0"));
+        assert_eq!(License::from_short_name("PDM"), Ok(mark));
+        assert_eq!(
+            License::new(Rights::Mark, Version::Four),
+            Err(ParseError::InvalidPublicDomainVersion)
+        );
+        assert_eq!(
+            License::ported(Rights::Mark, Version::One, Jurisdiction::Finland),
+            Err(ParseError::InvalidJurisdiction)
+        );
+    }
+
+    #[test]
+    fn test_representation() {
+        assert_eq!(std::mem::size_of::<License>(), 2);
+        assert_eq!(std::mem::size_of::<Option<License>>(), 2);
+        for rights in Rights::iter() {
+            for version in Version::iter() {
+                let license = License::from_parts(rights, version, None);
+                assert_eq!(license.rights_enum(), rights);
+                assert_eq!(license.version_enum(), version);
+            }
+        }
+    }
+
     #[test]
     fn test_from_url() {
         assert_eq!(
             License::from_url("https://creativecommons.org/licenses/by/4.0/").unwrap(),
-            License {
-                rights: Rights::By,
-                version: Version::Four,
-            }
+            License::from_parts(Rights::By, Version::Four, None)
         );
         assert_eq!(
             License::from_url("https://creativecommons.org/licenses/by-nc/1.0/").unwrap(),
-            License {
-                rights: Rights::ByNc,
-                version: Version::One,
-            }
+            License::from_parts(Rights::ByNc, Version::One, None)
         );
         assert_eq!(
             License::from_url("http://creativecommons.org/licenses/by-nc-sa/4.0/").unwrap(),
-            License {
-                rights: Rights::ByNcSa,
-                version: Version::Four,
-            }
+            License::from_parts(Rights::ByNcSa, Version::Four, None)
         );
         assert_eq!(
             License::from_url("https://creativecommons.org/licenses/by-nc-nd/3.0").unwrap(),
-            License {
-                rights: Rights::ByNcNd,
-                version: Version::Three,
-            }
+            License::from_parts(Rights::ByNcNd, Version::Three, None)
         );
         assert_eq!(
             License::from_url("https://creativecommons.org/publicdomain/zero/1.0/").unwrap(),
-            License {
-                rights: Rights::Zero,
-                version: Version::One,
-            }
+            License::from_parts(Rights::Zero, Version::One, None)
         );
 
         assert!(License::from_url("creativecommons.org/licenses/by/1.0/").is_err());
@@ -220,89 +1317,178 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            License::new(Rights::ByNcNd, Version::TwoFive),
+            Ok(License::from_parts(Rights::ByNcNd, Version::TwoFive, None))
+        );
+        assert!(License::new(Rights::Zero, Version::One).is_ok());
+        assert_eq!(
+            License::new(Rights::Zero, Version::Three),
+            Err(ParseError::InvalidPublicDomainVersion)
+        );
+        assert_eq!(
+            License::new(Rights::Certification, Version::Two),
+            Err(ParseError::InvalidVersion)
+        );
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut licenses = [
+            License::new(Rights::Zero, Version::One).unwrap(),
+            License::new(Rights::BySa, Version::Three).unwrap(),
+            License::new(Rights::By, Version::Four).unwrap(),
+            License::new(Rights::BySa, Version::Two).unwrap(),
+        ];
+        licenses.sort();
+        assert_eq!(
+            licenses
+                .iter()
+                .map(|license| license.short())
+                .collect::<Vec<_>>(),
+            ["CC BY 4.0", "CC BY-SA 2.0", "CC BY-SA 3.0", "CC0 1.0"]
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        let by_nd = License::new(Rights::ByNd, Version::Two).unwrap();
+        assert_eq!(
+            "https://creativecommons.org/licenses/by-nd/2.0/".parse(),
+            Ok(by_nd)
+        );
+        assert_eq!("CC BY-ND 2.0".parse(), Ok(by_nd));
+        assert_eq!(
+            "creativecommons.org/licenses/by-nd/2.0/".parse::<License>(),
+            Err(ParseError::InvalidRights)
+        );
+    }
+
+    #[test]
+    fn test_slugs() {
+        for license in License::all() {
+            let segments: Vec<&str> = license.path().split('/').collect();
+            assert_eq!(segments[1], license.rights_slug());
+            if license.rights_enum() != Rights::Certification {
+                assert_eq!(segments[2], license.version_slug());
+            }
+        }
+        assert_eq!(License::PDDC.version_slug(), "1.0");
+    }
+
+    #[test]
+    fn test_latest() {
+        for license in License::all() {
+            let latest = License::latest(license.rights_enum());
+            assert!(latest.same_family(license));
+            assert!(latest >= *license);
+        }
+        assert_eq!(License::latest(Rights::By).version_enum(), Version::Four);
+        assert_eq!(License::latest(Rights::Certification), License::PDDC);
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(
+            License::try_from((Rights::ByNc, Version::Two)),
+            Ok(License::CC_BY_NC_2_0)
+        );
+        assert_eq!(
+            License::try_from((Rights::Zero, Version::Two)),
+            Err(ParseError::InvalidPublicDomainVersion)
+        );
+        assert_eq!(
+            License::try_from((Rights::Certification, Version::Four)),
+            Err(ParseError::InvalidVersion)
+        );
+        assert!(License::all()
+            .iter()
+            .all(|license| license.validate().is_ok()));
+    }
+
+    #[test]
+    fn test_from_url_certification() {
+        let certification = License::from_parts(Rights::Certification, Version::One, None);
+        for url in [
+            "http://creativecommons.org/licenses/publicdomain/",
+            "https://creativecommons.org/licenses/publicdomain",
+            "https://creativecommons.org/publicdomain/certification/1.0/",
+        ] {
+            assert_eq!(License::from_url(url), Ok(certification));
+        }
+        assert_eq!(
+            License::from_url("https://creativecommons.org/publicdomain/certification/2.0/"),
+            Err(ParseError::InvalidVersion)
+        );
+        assert_eq!(certification.short(), "PDDC".to_string());
+        assert_eq!(certification.status(), Status::Retired);
+        assert_eq!(
+            License::from_url("https://creativecommons.org/licenses/by/4.0/")
+                .unwrap()
+                .status(),
+            Status::Active
+        );
+    }
+
+    #[test]
+    fn test_alternate_format() {
+        for license in License::all() {
+            assert_eq!(format!("{:#}", license), license.short());
+        }
+    }
+
     #[test]
     fn test_to_string() {
-        let mut test_license = License {
-            rights: Rights::By,
-            version: Version::One,
-        };
+        let mut test_license = License::from_parts(Rights::By, Version::One, None);
         assert_eq!(
             test_license.to_string(),
             "Creative Commons Attribution 1.0 Generic license (CC BY 1.0).".to_string()
         );
-        test_license = License {
-            rights: Rights::By,
-            version: Version::Two,
-        };
+        test_license = License::from_parts(Rights::By, Version::Two, None);
         assert_eq!(
             test_license.to_string(),
             "Creative Commons Attribution 2.0 Generic license (CC BY 2.0).".to_string()
         );
-        test_license = License {
-            rights: Rights::By,
-            version: Version::TwoFive,
-        };
+        test_license = License::from_parts(Rights::By, Version::TwoFive, None);
         assert_eq!(
             test_license.to_string(),
             "Creative Commons Attribution 2.5 Generic license (CC BY 2.5).".to_string()
         );
-        test_license = License {
-            rights: Rights::By,
-            version: Version::Three,
-        };
+        test_license = License::from_parts(Rights::By, Version::Three, None);
         assert_eq!(
             test_license.to_string(),
             "Creative Commons Attribution 3.0 Unported license (CC BY 3.0).".to_string()
         );
-        test_license = License {
-            rights: Rights::By,
-            version: Version::Four,
-        };
+        test_license = License::from_parts(Rights::By, Version::Four, None);
         assert_eq!(
             test_license.to_string(),
             "Creative Commons Attribution 4.0 International license (CC BY 4.0).".to_string()
         );
-        test_license = License {
-            rights: Rights::ByNc,
-            version: Version::Four,
-        };
+        test_license = License::from_parts(Rights::ByNc, Version::Four, None);
         assert_eq!(
             test_license.to_string(),
             "Creative Commons Attribution-NonCommercial 4.0 International license (CC BY-NC 4.0)."
                 .to_string()
         );
-        test_license = License {
-            rights: Rights::ByNd,
-            version: Version::Four,
-        };
+        test_license = License::from_parts(Rights::ByNd, Version::Four, None);
         assert_eq!(
             test_license.to_string(),
             "Creative Commons Attribution-NoDerivatives 4.0 International license (CC BY-ND 4.0)."
                 .to_string()
         );
-        test_license = License {
-            rights: Rights::BySa,
-            version: Version::Four,
-        };
+        test_license = License::from_parts(Rights::BySa, Version::Four, None);
         assert_eq!(
             test_license.to_string(),
             "Creative Commons Attribution-ShareAlike 4.0 International license (CC BY-SA 4.0)."
                 .to_string()
         );
-        test_license = License {
-            rights: Rights::ByNcSa,
-            version: Version::Four,
-        };
+        test_license = License::from_parts(Rights::ByNcSa, Version::Four, None);
         assert_eq!(test_license.to_string(), "Creative Commons Attribution-NonCommercial-ShareAlike 4.0 International license (CC BY-NC-SA 4.0).".to_string());
-        test_license = License {
-            rights: Rights::ByNcNd,
-            version: Version::Four,
-        };
+        test_license = License::from_parts(Rights::ByNcNd, Version::Four, None);
         assert_eq!(test_license.to_string(), "Creative Commons Attribution-NonCommercial-NoDerivatives 4.0 International license (CC BY-NC-ND 4.0).".to_string());
-        test_license = License {
-            rights: Rights::Zero,
-            version: Version::One,
-        };
+        test_license = License::from_parts(Rights::Zero, Version::One, None);
         assert_eq!(
             test_license.to_string(),
             "Creative Commons CC0 1.0 Universal license (CC0 1.0).".to_string()
@@ -311,38 +1497,20 @@ mod tests {
 
     #[test]
     fn to_nomenclature() {
-        let mut test_license = License {
-            rights: Rights::By,
-            version: Version::One,
-        };
+        let mut test_license = License::from_parts(Rights::By, Version::One, None);
         assert_eq!(Nomenclature::from(&test_license), Nomenclature::Generic);
-        test_license = License {
-            rights: Rights::By,
-            version: Version::Two,
-        };
+        test_license = License::from_parts(Rights::By, Version::Two, None);
         assert_eq!(Nomenclature::from(&test_license), Nomenclature::Generic);
-        test_license = License {
-            rights: Rights::By,
-            version: Version::TwoFive,
-        };
+        test_license = License::from_parts(Rights::By, Version::TwoFive, None);
         assert_eq!(Nomenclature::from(&test_license), Nomenclature::Generic);
-        test_license = License {
-            rights: Rights::By,
-            version: Version::Three,
-        };
+        test_license = License::from_parts(Rights::By, Version::Three, None);
         assert_eq!(Nomenclature::from(&test_license), Nomenclature::Unported);
-        test_license = License {
-            rights: Rights::By,
-            version: Version::Four,
-        };
+        test_license = License::from_parts(Rights::By, Version::Four, None);
         assert_eq!(
             Nomenclature::from(&test_license),
             Nomenclature::International
         );
-        test_license = License {
-            rights: Rights::Zero,
-            version: Version::One,
-        };
+        test_license = License::from_parts(Rights::Zero, Version::One, None);
         assert_eq!(Nomenclature::from(&test_license), Nomenclature::Universal);
     }
 }
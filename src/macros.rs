@@ -0,0 +1,48 @@
+/// Build a [`License`](crate::License) from a URL literal, validated at compile time
+///
+/// The URL is parsed in a const context, so a typo in a hard-coded license URL
+/// fails the build instead of surfacing as a runtime error.
+///
+/// # Example
+///
+/// ```rust
+/// use cc_license::cc_license;
+///
+/// let license = cc_license!("https://creativecommons.org/licenses/by/4.0/");
+/// assert_eq!(license.short(), "CC BY 4.0".to_string());
+/// ```
+///
+/// Invalid URLs are rejected by the compiler:
+///
+/// ```compile_fail
+/// use cc_license::cc_license;
+///
+/// let license = cc_license!("https://creativecommons.org/licenses/by/5.0/");
+/// ```
+#[macro_export]
+macro_rules! cc_license {
+    ($url:literal $(,)?) => {{
+        const LICENSE: $crate::License = match $crate::License::from_url_const($url) {
+            ::core::result::Result::Ok(license) => license,
+            ::core::result::Result::Err(e) => ::core::panic!("{}", e.description()),
+        };
+        LICENSE
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::License;
+
+    #[test]
+    fn test_cc_license() {
+        assert_eq!(
+            cc_license!("https://creativecommons.org/licenses/by-nc-nd/3.0/"),
+            License::from_url("https://creativecommons.org/licenses/by-nc-nd/3.0/").unwrap()
+        );
+        assert_eq!(
+            cc_license!("https://creativecommons.org/publicdomain/zero/1.0/",),
+            License::from_url("https://creativecommons.org/publicdomain/zero/1.0/").unwrap()
+        );
+    }
+}
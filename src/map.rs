@@ -0,0 +1,286 @@
+use crate::error::ParseError;
+use crate::License;
+use std::fmt;
+use std::str::FromStr;
+
+/// Licenses of the components of a single work
+///
+/// Monographs and other complex works often license their parts differently: the
+/// cover under one license and the text under another, chapters individually, or
+/// each format with its own terms. Components are kept in insertion order.
+///
+/// Formats as one line per component, made of the component and the canonical URL
+/// of its license separated by a space, e.g.
+/// `cover https://creativecommons.org/licenses/by-nc-nd/4.0/`. Maps with `String`
+/// components parse back from that form, as long as no component spans lines.
+///
+/// # Example
+///
+/// ```rust
+/// # use cc_license::ParseError;
+/// use cc_license::{License, LicenseMap};
+///
+/// # fn run() -> Result<(), ParseError> {
+/// let mut map = LicenseMap::new();
+/// map.insert("text", License::from_url("https://creativecommons.org/licenses/by/4.0/")?);
+/// map.insert("cover", License::from_url("https://creativecommons.org/licenses/by-nc-nd/4.0/")?);
+/// map.insert("figures", License::from_url("https://creativecommons.org/licenses/by-sa/4.0/")?);
+///
+/// let (component, license) = map.most_restrictive().unwrap();
+/// assert_eq!(*component, "cover");
+/// assert_eq!(license.short(), "CC BY-NC-ND 4.0");
+/// assert!(!map.is_uniform());
+///
+/// let text = map.to_string();
+/// assert!(text.starts_with("text https://creativecommons.org/licenses/by/4.0/\n"));
+/// let parsed: LicenseMap<String> = text.parse()?;
+/// assert_eq!(parsed.get(&"cover".to_string()), map.get(&"cover"));
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct LicenseMap<K> {
+    entries: Vec<(K, License)>,
+}
+
+impl<K> Default for LicenseMap<K> {
+    fn default() -> Self {
+        LicenseMap {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<K: PartialEq> LicenseMap<K> {
+    /// Create an empty map
+    pub fn new() -> Self {
+        LicenseMap::default()
+    }
+
+    /// Set the license of a component, returning its previous license
+    pub fn insert(&mut self, component: K, license: License) -> Option<License> {
+        match self.entries.iter_mut().find(|(k, _)| *k == component) {
+            Some((_, previous)) => Some(std::mem::replace(previous, license)),
+            None => {
+                self.entries.push((component, license));
+                None
+            }
+        }
+    }
+
+    /// Obtain the license of a component
+    pub fn get(&self, component: &K) -> Option<&License> {
+        self.entries
+            .iter()
+            .find(|(k, _)| k == component)
+            .map(|(_, license)| license)
+    }
+
+    /// Remove a component, returning its license
+    pub fn remove(&mut self, component: &K) -> Option<License> {
+        let index = self.entries.iter().position(|(k, _)| k == component)?;
+        Some(self.entries.remove(index).1)
+    }
+}
+
+impl<K> LicenseMap<K> {
+    /// Iterate over the components and their licenses, in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &License)> {
+        self.entries.iter().map(|(k, license)| (k, license))
+    }
+
+    /// Number of components
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the map has no components
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Distinct licenses applied to the components, in order of first use
+    pub fn licenses(&self) -> Vec<License> {
+        let mut licenses: Vec<License> = Vec::new();
+        for (_, license) in &self.entries {
+            if !licenses.contains(license) {
+                licenses.push(*license);
+            }
+        }
+        licenses
+    }
+
+    /// Whether every component has the same license
+    pub fn is_uniform(&self) -> bool {
+        self.licenses().len() <= 1
+    }
+
+    /// Obtain the component with the most restrictive license
+    ///
+    /// Licenses are ranked along the Creative Commons spectrum, from CC0 through
+    /// BY, BY-SA, BY-NC, BY-NC-SA and BY-ND to BY-NC-ND, followed by the retired
+    /// Sampling and Developing Nations licenses. The retired 1.0 licenses without
+    /// attribution rank with their attribution counterparts. The first component wins a tie.
+    pub fn most_restrictive(&self) -> Option<(&K, &License)> {
+        self.iter().reduce(|most, entry| {
+            if entry.1.rights_enum().restrictiveness() > most.1.rights_enum().restrictiveness() {
+                entry
+            } else {
+                most
+            }
+        })
+    }
+
+    /// Obtain the component with the least restrictive license
+    ///
+    /// See [`LicenseMap::most_restrictive`] for the ranking. The first component
+    /// wins a tie.
+    pub fn least_restrictive(&self) -> Option<(&K, &License)> {
+        self.iter().reduce(|least, entry| {
+            if entry.1.rights_enum().restrictiveness() < least.1.rights_enum().restrictiveness() {
+                entry
+            } else {
+                least
+            }
+        })
+    }
+}
+
+impl<K: PartialEq> FromIterator<(K, License)> for LicenseMap<K> {
+    fn from_iter<I: IntoIterator<Item = (K, License)>>(iter: I) -> Self {
+        let mut map = LicenseMap::new();
+        for (component, license) in iter {
+            map.insert(component, license);
+        }
+        map
+    }
+}
+
+impl<K: fmt::Display> fmt::Display for LicenseMap<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (component, license)) in self.entries.iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            write!(f, "{} ", component)?;
+            license.write_url_to(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for LicenseMap<String> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map = LicenseMap::new();
+        for line in s.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let (component, url) = line.rsplit_once(' ').ok_or(ParseError::InvalidUrl)?;
+            map.insert(component.trim_end().to_string(), License::from_url(url)?);
+        }
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rights::Rights;
+    use crate::version::Version;
+
+    fn license(rights: Rights) -> License {
+        License::from_parts(rights, Version::Four, None)
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut map = LicenseMap::new();
+        assert_eq!(map.insert(1, license(Rights::By)), None);
+        assert_eq!(map.insert(2, license(Rights::BySa)), None);
+        assert_eq!(
+            map.insert(1, license(Rights::ByNd)),
+            Some(license(Rights::By))
+        );
+        assert_eq!(map.get(&1), Some(&license(Rights::ByNd)));
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.remove(&2), Some(license(Rights::BySa)));
+        assert_eq!(map.remove(&2), None);
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            vec![(&1, &license(Rights::ByNd))]
+        );
+    }
+
+    #[test]
+    fn test_aggregates() {
+        let map: LicenseMap<&str> = [
+            ("chapter 1", license(Rights::BySa)),
+            ("chapter 2", license(Rights::ByNc)),
+            ("chapter 3", license(Rights::BySa)),
+            ("chapter 4", license(Rights::ByNc)),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            map.most_restrictive(),
+            Some((&"chapter 2", &license(Rights::ByNc)))
+        );
+        assert_eq!(
+            map.least_restrictive(),
+            Some((&"chapter 1", &license(Rights::BySa)))
+        );
+        assert_eq!(
+            map.licenses(),
+            vec![license(Rights::BySa), license(Rights::ByNc)]
+        );
+        assert!(!map.is_uniform());
+
+        let empty = LicenseMap::<&str>::new();
+        assert!(empty.is_empty());
+        assert!(empty.is_uniform());
+        assert_eq!(empty.most_restrictive(), None);
+    }
+
+    #[test]
+    fn test_restrictiveness_spectrum() {
+        let spectrum = [
+            Rights::Zero,
+            Rights::By,
+            Rights::BySa,
+            Rights::ByNc,
+            Rights::ByNcSa,
+            Rights::ByNd,
+            Rights::ByNcNd,
+        ];
+        assert!(spectrum
+            .windows(2)
+            .all(|pair| pair[0].restrictiveness() < pair[1].restrictiveness()));
+    }
+
+    #[test]
+    fn test_text() {
+        let map: LicenseMap<String> = [
+            ("front cover".to_string(), license(Rights::ByNcNd)),
+            ("text".to_string(), license(Rights::By)),
+        ]
+        .into_iter()
+        .collect();
+        let text = map.to_string();
+        assert_eq!(
+            text,
+            "front cover https://creativecommons.org/licenses/by-nc-nd/4.0/\n\
+            text https://creativecommons.org/licenses/by/4.0/"
+        );
+        assert_eq!(text.parse(), Ok(map));
+        assert_eq!("".parse(), Ok(LicenseMap::<String>::new()));
+        assert_eq!(
+            "https://creativecommons.org/licenses/by/4.0/".parse::<LicenseMap<String>>(),
+            Err(ParseError::InvalidUrl)
+        );
+        assert_eq!(
+            "text https://creativecommons.org/licenses/by/5.0/".parse::<LicenseMap<String>>(),
+            Err(ParseError::InvalidVersion)
+        );
+    }
+}
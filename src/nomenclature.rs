@@ -1,11 +1,20 @@
+use crate::error::ParseError;
 use std::fmt;
+use std::str::FromStr;
 
-#[derive(Debug, PartialEq)]
-pub(crate) enum Nomenclature {
+/// Wording qualifying the version of a license in its title
+///
+/// Licenses before 3.0 are Generic, 3.0 licenses are Unported, 4.0 licenses are
+/// International and CC0 is Universal. Licenses ported to a jurisdiction are
+/// Ported, and their title names the jurisdiction instead.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[non_exhaustive]
+pub enum Nomenclature {
     Generic,
     Unported,
     International,
     Universal,
+    Ported,
 }
 
 impl fmt::Display for Nomenclature {
@@ -15,11 +24,30 @@ impl fmt::Display for Nomenclature {
             Nomenclature::Unported => "Unported",
             Nomenclature::International => "International",
             Nomenclature::Universal => "Universal",
+            Nomenclature::Ported => "Ported",
         };
         write!(f, "{}", nomenclature)
     }
 }
 
+impl FromStr for Nomenclature {
+    type Err = ParseError;
+
+    /// Parse the word as displayed, ignoring ASCII case
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Nomenclature::Generic,
+            Nomenclature::Unported,
+            Nomenclature::International,
+            Nomenclature::Universal,
+            Nomenclature::Ported,
+        ]
+        .into_iter()
+        .find(|nomenclature| nomenclature.to_string().eq_ignore_ascii_case(s))
+        .ok_or(ParseError::InvalidNomenclature)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,4 +68,30 @@ mod tests {
             "Universal".to_string()
         );
     }
+
+    #[test]
+    fn test_from_string() {
+        assert_eq!(Nomenclature::from_str("Generic"), Ok(Nomenclature::Generic));
+        assert_eq!(
+            Nomenclature::from_str("unported"),
+            Ok(Nomenclature::Unported)
+        );
+        assert_eq!(
+            Nomenclature::from_str("INTERNATIONAL"),
+            Ok(Nomenclature::International)
+        );
+        assert_eq!(
+            Nomenclature::from_str("Universal"),
+            Ok(Nomenclature::Universal)
+        );
+        assert_eq!(Nomenclature::from_str("ported"), Ok(Nomenclature::Ported));
+        assert_eq!(
+            Nomenclature::from_str("Global"),
+            Err(ParseError::InvalidNomenclature)
+        );
+        assert_eq!(
+            Nomenclature::from_str(" Generic"),
+            Err(ParseError::InvalidNomenclature)
+        );
+    }
 }
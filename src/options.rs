@@ -0,0 +1,187 @@
+use std::sync::OnceLock;
+
+/// How the jurisdiction of ported licenses is handled when parsing URLs
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[non_exhaustive]
+pub enum JurisdictionPolicy {
+    /// Parse into the ported license
+    Keep,
+    /// Parse into the unported license the port is based on, once the port is
+    /// checked to exist
+    Strip,
+    /// Fail with [`ParseError::InvalidJurisdiction`](crate::ParseError::InvalidJurisdiction)
+    Reject,
+}
+
+/// How versions unknown to the crate, such as `5.0`, are handled when parsing URLs
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[non_exhaustive]
+pub enum VersionPolicy {
+    /// Fail with [`ParseError::InvalidVersion`](crate::ParseError::InvalidVersion)
+    ///
+    /// Use [`License::from_url_forward_compatible`](crate::License::from_url_forward_compatible)
+    /// to recognise well-formed URLs of versions published after this version of
+    /// the crate instead.
+    Reject,
+}
+
+static GLOBAL_DEFAULT: OnceLock<ParseOptions> = OnceLock::new();
+
+/// Policies applied when parsing license URLs
+///
+/// The default options accept `http` and `https`, an optional `www.` prefix, the
+/// deed, legal code and RDF pages and an optional trailing slash, keep the
+/// jurisdiction of ported licenses and reject unknown versions. The
+/// [strict](ParseOptions::strict) options only accept canonical URLs.
+///
+/// # Example
+///
+/// ```rust
+/// use cc_license::{JurisdictionPolicy, License, ParseError, ParseOptions};
+///
+/// let mut options = ParseOptions::new();
+/// options.lenient = true;
+/// options.jurisdictions = JurisdictionPolicy::Strip;
+/// let parse = |url| License::parse_with(url, &options);
+/// assert_eq!(parse(" creativecommons.org/licenses/by/3.0/de/"), Ok(License::CC_BY_3_0));
+/// assert_eq!(
+///     parse("https://creativecommons.org/licenses/by-sa/5.0/").map_err(|failure| failure.error()),
+///     Err(ParseError::InvalidVersion)
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[non_exhaustive]
+pub struct ParseOptions {
+    /// Reject URLs that do not use the `https` scheme
+    pub require_https: bool,
+    /// Accept the `www.` prefix on the domain
+    pub allow_www: bool,
+    /// Reject URLs that do not end with a slash
+    pub require_trailing_slash: bool,
+    /// Accept URLs of the deed, legal code or RDF pages, e.g. ending in `deed.fr`
+    pub allow_pages: bool,
+    /// Reject URLs whose path is not the canonical path of the license, e.g.
+    /// `publicdomain/by/4.0/` or `licenses/zero/1.0/`
+    pub require_canonical_path: bool,
+    /// Normalise the URL as [`License::from_url_lenient`] does before parsing it
+    ///
+    /// Ignored by [`LicenseRef`], which borrows the input as is, and by const
+    /// parsing, which cannot allocate.
+    ///
+    /// [`License::from_url_lenient`]: crate::License::from_url_lenient
+    /// [`LicenseRef`]: crate::LicenseRef
+    pub lenient: bool,
+    /// How the jurisdiction of ported licenses is handled
+    pub jurisdictions: JurisdictionPolicy,
+    /// How versions unknown to the crate are handled
+    pub unknown_version: VersionPolicy,
+    /// Unwrap Wayback Machine and Google redirect URLs around the license URL,
+    /// e.g. `https://web.archive.org/web/2019*/https://creativecommons.org/licenses/by/4.0/`
+    ///
+    /// Ignored by [`LicenseRef`](crate::LicenseRef) and by const parsing, as for
+    /// [`lenient`](ParseOptions::lenient).
+    pub unwrap_redirects: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions::new()
+    }
+}
+
+impl ParseOptions {
+    /// Create the default options
+    pub const fn new() -> Self {
+        ParseOptions {
+            require_https: false,
+            allow_www: true,
+            require_trailing_slash: false,
+            allow_pages: true,
+            require_canonical_path: false,
+            lenient: false,
+            jurisdictions: JurisdictionPolicy::Keep,
+            unknown_version: VersionPolicy::Reject,
+            unwrap_redirects: false,
+        }
+    }
+
+    /// Create options only accepting the canonical form of license URLs
+    ///
+    /// The canonical form, as produced by [`License::to_url`], uses `https`, no
+    /// `www.` prefix and a trailing slash, and points to the license itself rather
+    /// than one of its pages, under its canonical path.
+    ///
+    /// [`License::to_url`]: crate::License::to_url
+    pub const fn strict() -> Self {
+        ParseOptions {
+            require_https: true,
+            allow_www: false,
+            require_trailing_slash: true,
+            allow_pages: false,
+            require_canonical_path: true,
+            lenient: false,
+            jurisdictions: JurisdictionPolicy::Keep,
+            unknown_version: VersionPolicy::Reject,
+            unwrap_redirects: false,
+        }
+    }
+
+    /// Set the options used by parsers that do not take options explicitly
+    ///
+    /// This applies process-wide to [`License::from_url`](crate::License::from_url),
+    /// the `FromStr` implementation and everything built on them, so frameworks that
+    /// can only call `str::parse` still honour the application's policy. The
+    /// default can only be set once; later calls return the rejected options.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{License, ParseOptions};
+    ///
+    /// let mut options = ParseOptions::new();
+    /// options.require_https = true;
+    /// ParseOptions::set_global_default(options).unwrap();
+    /// assert!(ParseOptions::set_global_default(ParseOptions::new()).is_err());
+    ///
+    /// assert!("https://creativecommons.org/licenses/by/4.0/".parse::<License>().is_ok());
+    /// assert!("http://creativecommons.org/licenses/by/4.0/".parse::<License>().is_err());
+    /// ```
+    pub fn set_global_default(options: ParseOptions) -> Result<(), ParseOptions> {
+        GLOBAL_DEFAULT.set(options)
+    }
+
+    /// Obtain the process-wide default options
+    pub fn global_default() -> &'static ParseOptions {
+        static FALLBACK: ParseOptions = ParseOptions::new();
+        GLOBAL_DEFAULT.get().unwrap_or(&FALLBACK)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        assert_eq!(ParseOptions::default(), ParseOptions::new());
+        assert!(!ParseOptions::new().require_https);
+        assert!(ParseOptions::new().allow_www);
+        assert!(!ParseOptions::new().require_trailing_slash);
+        assert!(ParseOptions::new().allow_pages);
+        assert!(!ParseOptions::new().require_canonical_path);
+        assert!(!ParseOptions::new().lenient);
+        assert_eq!(ParseOptions::new().jurisdictions, JurisdictionPolicy::Keep);
+        assert_eq!(ParseOptions::new().unknown_version, VersionPolicy::Reject);
+        assert!(!ParseOptions::new().unwrap_redirects);
+    }
+
+    #[test]
+    fn test_strict() {
+        let strict = ParseOptions::strict();
+        assert!(strict.require_https);
+        assert!(!strict.allow_www);
+        assert!(strict.require_trailing_slash);
+        assert!(!strict.allow_pages);
+        assert!(strict.require_canonical_path);
+    }
+}
@@ -0,0 +1,883 @@
+use crate::error::ParseError;
+use crate::jurisdiction::Jurisdiction;
+use crate::options::{JurisdictionPolicy, ParseOptions};
+use crate::rights::Rights;
+use crate::table;
+use crate::version::Version;
+use crate::License;
+
+/// Byte offsets of a segment within the parsed input
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) struct Span {
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+impl Span {
+    pub(crate) const fn slice<'a>(&self, bytes: &'a [u8]) -> &'a [u8] {
+        bytes.split_at(self.end).0.split_at(self.start).1
+    }
+}
+
+/// Location of the rights and version segments of a Creative Commons URL,
+/// along with the optional parts of the URL that were present
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) struct Segments {
+    /// Path of the license after the domain, e.g. `licenses/by/4.0`, without the page
+    pub(crate) path: Span,
+    pub(crate) rights: Span,
+    pub(crate) version: Span,
+    /// Empty when the license is unported
+    pub(crate) jurisdiction: Span,
+    /// Page selected after the license path, e.g. `deed.fr`, empty when absent
+    pub(crate) page: Span,
+    /// Whether the URL points to the legal code rather than the deed
+    pub(crate) legal_code: bool,
+    /// Language of the page, e.g. `de` in `legalcode.de`, empty when absent
+    pub(crate) language: Span,
+    /// Whether the URL points to the RDF description of the page
+    pub(crate) rdf: bool,
+    pub(crate) https: bool,
+    pub(crate) www: bool,
+    pub(crate) trailing_slash: bool,
+}
+
+const fn starts_with(bytes: &[u8], at: usize, prefix: &[u8]) -> bool {
+    if bytes.len() < at + prefix.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if bytes[at + i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Whether the segment is a language tag as used in page suffixes, e.g. `pt_BR`
+const fn is_language(bytes: &[u8], segment: Span) -> bool {
+    if segment.start == segment.end {
+        return false;
+    }
+    let mut i = segment.start;
+    while i < segment.end {
+        let b = bytes[i];
+        if !(b.is_ascii_alphanumeric() || b == b'-' || b == b'_') {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Page of a license selected by the segment following the license path
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Page {
+    legal_code: bool,
+    language: Span,
+    rdf: bool,
+}
+
+/// Locate the page selected by a segment following the license path, or `None`
+/// if the segment is not a page
+///
+/// Accepts `legalcode`, `deed` and either followed by `.<language>`, each
+/// optionally in RDF with a `.rdf` suffix, as well as `rdf` alone.
+const fn split_page(bytes: &[u8], segment: Span) -> Option<Page> {
+    let rdf = segment.end >= segment.start + 4 && starts_with(bytes, segment.end - 4, b".rdf");
+    let end = if rdf { segment.end - 4 } else { segment.end };
+    let no_language = Span { start: end, end };
+    if segment.end == segment.start + 3 && starts_with(bytes, segment.start, b"rdf") {
+        return Some(Page {
+            legal_code: false,
+            language: no_language,
+            rdf: true,
+        });
+    }
+    let (legal_code, rest) = if starts_with(bytes, segment.start, b"legalcode") {
+        (true, segment.start + 9)
+    } else if starts_with(bytes, segment.start, b"deed") {
+        (false, segment.start + 4)
+    } else {
+        return None;
+    };
+    if rest > end {
+        return None;
+    }
+    if rest == end {
+        return Some(Page {
+            legal_code,
+            language: no_language,
+            rdf,
+        });
+    }
+    let language = Span {
+        start: rest + 1,
+        end,
+    };
+    if bytes[rest] == b'.' && is_language(bytes, language) {
+        Some(Page {
+            legal_code,
+            language,
+            rdf,
+        })
+    } else {
+        None
+    }
+}
+
+const fn segment_end(bytes: &[u8], start: usize) -> usize {
+    let mut end = start;
+    while end < bytes.len() && bytes[end] != b'/' {
+        end += 1;
+    }
+    end
+}
+
+/// Whether the URL points to the Creative Commons domain, with or without `www.`
+pub(crate) const fn matches_cc_domain(bytes: &[u8]) -> bool {
+    let mut pos = if starts_with(bytes, 0, b"https://") {
+        8
+    } else if starts_with(bytes, 0, b"http://") {
+        7
+    } else {
+        return false;
+    };
+    if starts_with(bytes, pos, b"www.") {
+        pos += 4;
+    }
+    starts_with(bytes, pos, b"creativecommons.org")
+        && (bytes.len() == pos + 19 || bytes[pos + 19] == b'/')
+}
+
+/// Split a Creative Commons URL into its rights and version segments.
+///
+/// Accepts `http(s)://(www.)creativecommons.org/(licenses|publicdomain)/<rights>/<version>`
+/// with an optional trailing slash. Segments are located but not validated, except
+/// for the optional jurisdiction segment following the version, which must name a
+/// known [`Jurisdiction`] since it cannot be told apart from other pages otherwise.
+/// The path may end with the deed or legal code page, such as `deed.fr` or
+/// `legalcode`, optionally selecting a language, or with the RDF description of
+/// the license, such as `rdf` or `legalcode.rdf`.
+///
+/// The authority must be exactly the Creative Commons domain, so userinfo, ports,
+/// IP addresses and look-alike hosts are rejected without a general URL parser.
+pub(crate) const fn split_url(bytes: &[u8]) -> Result<Segments, ParseError> {
+    let https = starts_with(bytes, 0, b"https://");
+    let mut pos = if https {
+        8
+    } else if starts_with(bytes, 0, b"http://") {
+        7
+    } else {
+        return Err(ParseError::InvalidScheme);
+    };
+    let www = starts_with(bytes, pos, b"www.");
+    if www {
+        pos += 4;
+    }
+    if !starts_with(bytes, pos, b"creativecommons.org")
+        || (bytes.len() > pos + 19 && bytes[pos + 19] != b'/')
+    {
+        return Err(ParseError::InvalidDomain);
+    }
+    pos += 20;
+    let path_start = pos;
+    if starts_with(bytes, pos, b"licenses/") {
+        pos += 9;
+        // the retired Public Domain Dedication and Certification has no version
+        if starts_with(bytes, pos, b"publicdomain")
+            && (bytes.len() == pos + 12 || (bytes.len() == pos + 13 && bytes[pos + 12] == b'/'))
+        {
+            let rights = Span {
+                start: pos,
+                end: pos + 12,
+            };
+            let empty = Span {
+                start: rights.end,
+                end: rights.end,
+            };
+            return Ok(Segments {
+                path: Span {
+                    start: path_start,
+                    end: rights.end,
+                },
+                rights,
+                version: empty,
+                jurisdiction: empty,
+                page: empty,
+                legal_code: false,
+                language: empty,
+                rdf: false,
+                https,
+                www,
+                trailing_slash: bytes.len() == pos + 13,
+            });
+        }
+    } else if starts_with(bytes, pos, b"publicdomain/") {
+        pos += 13;
+    } else {
+        return Err(ParseError::InvalidUrl);
+    }
+
+    let rights = Span {
+        start: pos,
+        end: segment_end(bytes, pos),
+    };
+    if rights.start == rights.end {
+        return Err(ParseError::InvalidUrl);
+    }
+    if rights.end == bytes.len() {
+        return Err(ParseError::MissingVersion);
+    }
+    let version = Span {
+        start: rights.end + 1,
+        end: segment_end(bytes, rights.end + 1),
+    };
+    if version.start == version.end {
+        return Err(ParseError::MissingVersion);
+    }
+    let mut end = version.end;
+    let mut jurisdiction = Span { start: end, end };
+    if end + 1 < bytes.len() {
+        let segment = Span {
+            start: end + 1,
+            end: segment_end(bytes, end + 1),
+        };
+        if Jurisdiction::from_slug(segment.slice(bytes)).is_some() {
+            jurisdiction = segment;
+            end = segment.end;
+        }
+    }
+    let path = Span {
+        start: path_start,
+        end,
+    };
+    let mut page_segment = Span { start: end, end };
+    let mut page = Page {
+        legal_code: false,
+        language: page_segment,
+        rdf: false,
+    };
+    if end + 1 < bytes.len() {
+        let segment = Span {
+            start: end + 1,
+            end: segment_end(bytes, end + 1),
+        };
+        match split_page(bytes, segment) {
+            Some(selected) => {
+                page = selected;
+                page_segment = segment;
+                end = segment.end;
+            }
+            None => return Err(ParseError::TrailingSegments),
+        }
+    }
+    // allow a single trailing slash and nothing else
+    if end < bytes.len() && end + 1 != bytes.len() {
+        return Err(ParseError::TrailingSegments);
+    }
+    Ok(Segments {
+        path,
+        rights,
+        version,
+        jurisdiction,
+        page: page_segment,
+        legal_code: page.legal_code,
+        language: page.language,
+        rdf: page.rdf,
+        https,
+        www,
+        trailing_slash: end < bytes.len(),
+    })
+}
+
+/// Parse a Creative Commons URL, usable in const contexts
+pub(crate) const fn parse_url(url: &str, options: &ParseOptions) -> Result<License, ParseError> {
+    let bytes = url.as_bytes();
+    match split_url(bytes) {
+        Ok(segments) => parse_segments(bytes, &segments, options),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate the segments located by [`split_url`] and build the license
+pub(crate) const fn parse_segments(
+    bytes: &[u8],
+    segments: &Segments,
+    options: &ParseOptions,
+) -> Result<License, ParseError> {
+    match build_license(bytes, segments, options) {
+        Ok(license) if options.require_canonical_path => {
+            let path = table::canonical_path(&license).as_bytes();
+            if segments.path.end - segments.path.start == path.len()
+                && starts_with(bytes, segments.path.start, path)
+            {
+                Ok(license)
+            } else {
+                Err(ParseError::InvalidUrl)
+            }
+        }
+        result => result,
+    }
+}
+
+/// Build the license named by the segments, whatever its path
+const fn build_license(
+    bytes: &[u8],
+    segments: &Segments,
+    options: &ParseOptions,
+) -> Result<License, ParseError> {
+    if options.require_https && !segments.https {
+        return Err(ParseError::InvalidScheme);
+    }
+    if !options.allow_www && segments.www {
+        return Err(ParseError::InvalidDomain);
+    }
+    if !options.allow_pages && segments.page.start != segments.page.end {
+        return Err(ParseError::TrailingSegments);
+    }
+    if options.require_trailing_slash && !segments.trailing_slash {
+        return Err(ParseError::InvalidUrl);
+    }
+    let rights = match Rights::from_slug(segments.rights.slice(bytes)) {
+        Some(rights) => rights,
+        None => return Err(ParseError::InvalidRights),
+    };
+    let version = if segments.version.start == segments.version.end {
+        // only located for the unversioned public domain certification
+        Version::One
+    } else if segments.rights.end - segments.rights.start == 12
+        && starts_with(bytes, segments.rights.start, b"publicdomain")
+    {
+        // the certification is only versioned under its `certification` slug
+        return Err(ParseError::TrailingSegments);
+    } else {
+        match Version::from_slug(segments.version.slice(bytes)) {
+            Some(version) => version,
+            None => return Err(ParseError::InvalidVersion),
+        }
+    };
+    if segments.jurisdiction.start == segments.jurisdiction.end {
+        return License::new(rights, version);
+    }
+    let jurisdiction = match Jurisdiction::from_slug(segments.jurisdiction.slice(bytes)) {
+        Some(jurisdiction) => jurisdiction,
+        None => return Err(ParseError::InvalidJurisdiction),
+    };
+    match options.jurisdictions {
+        JurisdictionPolicy::Keep => License::ported(rights, version, jurisdiction),
+        JurisdictionPolicy::Strip => match License::ported(rights, version, jurisdiction) {
+            Ok(_) => License::new(rights, version),
+            Err(e) => Err(e),
+        },
+        JurisdictionPolicy::Reject => Err(ParseError::InvalidJurisdiction),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_cc_domain() {
+        for url in [
+            "https://creativecommons.org",
+            "http://creativecommons.org/",
+            "https://www.creativecommons.org/licenses/by/4.0/",
+            "https://creativecommons.org/about",
+        ] {
+            assert!(matches_cc_domain(url.as_bytes()), "{}", url);
+        }
+        for url in [
+            "creativecommons.org/licenses/by/4.0/",
+            "https://creativecommons.org.evil.com/licenses/by/4.0/",
+            "https://creativecommons.org:443/licenses/by/4.0/",
+            "https://user@creativecommons.org/",
+            "https://wiki.creativecommons.org/",
+            "ftp://creativecommons.org/",
+        ] {
+            assert!(!matches_cc_domain(url.as_bytes()), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_split_url() {
+        let url = "https://creativecommons.org/licenses/by-nc/4.0/";
+        let segments = split_url(url.as_bytes()).unwrap();
+        assert_eq!(&url[segments.rights.start..segments.rights.end], "by-nc");
+        assert_eq!(&url[segments.version.start..segments.version.end], "4.0");
+
+        let url = "http://www.creativecommons.org/publicdomain/zero/1.0";
+        let segments = split_url(url.as_bytes()).unwrap();
+        assert_eq!(&url[segments.rights.start..segments.rights.end], "zero");
+        assert_eq!(&url[segments.version.start..segments.version.end], "1.0");
+        assert!(!segments.https);
+        assert!(segments.www);
+        assert!(!segments.trailing_slash);
+
+        let url = "http://creativecommons.org/licenses/publicdomain/";
+        let segments = split_url(url.as_bytes()).unwrap();
+        assert_eq!(
+            &url[segments.rights.start..segments.rights.end],
+            "publicdomain"
+        );
+        assert_eq!(segments.version.start, segments.version.end);
+        assert!(segments.trailing_slash);
+
+        assert!(split_url(b"ftp://creativecommons.org/licenses/by/4.0/").is_err());
+        assert!(split_url(b"https://creativecommons.org/licenses/publicdomainx/").is_err());
+        assert!(split_url(b"https://creativecommons.org/licenses/by/4.0//").is_err());
+        assert!(split_url(b"https://creativecommons.org/licenses//4.0/").is_err());
+        assert!(split_url(b"https://creativecommons.org/licenses/by").is_err());
+        assert!(split_url(b"https://creativecommons.org/licenses/by/4.0/deeds").is_err());
+    }
+
+    #[test]
+    fn test_split_url_legal_code() {
+        let url = "https://creativecommons.org/licenses/by/4.0/legalcode";
+        let segments = split_url(url.as_bytes()).unwrap();
+        assert_eq!(&url[segments.version.start..segments.version.end], "4.0");
+        assert!(segments.legal_code);
+        assert_eq!(segments.language.start, segments.language.end);
+        assert!(!segments.trailing_slash);
+
+        let url = "http://creativecommons.org/licenses/by-sa/3.0/de/legalcode.pt_BR/";
+        let segments = split_url(url.as_bytes()).unwrap();
+        assert_eq!(
+            &url[segments.jurisdiction.start..segments.jurisdiction.end],
+            "de"
+        );
+        assert!(segments.legal_code);
+        assert_eq!(
+            &url[segments.language.start..segments.language.end],
+            "pt_BR"
+        );
+        assert!(segments.trailing_slash);
+
+        let segments = split_url(b"https://creativecommons.org/licenses/by/4.0/").unwrap();
+        assert!(!segments.legal_code);
+    }
+
+    #[test]
+    fn test_split_url_rdf() {
+        let segments = split_url(b"https://creativecommons.org/licenses/by/3.0/rdf").unwrap();
+        assert!(segments.rdf);
+        assert!(!segments.legal_code);
+
+        let url = "https://creativecommons.org/licenses/by/3.0/legalcode.rdf";
+        let segments = split_url(url.as_bytes()).unwrap();
+        assert!(segments.rdf);
+        assert!(segments.legal_code);
+        assert_eq!(segments.language.start, segments.language.end);
+
+        let url = "http://creativecommons.org/licenses/by-sa/2.5/deed.pt_BR.rdf";
+        let segments = split_url(url.as_bytes()).unwrap();
+        assert!(segments.rdf);
+        assert_eq!(
+            &url[segments.language.start..segments.language.end],
+            "pt_BR"
+        );
+
+        let segments = split_url(b"https://creativecommons.org/licenses/by/3.0/deed").unwrap();
+        assert!(!segments.rdf);
+
+        for url in [
+            "https://creativecommons.org/licenses/by/3.0/.rdf",
+            "https://creativecommons.org/licenses/by/3.0/rdfs",
+            "https://creativecommons.org/licenses/by/3.0/rdf.rdf",
+            "https://creativecommons.org/licenses/by/3.0/legalcode..rdf",
+            "https://creativecommons.org/licenses/by/3.0/rdf/legalcode",
+        ] {
+            assert_eq!(
+                split_url(url.as_bytes()),
+                Err(ParseError::TrailingSegments),
+                "{}",
+                url
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_url_deed() {
+        let url = "https://creativecommons.org/licenses/by-nc/4.0/deed.fr";
+        let segments = split_url(url.as_bytes()).unwrap();
+        assert_eq!(&url[segments.rights.start..segments.rights.end], "by-nc");
+        assert!(!segments.legal_code);
+        assert_eq!(&url[segments.language.start..segments.language.end], "fr");
+
+        let url = "http://creativecommons.org/licenses/by/3.0/de/deed";
+        let segments = split_url(url.as_bytes()).unwrap();
+        assert_eq!(
+            &url[segments.jurisdiction.start..segments.jurisdiction.end],
+            "de"
+        );
+        assert_eq!(segments.language.start, segments.language.end);
+
+        for url in [
+            "https://creativecommons.org/licenses/by/4.0/deed.",
+            "https://creativecommons.org/licenses/by/4.0/deedfr",
+            "https://creativecommons.org/licenses/by/4.0/deed.fr/legalcode",
+            "https://creativecommons.org/licenses/by/4.0/dee",
+        ] {
+            assert_eq!(
+                split_url(url.as_bytes()),
+                Err(ParseError::TrailingSegments),
+                "{}",
+                url
+            );
+        }
+
+        for url in [
+            "https://creativecommons.org/licenses/by/4.0/legalcode.",
+            "https://creativecommons.org/licenses/by/4.0/legalcode.d/e",
+            "https://creativecommons.org/licenses/by/4.0/legalcode.de.",
+            "https://creativecommons.org/licenses/by/4.0/legalcodes",
+            "https://creativecommons.org/licenses/by/4.0/legalcode//",
+            "https://creativecommons.org/licenses/by/4.0/legalcode/de",
+        ] {
+            assert_eq!(
+                split_url(url.as_bytes()),
+                Err(ParseError::TrailingSegments),
+                "{}",
+                url
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_url_ported() {
+        let url = "http://creativecommons.org/licenses/by-sa/2.0/uk/";
+        let segments = split_url(url.as_bytes()).unwrap();
+        assert_eq!(&url[segments.version.start..segments.version.end], "2.0");
+        assert_eq!(
+            &url[segments.jurisdiction.start..segments.jurisdiction.end],
+            "uk"
+        );
+        assert!(segments.trailing_slash);
+
+        let url = "https://creativecommons.org/licenses/by/3.0/scotland";
+        let segments = split_url(url.as_bytes()).unwrap();
+        assert_eq!(
+            &url[segments.jurisdiction.start..segments.jurisdiction.end],
+            "scotland"
+        );
+        assert!(!segments.trailing_slash);
+
+        let url = "https://creativecommons.org/licenses/by/3.0/";
+        let segments = split_url(url.as_bytes()).unwrap();
+        assert_eq!(segments.jurisdiction.start, segments.jurisdiction.end);
+
+        assert!(split_url(b"https://creativecommons.org/licenses/by/3.0/xx/").is_err());
+        assert!(split_url(b"https://creativecommons.org/licenses/by/3.0/de//").is_err());
+        assert!(split_url(b"https://creativecommons.org/licenses/by/3.0/de/faq").is_err());
+    }
+
+    #[test]
+    fn test_parse_ported() {
+        let options = ParseOptions::new();
+        let license = parse_url(
+            "http://creativecommons.org/licenses/by-nc-nd/3.0/us/",
+            &options,
+        );
+        assert_eq!(
+            license,
+            License::ported(Rights::ByNcNd, Version::Three, Jurisdiction::UnitedStates)
+        );
+        assert_eq!(
+            parse_url("https://creativecommons.org/licenses/by/4.0/de/", &options),
+            Err(ParseError::InvalidJurisdiction)
+        );
+        assert_eq!(
+            parse_url(
+                "http://creativecommons.org/licenses/by-sa/2.1/jp/",
+                &options
+            ),
+            License::ported(Rights::BySa, Version::TwoOne, Jurisdiction::Japan)
+        );
+        assert_eq!(
+            parse_url("http://creativecommons.org/licenses/by-sa/2.1/", &options),
+            Err(ParseError::InvalidVersion)
+        );
+        assert_eq!(
+            parse_url(
+                "http://creativecommons.org/licenses/by-sa/2.1/de/",
+                &options
+            ),
+            Err(ParseError::InvalidJurisdiction)
+        );
+        assert_eq!(
+            parse_url(
+                "https://creativecommons.org/publicdomain/zero/1.0/de/",
+                &options
+            ),
+            Err(ParseError::InvalidJurisdiction)
+        );
+    }
+
+    #[test]
+    fn test_split_url_rejects_authority_tricks() {
+        for (url, error) in [
+            (
+                "https://user@creativecommons.org/licenses/by/4.0/",
+                ParseError::InvalidDomain,
+            ),
+            (
+                "https://creativecommons.org@evil.example/licenses/by/4.0/",
+                ParseError::InvalidDomain,
+            ),
+            (
+                "https://creativecommons.org:443/licenses/by/4.0/",
+                ParseError::InvalidDomain,
+            ),
+            (
+                "https://creativecommons.org.evil.example/licenses/by/4.0/",
+                ParseError::InvalidDomain,
+            ),
+            (
+                "https://evilcreativecommons.org/licenses/by/4.0/",
+                ParseError::InvalidDomain,
+            ),
+            (
+                "https://CreativeCommons.org/licenses/by/4.0/",
+                ParseError::InvalidDomain,
+            ),
+            (
+                "https://creativecommons%2Eorg/licenses/by/4.0/",
+                ParseError::InvalidDomain,
+            ),
+            (
+                "https://104.20.150.16/licenses/by/4.0/",
+                ParseError::InvalidDomain,
+            ),
+            (
+                "https:\\\\creativecommons.org/licenses/by/4.0/",
+                ParseError::InvalidScheme,
+            ),
+            (
+                "https:/creativecommons.org/licenses/by/4.0/",
+                ParseError::InvalidScheme,
+            ),
+            (
+                " https://creativecommons.org/licenses/by/4.0/",
+                ParseError::InvalidScheme,
+            ),
+            (
+                "https://creativecommons.org/licenses/by/4.0/?utm_source=x",
+                ParseError::TrailingSegments,
+            ),
+            (
+                "https://creativecommons.org/licenses/by/4.0/#legal",
+                ParseError::TrailingSegments,
+            ),
+            (
+                "https://creativecommons.org/licenses/by/4.0/\n",
+                ParseError::TrailingSegments,
+            ),
+        ] {
+            assert_eq!(split_url(url.as_bytes()), Err(error), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_split_url_granular_errors() {
+        for (url, error) in [
+            (
+                "creativecommons.org/licenses/by/4.0/",
+                ParseError::InvalidScheme,
+            ),
+            (
+                "ftp://creativecommons.org/licenses/by/4.0/",
+                ParseError::InvalidScheme,
+            ),
+            (
+                "https://example.org/licenses/by/4.0/",
+                ParseError::InvalidDomain,
+            ),
+            ("https://creativecommons.org", ParseError::InvalidUrl),
+            ("https://creativecommons.org/about/", ParseError::InvalidUrl),
+            (
+                "https://creativecommons.org/licenses//4.0/",
+                ParseError::InvalidUrl,
+            ),
+            (
+                "https://creativecommons.org/licenses/by",
+                ParseError::MissingVersion,
+            ),
+            (
+                "https://creativecommons.org/licenses/by/",
+                ParseError::MissingVersion,
+            ),
+            (
+                "https://creativecommons.org/publicdomain/zero/",
+                ParseError::MissingVersion,
+            ),
+            (
+                "https://creativecommons.org/licenses/by/4.0/extra/",
+                ParseError::TrailingSegments,
+            ),
+            (
+                "https://creativecommons.org/licenses/by/4.0/deed.fr/extra",
+                ParseError::TrailingSegments,
+            ),
+        ] {
+            assert_eq!(split_url(url.as_bytes()), Err(error), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_parse_url_const() {
+        const LICENSE: Result<License, ParseError> = parse_url(
+            "https://creativecommons.org/licenses/by-sa/3.0/",
+            &ParseOptions::new(),
+        );
+        assert_eq!(
+            LICENSE,
+            Ok(License::from_parts(Rights::BySa, Version::Three, None))
+        );
+        assert_eq!(
+            parse_url(
+                "https://creativecommons.org/licenses/by-sa/3.1/",
+                &ParseOptions::new()
+            ),
+            Err(ParseError::InvalidVersion)
+        );
+    }
+
+    #[test]
+    fn test_parse_url_strict() {
+        let options = ParseOptions::strict();
+        assert!(parse_url("https://creativecommons.org/licenses/by/4.0/", &options).is_ok());
+        assert!(parse_url("https://creativecommons.org/licenses/by/3.0/de/", &options).is_ok());
+        for (url, error) in [
+            (
+                "http://creativecommons.org/licenses/by/4.0/",
+                ParseError::InvalidScheme,
+            ),
+            (
+                "https://www.creativecommons.org/licenses/by/4.0/",
+                ParseError::InvalidDomain,
+            ),
+            (
+                "https://creativecommons.org/licenses/by/4.0",
+                ParseError::InvalidUrl,
+            ),
+            (
+                "https://creativecommons.org/licenses/by/4.0/deed/",
+                ParseError::TrailingSegments,
+            ),
+            (
+                "https://creativecommons.org/licenses/by/4.0/legalcode.de",
+                ParseError::TrailingSegments,
+            ),
+            (
+                "https://creativecommons.org/licenses/by/4.0/rdf",
+                ParseError::TrailingSegments,
+            ),
+        ] {
+            assert_eq!(parse_url(url, &options), Err(error), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_parse_url_unversioned() {
+        let options = ParseOptions::new();
+        for url in [
+            "https://creativecommons.org/licenses/publicdomain/",
+            "http://creativecommons.org/licenses/publicdomain",
+            "https://creativecommons.org/publicdomain/certification/1.0/",
+        ] {
+            assert_eq!(parse_url(url, &options), Ok(License::PDDC), "{}", url);
+        }
+        for url in [
+            "https://creativecommons.org/licenses/publicdomain/1.0/",
+            "https://creativecommons.org/publicdomain/publicdomain/1.0/",
+        ] {
+            assert_eq!(
+                parse_url(url, &options),
+                Err(ParseError::TrailingSegments),
+                "{}",
+                url
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_url_canonical_path() {
+        let strict = ParseOptions::strict();
+        for license in table::LICENSES {
+            let url = license.to_url();
+            assert_eq!(parse_url(&url, &strict), Ok(*license), "{}", url);
+        }
+        for url in [
+            "https://creativecommons.org/licenses/zero/1.0/",
+            "https://creativecommons.org/publicdomain/by/4.0/",
+            "https://creativecommons.org/licenses/mark/1.0/",
+        ] {
+            assert_eq!(
+                parse_url(url, &strict),
+                Err(ParseError::InvalidUrl),
+                "{}",
+                url
+            );
+        }
+        assert_eq!(
+            parse_url(
+                "https://creativecommons.org/licenses/zero/1.0/",
+                &ParseOptions::new()
+            ),
+            Ok(License::CC0_1_0)
+        );
+    }
+
+    #[test]
+    fn test_parse_url_options() {
+        let mut options = ParseOptions::new();
+        options.require_https = true;
+        options.allow_www = false;
+        options.require_trailing_slash = true;
+        assert!(parse_url("https://creativecommons.org/licenses/by/4.0/", &options).is_ok());
+        assert_eq!(
+            parse_url("http://creativecommons.org/licenses/by/4.0/", &options),
+            Err(ParseError::InvalidScheme)
+        );
+        assert_eq!(
+            parse_url("https://www.creativecommons.org/licenses/by/4.0/", &options),
+            Err(ParseError::InvalidDomain)
+        );
+        assert_eq!(
+            parse_url("https://creativecommons.org/licenses/by/4.0", &options),
+            Err(ParseError::InvalidUrl)
+        );
+    }
+
+    #[test]
+    fn test_parse_url_jurisdiction_policy() {
+        let mut options = ParseOptions::new();
+        let url = "https://creativecommons.org/licenses/by-sa/2.0/uk/";
+        options.jurisdictions = JurisdictionPolicy::Strip;
+        assert_eq!(parse_url(url, &options), Ok(License::CC_BY_SA_2_0));
+        assert_eq!(
+            parse_url("https://creativecommons.org/licenses/by/4.0/de/", &options),
+            Err(ParseError::InvalidJurisdiction)
+        );
+        assert_eq!(
+            parse_url("https://creativecommons.org/licenses/by/2.0/at/", &options),
+            Ok(License::CC_BY_2_0)
+        );
+        assert_eq!(
+            parse_url("https://creativecommons.org/licenses/by/3.0/jp/", &options),
+            Err(ParseError::InvalidJurisdiction)
+        );
+        options.jurisdictions = JurisdictionPolicy::Reject;
+        assert_eq!(
+            parse_url(url, &options),
+            Err(ParseError::InvalidJurisdiction)
+        );
+        assert_eq!(
+            parse_url("https://creativecommons.org/licenses/by-sa/2.0/", &options),
+            Ok(License::CC_BY_SA_2_0)
+        );
+    }
+}
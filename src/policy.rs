@@ -0,0 +1,296 @@
+use crate::error::ParseError;
+use crate::instrument::RightsInstrument;
+use crate::rights::Rights;
+use crate::table::LICENSES;
+use crate::License;
+use std::str::FromStr;
+
+/// Outcome of checking a license against a [`LicenseAllowlist`]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Decision {
+    Allow,
+    Deny,
+}
+
+impl Decision {
+    /// Whether the license is allowed
+    pub fn is_allowed(&self) -> bool {
+        *self == Decision::Allow
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Pattern {
+    License(License),
+    Family(Rights),
+    Glob(String),
+}
+
+impl Pattern {
+    fn matches(&self, license: &License) -> bool {
+        match self {
+            Pattern::License(allowed) => allowed == license,
+            Pattern::Family(rights) => *rights == license.rights_enum(),
+            Pattern::Glob(glob) => glob_matches(glob, license.canonical_key()),
+        }
+    }
+
+    fn matches_instrument(&self, instrument: &dyn RightsInstrument) -> bool {
+        match (self, instrument.as_license()) {
+            (_, Some(license)) => self.matches(license),
+            (Pattern::Glob(glob), None) => glob_matches(glob, instrument.key()),
+            _ => false,
+        }
+    }
+}
+
+/// Policy gate accepting only a chosen set of licenses
+///
+/// Entries can be license URLs, canonical keys (see [`License::canonical_key`]),
+/// globs over canonical keys using `*` as a wildcard, or whole rights families.
+/// Anything not explicitly allowed is denied.
+///
+/// # Example
+///
+/// ```rust
+/// use cc_license::{Decision, License, LicenseAllowlist, ParseError, Rights};
+///
+/// // BY and BY-SA 4.0 only
+/// let allowlist = LicenseAllowlist::new().allow("by/4.0")?.allow("by-sa/4.0")?;
+/// let by = License::from_url("https://creativecommons.org/licenses/by/4.0/")?;
+/// let by_nc = License::from_url("https://creativecommons.org/licenses/by-nc/4.0/")?;
+/// assert_eq!(allowlist.check(&by), Decision::Allow);
+/// assert_eq!(allowlist.check(&by_nc), Decision::Deny);
+///
+/// // Any non-commercial license, any version
+/// let allowlist: LicenseAllowlist = "by-nc*/*".parse()?;
+/// assert!(allowlist.check(&by_nc).is_allowed());
+///
+/// let allowlist = LicenseAllowlist::new().allow_family(Rights::By);
+/// assert!(allowlist.check(&by).is_allowed());
+/// # Ok::<(), ParseError>(())
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct LicenseAllowlist {
+    patterns: Vec<Pattern>,
+}
+
+impl LicenseAllowlist {
+    /// Create an allowlist that denies every license
+    pub fn new() -> Self {
+        LicenseAllowlist::default()
+    }
+
+    /// Allow the licenses matching a URL, canonical key or glob
+    ///
+    /// Fails with [`ParseError::InvalidRights`] when a key or glob matches no
+    /// license at all, which usually indicates a typo in the policy.
+    pub fn allow(mut self, pattern: &str) -> Result<Self, ParseError> {
+        let pattern = pattern.trim();
+        let pattern = if pattern.contains("://") {
+            Pattern::License(License::from_url(pattern)?)
+        } else {
+            let pattern = Pattern::Glob(pattern.to_lowercase());
+            if !LICENSES.iter().any(|license| pattern.matches(license)) {
+                return Err(ParseError::InvalidRights);
+            }
+            pattern
+        };
+        self.patterns.push(pattern);
+        Ok(self)
+    }
+
+    /// Allow a single license
+    pub fn allow_license(mut self, license: License) -> Self {
+        self.patterns.push(Pattern::License(license));
+        self
+    }
+
+    /// Allow every version of a rights family
+    pub fn allow_family(mut self, rights: Rights) -> Self {
+        self.patterns.push(Pattern::Family(rights));
+        self
+    }
+
+    /// Allow the keys of other rights instruments matching a glob
+    ///
+    /// Unlike [`LicenseAllowlist::allow`], the glob is not checked against the
+    /// Creative Commons licenses, since it is meant to match keys of instruments
+    /// registered downstream (see [`RightsInstrument::key`]).
+    pub fn allow_key(mut self, glob: &str) -> Self {
+        self.patterns
+            .push(Pattern::Glob(glob.trim().to_lowercase()));
+        self
+    }
+
+    /// Decide whether a license is accepted by the policy
+    pub fn check(&self, license: &License) -> Decision {
+        decide(self.patterns.iter().any(|pattern| pattern.matches(license)))
+    }
+
+    /// Decide whether a license or other rights instrument is accepted by the policy
+    pub fn check_instrument(&self, instrument: &dyn RightsInstrument) -> Decision {
+        decide(
+            self.patterns
+                .iter()
+                .any(|pattern| pattern.matches_instrument(instrument)),
+        )
+    }
+}
+
+fn decide(allowed: bool) -> Decision {
+    if allowed {
+        Decision::Allow
+    } else {
+        Decision::Deny
+    }
+}
+
+impl FromStr for LicenseAllowlist {
+    type Err = ParseError;
+
+    /// Parse a comma-separated list of URLs, canonical keys and globs
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .filter(|pattern| !pattern.trim().is_empty())
+            .try_fold(LicenseAllowlist::new(), |allowlist, pattern| {
+                allowlist.allow(pattern)
+            })
+    }
+}
+
+/// Match a string against a glob in which `*` stands for any run of characters
+fn glob_matches(glob: &str, s: &str) -> bool {
+    match glob.split_once('*') {
+        None => glob == s,
+        Some((prefix, rest)) => {
+            let Some(s) = s.strip_prefix(prefix) else {
+                return false;
+            };
+            (0..=s.len())
+                .filter(|&i| s.is_char_boundary(i))
+                .any(|i| glob_matches(rest, &s[i..]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::version::Version;
+
+    fn license(rights: Rights, version: Version) -> License {
+        License::from_parts(rights, version, None)
+    }
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(glob_matches("by/4.0", "by/4.0"));
+        assert!(!glob_matches("by/4.0", "by-sa/4.0"));
+        assert!(glob_matches("by*/4.0", "by-sa/4.0"));
+        assert!(glob_matches("*", "zero/1.0"));
+        assert!(glob_matches("*-nc*", "by-nc-sa/3.0"));
+        assert!(!glob_matches("*-nc*", "by-nd/3.0"));
+        assert!(glob_matches("by/*", "by/2.5"));
+    }
+
+    #[test]
+    fn test_allow() {
+        let allowlist = LicenseAllowlist::new()
+            .allow("https://creativecommons.org/licenses/by/4.0/")
+            .unwrap()
+            .allow(" BY-SA/* ")
+            .unwrap();
+        assert_eq!(
+            allowlist.check(&license(Rights::By, Version::Four)),
+            Decision::Allow
+        );
+        assert_eq!(
+            allowlist.check(&license(Rights::By, Version::Three)),
+            Decision::Deny
+        );
+        assert_eq!(
+            allowlist.check(&license(Rights::BySa, Version::One)),
+            Decision::Allow
+        );
+
+        assert_eq!(
+            LicenseAllowlist::new().allow("by-sa/5.0"),
+            Err(ParseError::InvalidRights)
+        );
+        assert_eq!(
+            LicenseAllowlist::new().allow("https://creativecommons.org/licenses/by/5.0/"),
+            Err(ParseError::InvalidVersion)
+        );
+    }
+
+    #[test]
+    fn test_allow_license_and_family() {
+        let allowlist = LicenseAllowlist::new()
+            .allow_license(license(Rights::Zero, Version::One))
+            .allow_family(Rights::ByNd);
+        assert!(allowlist
+            .check(&license(Rights::Zero, Version::One))
+            .is_allowed());
+        assert!(allowlist
+            .check(&license(Rights::ByNd, Version::Two))
+            .is_allowed());
+        assert!(!allowlist
+            .check(&license(Rights::ByNcNd, Version::Two))
+            .is_allowed());
+        assert!(!LicenseAllowlist::new()
+            .check(&license(Rights::By, Version::Four))
+            .is_allowed());
+    }
+
+    #[derive(Debug)]
+    struct Instrument;
+
+    impl RightsInstrument for Instrument {
+        fn key(&self) -> &str {
+            "ogl-uk/3.0"
+        }
+
+        fn write_title_to(&self, w: &mut dyn std::fmt::Write) -> std::fmt::Result {
+            w.write_str("Open Government Licence v3.0")
+        }
+
+        fn write_short_to(&self, w: &mut dyn std::fmt::Write) -> std::fmt::Result {
+            w.write_str("OGL-UK-3.0")
+        }
+
+        fn url(&self) -> String {
+            String::new()
+        }
+    }
+
+    #[test]
+    fn test_check_instrument() {
+        let allowlist = LicenseAllowlist::new()
+            .allow_family(Rights::By)
+            .allow_key("OGL-UK/*");
+        assert!(allowlist.check_instrument(&Instrument).is_allowed());
+        assert!(allowlist
+            .check_instrument(&license(Rights::By, Version::Two))
+            .is_allowed());
+        assert!(!allowlist
+            .check_instrument(&license(Rights::BySa, Version::Two))
+            .is_allowed());
+        assert!(!LicenseAllowlist::new()
+            .allow_family(Rights::By)
+            .check_instrument(&Instrument)
+            .is_allowed());
+    }
+
+    #[test]
+    fn test_from_str() {
+        let allowlist = LicenseAllowlist::from_str("by/4.0, by-sa/4.0,").unwrap();
+        assert!(allowlist
+            .check(&license(Rights::BySa, Version::Four))
+            .is_allowed());
+        assert!(!allowlist
+            .check(&license(Rights::BySa, Version::Three))
+            .is_allowed());
+        assert!(LicenseAllowlist::from_str("by/4.0, attribution").is_err());
+    }
+}
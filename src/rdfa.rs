@@ -0,0 +1,254 @@
+use crate::error::ParseError;
+use crate::License;
+
+/// Attribution metadata published alongside a license in RDFa
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct Attribution {
+    /// Name to credit, from `cc:attributionName`
+    pub name: Option<String>,
+    /// URL to link to when crediting, from `cc:attributionURL`
+    pub url: Option<String>,
+    /// Title of the work, from `dct:title`
+    pub title: Option<String>,
+}
+
+/// A start tag and the text immediately following it
+struct Element<'a> {
+    attributes: Vec<(&'a str, &'a str)>,
+    text: &'a str,
+}
+
+impl<'a> Element<'a> {
+    fn attribute(&self, name: &str) -> Option<&'a str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| *value)
+    }
+
+    /// Whether a space-separated attribute such as `rel` contains any of the terms
+    fn has_term(&self, attribute: &str, terms: &[&str]) -> bool {
+        self.attribute(attribute).is_some_and(|value| {
+            value
+                .split_ascii_whitespace()
+                .any(|term| terms.iter().any(|t| term.eq_ignore_ascii_case(t)))
+        })
+    }
+
+    /// Value of a `property` element: its `content` attribute, or else its text
+    fn property_value(&self) -> Option<String> {
+        let value = self.attribute("content").unwrap_or(self.text).trim();
+        (!value.is_empty()).then(|| decode_entities(value))
+    }
+
+    /// Value of a `rel` element: its `href`, or else its `resource` attribute
+    fn link_value(&self) -> Option<String> {
+        let value = self
+            .attribute("href")
+            .or_else(|| self.attribute("resource"))?
+            .trim();
+        (!value.is_empty()).then(|| decode_entities(value))
+    }
+}
+
+/// Read the license and attribution metadata from an HTML page marked up with RDFa
+///
+/// The license is taken from the first `rel="license"` (or `cc:license`) link that
+/// parses. `cc:attributionName`, `cc:attributionURL` and `dct:title` are read from
+/// `property` or `rel` attributes, taking the first occurrence of each. This is a
+/// lightweight reader for the markup produced by the Creative Commons license
+/// chooser, not a full RDFa processor.
+///
+/// Fails with the error of the first license link when none of them parse, or
+/// with [`ParseError::InvalidUrl`] when the page has no license link at all.
+///
+/// # Example
+///
+/// ```rust
+/// # use cc_license::ParseError;
+/// use cc_license::read_rdfa;
+///
+/// # fn run() -> Result<(), ParseError> {
+/// let html = r#"<p xmlns:cc="http://creativecommons.org/ns#" xmlns:dct="http://purl.org/dc/terms/">
+///   <span property="dct:title">Sunset over the Bay</span> by
+///   <a rel="cc:attributionURL" href="https://example.com/jo"><span property="cc:attributionName">Jo Bloggs</span></a>
+///   is licensed under <a rel="license" href="https://creativecommons.org/licenses/by-sa/4.0/">CC BY-SA 4.0</a>
+/// </p>"#;
+/// let (license, attribution) = read_rdfa(html)?;
+/// assert_eq!(license.short(), "CC BY-SA 4.0");
+/// assert_eq!(attribution.title.as_deref(), Some("Sunset over the Bay"));
+/// assert_eq!(attribution.name.as_deref(), Some("Jo Bloggs"));
+/// assert_eq!(attribution.url.as_deref(), Some("https://example.com/jo"));
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+pub fn read_rdfa(html: &str) -> Result<(License, Attribution), ParseError> {
+    let mut license = None;
+    let mut error = None;
+    let mut attribution = Attribution::default();
+    for element in elements(html) {
+        if license.is_none() && element.has_term("rel", &["license", "cc:license"]) {
+            if let Some(url) = element.link_value() {
+                match License::from_url(&url) {
+                    Ok(parsed) => license = Some(parsed),
+                    Err(e) => {
+                        error.get_or_insert(e);
+                    }
+                }
+            }
+        }
+        if attribution.name.is_none() && element.has_term("property", &["cc:attributionName"]) {
+            attribution.name = element.property_value();
+        }
+        if attribution.url.is_none() {
+            if element.has_term("rel", &["cc:attributionURL"]) {
+                attribution.url = element.link_value();
+            } else if element.has_term("property", &["cc:attributionURL"]) {
+                attribution.url = element.property_value();
+            }
+        }
+        if attribution.title.is_none() && element.has_term("property", &["dct:title", "dc:title"]) {
+            attribution.title = element.property_value();
+        }
+    }
+    match license {
+        Some(license) => Ok((license, attribution)),
+        None => Err(error.unwrap_or(ParseError::InvalidUrl)),
+    }
+}
+
+/// Iterate over the start tags of a document, skipping comments and end tags
+fn elements(html: &str) -> impl Iterator<Item = Element<'_>> {
+    let mut rest = html;
+    std::iter::from_fn(move || loop {
+        let start = rest.find('<')?;
+        rest = &rest[start + 1..];
+        if let Some(comment) = rest.strip_prefix("!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        if !rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            continue;
+        }
+        let (attributes, after) = start_tag(rest);
+        rest = after;
+        let text = &rest[..rest.find('<').unwrap_or(rest.len())];
+        return Some(Element { attributes, text });
+    })
+}
+
+/// Parse the attributes of a start tag, returning them and the input after the tag
+fn start_tag(tag: &str) -> (Vec<(&str, &str)>, &str) {
+    let name_end = tag
+        .find(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+        .unwrap_or(tag.len());
+    let mut rest = &tag[name_end..];
+    let mut attributes = Vec::new();
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+        if rest.is_empty() {
+            return (attributes, rest);
+        }
+        if let Some(after) = rest.strip_prefix('>') {
+            return (attributes, after);
+        }
+        let key_end = rest
+            .find(|c: char| c.is_ascii_whitespace() || c == '=' || c == '>' || c == '/')
+            .unwrap_or(rest.len())
+            .max(1);
+        let key = &rest[..key_end];
+        rest = rest[key_end..].trim_start();
+        let Some(value) = rest.strip_prefix('=') else {
+            attributes.push((key, ""));
+            continue;
+        };
+        let value = value.trim_start();
+        let (value, after) = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let value = &value[1..];
+                let end = value.find(quote).unwrap_or(value.len());
+                (&value[..end], &value[(end + 1).min(value.len())..])
+            }
+            _ => {
+                let end = value
+                    .find(|c: char| c.is_ascii_whitespace() || c == '>')
+                    .unwrap_or(value.len());
+                value.split_at(end)
+            }
+        };
+        attributes.push((key, value));
+        rest = after;
+    }
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rights::Rights;
+    use crate::version::Version;
+
+    #[test]
+    fn test_start_tag() {
+        let (attributes, rest) =
+            start_tag(r#"a rel='license' href="x" data-flag target=_blank>text"#);
+        assert_eq!(
+            attributes,
+            vec![
+                ("rel", "license"),
+                ("href", "x"),
+                ("data-flag", ""),
+                ("target", "_blank")
+            ]
+        );
+        assert_eq!(rest, "text");
+        assert_eq!(start_tag("br/>after"), (vec![], "after"));
+    }
+
+    #[test]
+    fn test_read_rdfa() {
+        let html = r#"<!-- <a rel="license" href="https://creativecommons.org/licenses/by-nd/4.0/"> -->
+            <div about="photo.jpg">
+              <meta property="dct:title" content="Tom &amp; Jerry" />
+              <a rel="nofollow license" href="https://creativecommons.org/licenses/by/5.0/">old</a>
+              <a rel="license" href="http://creativecommons.org/licenses/by-nc/3.0/">new</a>
+              <span property="cc:attributionURL">https://example.com/</span>
+            </div>"#;
+        let (license, attribution) = read_rdfa(html).unwrap();
+        assert_eq!(
+            license,
+            License::from_parts(Rights::ByNc, Version::Three, None)
+        );
+        assert_eq!(
+            attribution,
+            Attribution {
+                name: None,
+                url: Some("https://example.com/".to_string()),
+                title: Some("Tom & Jerry".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_read_rdfa_errors() {
+        assert_eq!(
+            read_rdfa(r#"<a href="https://creativecommons.org/licenses/by/4.0/">"#),
+            Err(ParseError::InvalidUrl)
+        );
+        assert_eq!(
+            read_rdfa(r#"<a rel="license" href="https://creativecommons.org/licenses/by/5.0/">"#),
+            Err(ParseError::InvalidVersion)
+        );
+        assert_eq!(read_rdfa("<a rel=\"license"), Err(ParseError::InvalidUrl));
+    }
+}
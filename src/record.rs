@@ -0,0 +1,218 @@
+use crate::rights::Rights;
+use crate::table::LICENSES;
+use crate::License;
+use std::error::Error;
+use std::fmt;
+
+/// Contradiction between the parts of a [`RightsRecord`]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum RecordError {
+    /// The statement reserves all rights, which no Creative Commons license does
+    AllRightsReserved,
+    /// The statement claims copyright over a work dedicated to the public domain
+    CopyrightClaimed,
+    /// The statement names a different Creative Commons license
+    LicenseMismatch(License),
+    /// The copyright holder is present but blank
+    EmptyCopyrightHolder,
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordError::AllRightsReserved => {
+                f.write_str("The statement reserves all rights, contradicting the license")
+            }
+            RecordError::CopyrightClaimed => f.write_str(
+                "The statement claims copyright over a work dedicated to the public domain",
+            ),
+            RecordError::LicenseMismatch(license) => {
+                write!(
+                    f,
+                    "The statement names a different license: {}",
+                    license.short()
+                )
+            }
+            RecordError::EmptyCopyrightHolder => f.write_str("The copyright holder is blank"),
+        }
+    }
+}
+
+impl Error for RecordError {}
+
+/// A license together with the copyright information stored alongside it
+///
+/// This mirrors how book and media metadata systems record rights: a license, who
+/// holds the copyright and since when, and the free-text rights statement shown to
+/// readers.
+///
+/// # Example
+///
+/// ```rust
+/// # use cc_license::ParseError;
+/// use cc_license::{License, RecordError, RightsRecord};
+///
+/// # fn run() -> Result<(), ParseError> {
+/// let mut record = RightsRecord::new(License::from_url("https://creativecommons.org/licenses/by/4.0/")?);
+/// record.copyright_holder = Some("Jo Bloggs".to_string());
+/// record.copyright_year = Some(2021);
+/// assert_eq!(record.validate(), Ok(()));
+/// assert_eq!(record.to_string(), "© 2021 Jo Bloggs. Licensed under CC BY 4.0.");
+///
+/// record.license = License::from_url("https://creativecommons.org/publicdomain/zero/1.0/")?;
+/// record.statement = Some("© 2021 Jo Bloggs. All rights reserved.".to_string());
+/// assert_eq!(record.validate(), Err(RecordError::AllRightsReserved));
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct RightsRecord {
+    /// License the work is published under
+    pub license: License,
+    /// Person or organisation holding the copyright
+    pub copyright_holder: Option<String>,
+    /// Year of first publication
+    pub copyright_year: Option<u16>,
+    /// Free-text rights statement
+    pub statement: Option<String>,
+}
+
+impl RightsRecord {
+    /// Create a record holding only a license
+    pub fn new(license: License) -> Self {
+        RightsRecord {
+            license,
+            copyright_holder: None,
+            copyright_year: None,
+            statement: None,
+        }
+    }
+
+    /// Check that the parts of the record do not contradict each other
+    ///
+    /// Returns the first contradiction found.
+    pub fn validate(&self) -> Result<(), RecordError> {
+        if self
+            .copyright_holder
+            .as_ref()
+            .is_some_and(|holder| holder.trim().is_empty())
+        {
+            return Err(RecordError::EmptyCopyrightHolder);
+        }
+        let Some(statement) = &self.statement else {
+            return Ok(());
+        };
+        let lowercase = statement.to_lowercase();
+        if lowercase.contains("all rights reserved") {
+            return Err(RecordError::AllRightsReserved);
+        }
+        if matches!(
+            self.license.rights_enum(),
+            Rights::Zero | Rights::Mark | Rights::Certification
+        ) && (lowercase.contains('©') || lowercase.contains("(c)"))
+        {
+            return Err(RecordError::CopyrightClaimed);
+        }
+        let mut mentioned = LICENSES
+            .iter()
+            .filter(|license| lowercase.contains(&license.short().to_lowercase()));
+        if let Some(other) = mentioned.clone().find(|license| **license != self.license) {
+            if !mentioned.any(|license| *license == self.license) {
+                return Err(RecordError::LicenseMismatch(*other));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for RightsRecord {
+    /// Format the statement, or else a notice built from the other parts
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(statement) = &self.statement {
+            return f.write_str(statement);
+        }
+        match (&self.copyright_holder, self.copyright_year) {
+            (Some(holder), Some(year)) => write!(f, "© {} {}. ", year, holder)?,
+            (Some(holder), None) => write!(f, "© {}. ", holder)?,
+            _ => {}
+        }
+        f.write_str("Licensed under ")?;
+        self.license.write_short_to(f)?;
+        f.write_str(".")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::version::Version;
+
+    fn record(rights: Rights, version: Version, statement: &str) -> RightsRecord {
+        RightsRecord {
+            license: License::from_parts(rights, version, None),
+            copyright_holder: Some("Jo Bloggs".to_string()),
+            copyright_year: None,
+            statement: Some(statement.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            record(Rights::BySa, Version::Four, "© Jo Bloggs, CC BY-SA 4.0").validate(),
+            Ok(())
+        );
+        assert_eq!(
+            record(
+                Rights::By,
+                Version::Four,
+                "Copyright Jo Bloggs. All Rights Reserved"
+            )
+            .validate(),
+            Err(RecordError::AllRightsReserved)
+        );
+        assert_eq!(
+            record(Rights::Zero, Version::One, "(c) 2020 Jo Bloggs").validate(),
+            Err(RecordError::CopyrightClaimed)
+        );
+        assert_eq!(
+            record(
+                Rights::Zero,
+                Version::One,
+                "Jo Bloggs has waived all copyright"
+            )
+            .validate(),
+            Ok(())
+        );
+        assert_eq!(
+            record(
+                Rights::ByNc,
+                Version::Four,
+                "Available under CC BY-NC-SA 4.0"
+            )
+            .validate(),
+            Err(RecordError::LicenseMismatch(License::from_parts(
+                Rights::ByNcSa,
+                Version::Four,
+                None
+            )))
+        );
+        let mut blank = RightsRecord::new(License::from_parts(Rights::By, Version::Two, None));
+        blank.copyright_holder = Some(" ".to_string());
+        assert_eq!(blank.validate(), Err(RecordError::EmptyCopyrightHolder));
+    }
+
+    #[test]
+    fn test_to_string() {
+        let mut record = RightsRecord::new(License::from_parts(Rights::ByNd, Version::Three, None));
+        assert_eq!(record.to_string(), "Licensed under CC BY-ND 3.0.");
+        record.copyright_holder = Some("Jo Bloggs".to_string());
+        assert_eq!(
+            record.to_string(),
+            "© Jo Bloggs. Licensed under CC BY-ND 3.0."
+        );
+        record.statement = Some("Some rights reserved".to_string());
+        assert_eq!(record.to_string(), "Some rights reserved");
+    }
+}
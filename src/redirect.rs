@@ -0,0 +1,134 @@
+use crate::lenient::percent_decode;
+use std::borrow::Cow;
+
+/// Remove the Wayback Machine and Google redirect wrappers around a URL, as many
+/// times as they are nested
+///
+/// Wayback Machine URLs such as `https://web.archive.org/web/2019*/<url>` keep
+/// the wrapped URL as the rest of their path, where the double slash after the
+/// scheme is sometimes collapsed. Google redirects such as
+/// `https://www.google.com/url?q=<url>&sa=D` carry it percent-encoded in their
+/// `q` or `url` parameter. Other URLs are returned unchanged.
+pub(crate) fn unwrap(url: &str) -> Cow<'_, str> {
+    let mut url = Cow::Borrowed(url);
+    while let Some(inner) = unwrap_once(url.trim()) {
+        url = Cow::Owned(inner);
+    }
+    url
+}
+
+fn unwrap_once(url: &str) -> Option<String> {
+    let (host, path) = split_host(url)?;
+    if host.eq_ignore_ascii_case("web.archive.org")
+        || host.eq_ignore_ascii_case("wayback.archive.org")
+    {
+        let (_timestamp, inner) = path.strip_prefix("web/")?.split_once('/')?;
+        return Some(restore_scheme(inner));
+    }
+    let host = host.to_ascii_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+    if host
+        .strip_prefix("google.")
+        .is_some_and(|tld| !tld.contains('.') || tld == "co.uk")
+    {
+        let query = path.strip_prefix("url?")?;
+        return query.split('&').find_map(|parameter| {
+            let (key, value) = parameter.split_once('=')?;
+            (key == "q" || key == "url").then(|| percent_decode(value))
+        });
+    }
+    None
+}
+
+/// Split an `http` or `https` URL into its host and the path after the first slash
+fn split_host(url: &str) -> Option<(&str, &str)> {
+    let (scheme, rest) = url.split_once("://")?;
+    if !scheme.eq_ignore_ascii_case("https") && !scheme.eq_ignore_ascii_case("http") {
+        return None;
+    }
+    rest.split_once('/')
+}
+
+/// Restore the double slash after the scheme, which the Wayback Machine may collapse
+fn restore_scheme(url: &str) -> String {
+    for scheme in ["https:/", "http:/"] {
+        if let Some(rest) = url.strip_prefix(scheme) {
+            if !rest.starts_with('/') {
+                return format!("{}/{}", scheme, rest);
+            }
+        }
+    }
+    url.to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{License, ParseError, ParseOptions};
+
+    const URL: &str = "https://creativecommons.org/licenses/by/4.0/";
+
+    #[test]
+    fn test_unwrap_wayback() {
+        for wrapped in [
+            "https://web.archive.org/web/2019*/https://creativecommons.org/licenses/by/4.0/",
+            "http://web.archive.org/web/20190101000000/https://creativecommons.org/licenses/by/4.0/",
+            "https://web.archive.org/web/20190101000000id_/https:/creativecommons.org/licenses/by/4.0/",
+            "https://wayback.archive.org/web/2019/https://creativecommons.org/licenses/by/4.0/",
+            " https://WEB.ARCHIVE.ORG/web/2019*/https://creativecommons.org/licenses/by/4.0/ ",
+        ] {
+            assert_eq!(unwrap(wrapped), URL, "{}", wrapped);
+        }
+    }
+
+    #[test]
+    fn test_unwrap_google() {
+        for wrapped in [
+            "https://www.google.com/url?q=https://creativecommons.org/licenses/by/4.0/&sa=D",
+            "https://www.google.com/url?sa=t&url=https%3A%2F%2Fcreativecommons.org%2Flicenses%2Fby%2F4.0%2F",
+            "https://google.de/url?q=https%3A%2F%2Fcreativecommons.org%2Flicenses%2Fby%2F4.0%2F",
+            "https://www.google.com/url?q=https://web.archive.org/web/2019/https://creativecommons.org/licenses/by/4.0/",
+        ] {
+            assert_eq!(unwrap(wrapped), URL, "{}", wrapped);
+        }
+    }
+
+    #[test]
+    fn test_unwrap_unchanged() {
+        for url in [
+            URL,
+            "https://web.archive.org/",
+            "https://web.archive.org/save/https://creativecommons.org/licenses/by/4.0/",
+            "https://www.google.com/search?q=https://creativecommons.org/licenses/by/4.0/",
+            "https://www.google.example.com/url?q=https://creativecommons.org/licenses/by/4.0/",
+            "https://example.org/web/2019/https://creativecommons.org/licenses/by/4.0/",
+            "",
+        ] {
+            assert_eq!(unwrap(url), url);
+        }
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let wrapped =
+            "https://web.archive.org/web/2019*/https://creativecommons.org/licenses/by/4.0/";
+        let mut options = ParseOptions::new();
+        assert_eq!(
+            License::parse_with(wrapped, &options).map_err(ParseError::from),
+            Err(ParseError::InvalidDomain)
+        );
+        options.unwrap_redirects = true;
+        assert_eq!(
+            License::parse_with(wrapped, &options),
+            Ok(License::CC_BY_4_0)
+        );
+        options.lenient = true;
+        assert_eq!(
+            License::parse_with(
+                "https://web.archive.org/web/2019/creativecommons.org/licenses/BY/4.0",
+                &options
+            ),
+            Ok(License::CC_BY_4_0)
+        );
+    }
+}
@@ -1,9 +1,17 @@
 use crate::error::ParseError;
+use crate::version::Version;
 use std::fmt;
 use std::str::FromStr;
 
-#[derive(Debug, PartialEq)]
-pub(crate) enum Rights {
+/// Rights module of a Creative Commons license
+///
+/// Rights are ordered as the variants are declared: the six attribution
+/// licenses, then CC0, then the Public Domain Mark, then the retired Public
+/// Domain Dedication and Certification, Sampling and Developing Nations licenses,
+/// then the retired 1.0 licenses without attribution.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[non_exhaustive]
+pub enum Rights {
     By,
     BySa,
     ByNd,
@@ -11,9 +19,231 @@ pub(crate) enum Rights {
     ByNcSa,
     ByNcNd,
     Zero,
+    /// The Public Domain Mark, labelling works already free of known copyright
+    Mark,
+    /// The retired Public Domain Dedication and Certification
+    Certification,
+    /// The retired Sampling license
+    Sampling,
+    /// The retired Sampling Plus license
+    SamplingPlus,
+    /// The retired NonCommercial Sampling Plus license
+    NcSamplingPlus,
+    /// The retired Developing Nations license
+    DevNations,
+    /// The retired ShareAlike 1.0 license, without attribution
+    Sa,
+    /// The retired NoDerivs 1.0 license, without attribution
+    Nd,
+    /// The retired NonCommercial 1.0 license, without attribution
+    Nc,
+    /// The retired NonCommercial-ShareAlike 1.0 license, without attribution
+    NcSa,
+    /// The retired NoDerivs-NonCommercial 1.0 license, without attribution
+    NdNc,
 }
 
 impl Rights {
+    /// Every rights module, in order
+    pub(crate) const ALL: [Rights; 18] = [
+        Rights::By,
+        Rights::BySa,
+        Rights::ByNd,
+        Rights::ByNc,
+        Rights::ByNcSa,
+        Rights::ByNcNd,
+        Rights::Zero,
+        Rights::Mark,
+        Rights::Certification,
+        Rights::Sampling,
+        Rights::SamplingPlus,
+        Rights::NcSamplingPlus,
+        Rights::DevNations,
+        Rights::Sa,
+        Rights::Nd,
+        Rights::Nc,
+        Rights::NcSa,
+        Rights::NdNc,
+    ];
+
+    /// Iterate over every rights module, in order
+    ///
+    /// Since the enum is non-exhaustive, later versions of the crate may yield
+    /// more rights.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::Rights;
+    ///
+    /// let attribution = Rights::iter().filter(|rights| rights.to_string().starts_with("CC BY"));
+    /// assert_eq!(attribution.count(), 6);
+    /// ```
+    pub fn iter() -> impl DoubleEndedIterator<Item = Rights> + ExactSizeIterator {
+        Rights::ALL.into_iter()
+    }
+
+    pub(crate) const fn from_slug(slug: &[u8]) -> Option<Self> {
+        match slug {
+            b"by" => Some(Rights::By),
+            b"by-sa" => Some(Rights::BySa),
+            b"by-nd" => Some(Rights::ByNd),
+            b"by-nc" => Some(Rights::ByNc),
+            b"by-nc-sa" => Some(Rights::ByNcSa),
+            b"by-nc-nd" => Some(Rights::ByNcNd),
+            b"zero" => Some(Rights::Zero),
+            b"mark" => Some(Rights::Mark),
+            b"certification" | b"publicdomain" => Some(Rights::Certification),
+            b"sampling" => Some(Rights::Sampling),
+            b"sampling+" => Some(Rights::SamplingPlus),
+            b"nc-sampling+" => Some(Rights::NcSamplingPlus),
+            b"devnations" => Some(Rights::DevNations),
+            b"sa" => Some(Rights::Sa),
+            b"nd" => Some(Rights::Nd),
+            b"nc" => Some(Rights::Nc),
+            b"nc-sa" => Some(Rights::NcSa),
+            b"nd-nc" => Some(Rights::NdNc),
+            _ => None,
+        }
+    }
+
+    pub(crate) const fn slug(&self) -> &'static str {
+        match self {
+            Rights::By => "by",
+            Rights::BySa => "by-sa",
+            Rights::ByNd => "by-nd",
+            Rights::ByNc => "by-nc",
+            Rights::ByNcSa => "by-nc-sa",
+            Rights::ByNcNd => "by-nc-nd",
+            Rights::Zero => "zero",
+            Rights::Mark => "mark",
+            Rights::Certification => "certification",
+            Rights::Sampling => "sampling",
+            Rights::SamplingPlus => "sampling+",
+            Rights::NcSamplingPlus => "nc-sampling+",
+            Rights::DevNations => "devnations",
+            Rights::Sa => "sa",
+            Rights::Nd => "nd",
+            Rights::Nc => "nc",
+            Rights::NcSa => "nc-sa",
+            Rights::NdNc => "nd-nc",
+        }
+    }
+
+    /// Whether the instrument has a single, unversioned edition
+    pub(crate) const fn is_unversioned(&self) -> bool {
+        matches!(self, Rights::Certification)
+    }
+
+    /// The only version the rights were published in, if they were not revised
+    pub(crate) const fn sole_version(&self) -> Option<Version> {
+        match self {
+            Rights::Zero
+            | Rights::Mark
+            | Rights::Certification
+            | Rights::Sampling
+            | Rights::SamplingPlus
+            | Rights::NcSamplingPlus
+            | Rights::Sa
+            | Rights::Nd
+            | Rights::Nc
+            | Rights::NcSa
+            | Rights::NdNc => Some(Version::One),
+            Rights::DevNations => Some(Version::Two),
+            _ => None,
+        }
+    }
+
+    /// Whether the rights are one of the retired Sampling licenses
+    pub(crate) const fn is_sampling(&self) -> bool {
+        matches!(
+            self,
+            Rights::Sampling | Rights::SamplingPlus | Rights::NcSamplingPlus
+        )
+    }
+
+    /// Whether the rights are one of the retired 1.0 licenses without attribution
+    pub(crate) const fn lacks_attribution(&self) -> bool {
+        matches!(
+            self,
+            Rights::Sa | Rights::Nd | Rights::Nc | Rights::NcSa | Rights::NdNc
+        )
+    }
+
+    pub(crate) const fn requires_attribution(&self) -> bool {
+        matches!(
+            self,
+            Rights::By
+                | Rights::BySa
+                | Rights::ByNd
+                | Rights::ByNc
+                | Rights::ByNcSa
+                | Rights::ByNcNd
+                | Rights::Sampling
+                | Rights::SamplingPlus
+                | Rights::NcSamplingPlus
+                | Rights::DevNations
+        )
+    }
+
+    pub(crate) const fn allows_commercial_use(&self) -> bool {
+        !matches!(
+            self,
+            Rights::ByNc
+                | Rights::ByNcSa
+                | Rights::ByNcNd
+                | Rights::NcSamplingPlus
+                | Rights::Nc
+                | Rights::NcSa
+                | Rights::NdNc
+        )
+    }
+
+    pub(crate) const fn allows_derivatives(&self) -> bool {
+        !matches!(
+            self,
+            Rights::ByNd | Rights::ByNcNd | Rights::Nd | Rights::NdNc
+        )
+    }
+
+    pub(crate) const fn requires_share_alike(&self) -> bool {
+        matches!(
+            self,
+            Rights::BySa | Rights::ByNcSa | Rights::Sa | Rights::NcSa
+        )
+    }
+
+    /// Position on the Creative Commons spectrum, from the most to the least free
+    ///
+    /// Licenses without attribution rank with their attribution counterparts.
+    pub(crate) const fn restrictiveness(&self) -> u8 {
+        match self {
+            Rights::Zero | Rights::Mark => 0,
+            Rights::Certification => 1,
+            Rights::By => 2,
+            Rights::BySa | Rights::Sa => 3,
+            Rights::ByNc | Rights::Nc => 4,
+            Rights::ByNcSa | Rights::NcSa => 5,
+            Rights::ByNd | Rights::Nd => 6,
+            Rights::ByNcNd | Rights::NdNc => 7,
+            Rights::SamplingPlus => 8,
+            Rights::NcSamplingPlus => 9,
+            Rights::Sampling => 10,
+            Rights::DevNations => 11,
+        }
+    }
+
+    /// Whether the instrument waives or disclaims copyright instead of licensing it
+    pub(crate) const fn is_public_domain(&self) -> bool {
+        matches!(self, Rights::Zero | Rights::Mark | Rights::Certification)
+    }
+
+    pub(crate) const fn is_retired(&self) -> bool {
+        matches!(self, Rights::Certification | Rights::DevNations)
+            || self.is_sampling()
+            || self.lacks_attribution()
+    }
+
     pub(crate) fn full_text(&self) -> &str {
         match self {
             Rights::By => "Attribution",
@@ -23,6 +253,17 @@ impl Rights {
             Rights::ByNcSa => "Attribution-NonCommercial-ShareAlike",
             Rights::ByNcNd => "Attribution-NonCommercial-NoDerivatives",
             Rights::Zero => "CC0",
+            Rights::Mark => "Public Domain Mark",
+            Rights::Certification => "Public Domain Dedication and Certification",
+            Rights::Sampling => "Sampling",
+            Rights::SamplingPlus => "Sampling Plus",
+            Rights::NcSamplingPlus => "NonCommercial Sampling Plus",
+            Rights::DevNations => "Developing Nations",
+            Rights::Sa => "ShareAlike",
+            Rights::Nd => "NoDerivs",
+            Rights::Nc => "NonCommercial",
+            Rights::NcSa => "NonCommercial-ShareAlike",
+            Rights::NdNc => "NoDerivs-NonCommercial",
         }
     }
 }
@@ -37,6 +278,17 @@ impl fmt::Display for Rights {
             Rights::ByNcSa => "CC BY-NC-SA",
             Rights::ByNcNd => "CC BY-NC-ND",
             Rights::Zero => "CC0",
+            Rights::Mark => "PDM",
+            Rights::Certification => "PDDC",
+            Rights::Sampling => "CC Sampling",
+            Rights::SamplingPlus => "CC Sampling+",
+            Rights::NcSamplingPlus => "CC NC-Sampling+",
+            Rights::DevNations => "CC DevNations",
+            Rights::Sa => "CC SA",
+            Rights::Nd => "CC ND",
+            Rights::Nc => "CC NC",
+            Rights::NcSa => "CC NC-SA",
+            Rights::NdNc => "CC ND-NC",
         };
         write!(f, "{}", rights)
     }
@@ -46,16 +298,7 @@ impl FromStr for Rights {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "by" => Ok(Rights::By),
-            "by-sa" => Ok(Rights::BySa),
-            "by-nd" => Ok(Rights::ByNd),
-            "by-nc" => Ok(Rights::ByNc),
-            "by-nc-sa" => Ok(Rights::ByNcSa),
-            "by-nc-nd" => Ok(Rights::ByNcNd),
-            "zero" => Ok(Rights::Zero),
-            &_ => Err(ParseError::InvalidRights),
-        }
+        Rights::from_slug(s.as_bytes()).ok_or(ParseError::InvalidRights)
     }
 }
 
@@ -73,6 +316,12 @@ mod tests {
         assert_eq!(format!("{}", Rights::ByNcSa), "CC BY-NC-SA".to_string());
         assert_eq!(format!("{}", Rights::ByNcNd), "CC BY-NC-ND".to_string());
         assert_eq!(format!("{}", Rights::Zero), "CC0".to_string());
+        assert_eq!(format!("{}", Rights::Mark), "PDM".to_string());
+        assert_eq!(format!("{}", Rights::Certification), "PDDC".to_string());
+        assert_eq!(
+            format!("{}", Rights::NcSamplingPlus),
+            "CC NC-Sampling+".to_string()
+        );
     }
 
     #[test]
@@ -84,12 +333,65 @@ mod tests {
         assert_eq!(Rights::from_str("by-nc-sa").unwrap(), Rights::ByNcSa);
         assert_eq!(Rights::from_str("by-nc-nd").unwrap(), Rights::ByNcNd);
         assert_eq!(Rights::from_str("zero").unwrap(), Rights::Zero);
+        assert_eq!(Rights::from_str("mark").unwrap(), Rights::Mark);
+        assert_eq!(Rights::from_str("sampling+").unwrap(), Rights::SamplingPlus);
+        assert_eq!(
+            Rights::from_str("certification").unwrap(),
+            Rights::Certification
+        );
+        assert_eq!(
+            Rights::from_str("publicdomain").unwrap(),
+            Rights::Certification
+        );
 
         assert!(Rights::from_str("CC by").is_err());
         assert!(Rights::from_str("cc By").is_err());
         assert!(Rights::from_str("Creative Commons BY").is_err());
     }
 
+    #[test]
+    fn test_slug() {
+        for rights in Rights::iter() {
+            assert_eq!(Rights::from_str(rights.slug()).unwrap(), rights);
+        }
+    }
+
+    #[test]
+    fn test_iter() {
+        assert_eq!(Rights::iter().len(), 18);
+        assert!(Rights::iter()
+            .zip(Rights::iter().skip(1))
+            .all(|(a, b)| a < b));
+        assert!(crate::table::LICENSES
+            .iter()
+            .all(|license| Rights::iter().any(|rights| rights == license.rights_enum())));
+    }
+
+    #[test]
+    fn test_terms() {
+        assert!(Rights::By.requires_attribution());
+        assert!(!Rights::Zero.requires_attribution());
+        assert!(!Rights::Certification.requires_attribution());
+        assert!(Rights::BySa.allows_commercial_use());
+        assert!(!Rights::ByNcSa.allows_commercial_use());
+        assert!(Rights::ByNc.allows_derivatives());
+        assert!(!Rights::ByNcNd.allows_derivatives());
+        assert!(Rights::ByNcSa.requires_share_alike());
+        assert!(!Rights::ByNc.requires_share_alike());
+        assert!(Rights::Certification.is_public_domain());
+        assert!(!Rights::By.is_public_domain());
+        assert!(Rights::Sampling.is_retired());
+        assert!(Rights::DevNations.is_retired());
+        assert!(Rights::NdNc.is_retired());
+        assert!(!Rights::NdNc.requires_attribution());
+        assert!(!Rights::NdNc.allows_derivatives());
+        assert!(!Rights::NdNc.allows_commercial_use());
+        assert!(Rights::NcSa.requires_share_alike());
+        assert_eq!(Rights::DevNations.sole_version(), Some(Version::Two));
+        assert_eq!(Rights::By.sole_version(), None);
+        assert!(!Rights::NcSamplingPlus.allows_commercial_use());
+    }
+
     #[test]
     fn test_full_text() {
         assert_eq!(Rights::By.full_text(), "Attribution");
@@ -105,5 +407,10 @@ mod tests {
             "Attribution-NonCommercial-NoDerivatives"
         );
         assert_eq!(Rights::Zero.full_text(), "CC0");
+        assert_eq!(Rights::Mark.full_text(), "Public Domain Mark");
+        assert_eq!(
+            Rights::Certification.full_text(),
+            "Public Domain Dedication and Certification"
+        );
     }
 }
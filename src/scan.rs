@@ -0,0 +1,317 @@
+use crate::License;
+use memchr::memmem;
+use std::io::{self, Read};
+use std::ops::Range;
+
+const DOMAIN: &str = "creativecommons.org/";
+/// Bytes that may precede the domain in a URL, i.e. `https://www.`
+const LOOKBACK: usize = 12;
+/// Longest URL considered after the domain, longer ones are invalid anyway
+const MAX_PATH: usize = 256;
+/// Bytes read from the reader at a time
+const CHUNK: usize = 64 * 1024;
+
+/// Iterator over the valid Creative Commons license URLs found in a text
+///
+/// Occurrences of the Creative Commons domain are located with a SIMD-accelerated
+/// substring search, and full parsing only runs on the URL surrounding each one,
+/// so large documents can be scanned at close to memory bandwidth. Each item is
+/// the parsed license and the byte range of its URL within the text.
+///
+/// # Example
+///
+/// ```rust
+/// use cc_license::Scanner;
+///
+/// let html = r#"<a rel="license" href="https://creativecommons.org/licenses/by/4.0/">CC BY</a>"#;
+/// let found: Vec<_> = Scanner::new(html).collect();
+/// assert_eq!(found.len(), 1);
+/// assert_eq!(found[0].0.short(), "CC BY 4.0".to_string());
+/// assert_eq!(&html[found[0].1.clone()], "https://creativecommons.org/licenses/by/4.0/");
+/// ```
+pub struct Scanner<'a> {
+    text: &'a str,
+    occurrences: memmem::FindIter<'a, 'static>,
+}
+
+impl<'a> Scanner<'a> {
+    /// Scan a text for license URLs
+    pub fn new(text: &'a str) -> Self {
+        Scanner {
+            text,
+            occurrences: memmem::find_iter(text.as_bytes(), DOMAIN.as_bytes()),
+        }
+    }
+}
+
+impl Iterator for Scanner<'_> {
+    type Item = (License, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for domain in self.occurrences.by_ref() {
+            if let Some(range) = candidate(self.text, domain) {
+                if let Ok(license) = License::from_url(&self.text[range.clone()]) {
+                    return Some((license, range));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Byte range of the URL around an occurrence of the domain, if it has a scheme
+pub(crate) fn candidate(text: &str, domain: usize) -> Option<Range<usize>> {
+    let before = &text[..domain];
+    let before = before.strip_suffix("www.").unwrap_or(before);
+    let start = if before.ends_with("https://") {
+        before.len() - 8
+    } else if before.ends_with("http://") {
+        before.len() - 7
+    } else {
+        return None;
+    };
+    let rest = &text[domain..];
+    let length = rest.find(is_delimiter).unwrap_or(rest.len());
+    let url = rest[..length].trim_end_matches(['.', ',', ';', ':', '!', '?']);
+    Some(start..domain + url.len())
+}
+
+/// Iterator over the valid Creative Commons license URLs found in a reader
+///
+/// The streaming counterpart of [`Scanner`] for documents too large to be held in
+/// memory, such as crawl dumps: input is read in chunks and only a few bytes are
+/// kept between them, so URLs straddling two chunks are still found. Each item is
+/// the parsed license and the byte range of its URL within the whole input, or
+/// the error that stopped reading. URLs must be ASCII, as canonical URLs are.
+///
+/// # Example
+///
+/// ```rust
+/// use cc_license::{License, StreamScanner};
+///
+/// let dump = "<a href=\"https://creativecommons.org/licenses/by/4.0/\">".repeat(3);
+/// let found: Vec<_> = StreamScanner::new(dump.as_bytes()).collect::<Result<_, _>>()?;
+/// assert_eq!(found.len(), 3);
+/// assert_eq!(found[2], (License::CC_BY_4_0, 119..163));
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct StreamScanner<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    /// Offset of the start of the buffer within the input
+    offset: u64,
+    /// Position in the buffer from which occurrences of the domain are searched
+    scanned: usize,
+    eof: bool,
+}
+
+impl<R: Read> StreamScanner<R> {
+    /// Scan a reader for license URLs
+    pub fn new(reader: R) -> Self {
+        StreamScanner {
+            reader,
+            buffer: Vec::new(),
+            offset: 0,
+            scanned: 0,
+            eof: false,
+        }
+    }
+
+    /// Drop the scanned part of the buffer, except what may precede the domain,
+    /// and read the next chunk
+    fn fill(&mut self) -> io::Result<()> {
+        let drop = self.scanned.saturating_sub(LOOKBACK);
+        self.buffer.drain(..drop);
+        self.offset += drop as u64;
+        self.scanned -= drop;
+        let length = self.buffer.len();
+        self.buffer.resize(length + CHUNK, 0);
+        let read = loop {
+            match self.reader.read(&mut self.buffer[length..]) {
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                result => break result,
+            }
+        };
+        self.buffer.truncate(length + *read.as_ref().unwrap_or(&0));
+        match read {
+            Ok(0) => self.eof = true,
+            Ok(_) => {}
+            Err(e) => {
+                self.eof = true;
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse the URL around the occurrence of the domain at the position, whose
+    /// end has been read
+    fn parse(&self, domain: usize, end: usize) -> Option<(License, Range<u64>)> {
+        let mut start = domain.saturating_sub(LOOKBACK);
+        while start < domain && is_continuation(self.buffer[start]) {
+            start += 1;
+        }
+        let window = std::str::from_utf8(&self.buffer[start..end]).ok()?;
+        let range = candidate(window, domain - start)?;
+        let license = License::from_url(&window[range.clone()]).ok()?;
+        let offset = self.offset + start as u64;
+        Some((
+            license,
+            offset + range.start as u64..offset + range.end as u64,
+        ))
+    }
+}
+
+impl<R: Read> Iterator for StreamScanner<R> {
+    type Item = io::Result<(License, Range<u64>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let found = memmem::find(&self.buffer[self.scanned..], DOMAIN.as_bytes());
+            let Some(domain) = found.map(|i| self.scanned + i) else {
+                if self.eof {
+                    return None;
+                }
+                self.scanned = self
+                    .scanned
+                    .max(self.buffer.len().saturating_sub(DOMAIN.len() - 1));
+                if let Err(e) = self.fill() {
+                    return Some(Err(e));
+                }
+                continue;
+            };
+            let rest = &self.buffer[domain..];
+            let end = match rest.iter().position(|&b| is_delimiter_byte(b)) {
+                Some(length) => domain + length,
+                None if self.eof || rest.len() > MAX_PATH => self.buffer.len(),
+                None => {
+                    if let Err(e) = self.fill() {
+                        return Some(Err(e));
+                    }
+                    continue;
+                }
+            };
+            self.scanned = domain + 1;
+            if let Some(found) = self.parse(domain, end) {
+                return Some(Ok(found));
+            }
+        }
+    }
+}
+
+/// Whether the byte continues a multibyte UTF-8 character
+fn is_continuation(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
+}
+
+/// Whether the byte ends a URL, as [`is_delimiter`] for ASCII and any byte of a
+/// multibyte character
+fn is_delimiter_byte(byte: u8) -> bool {
+    !byte.is_ascii() || is_delimiter(char::from(byte))
+}
+
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace()
+        || matches!(
+            c,
+            '"' | '\'' | '<' | '>' | '(' | ')' | '[' | ']' | '{' | '}'
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rights::Rights;
+    use crate::version::Version;
+
+    #[test]
+    fn test_scanner() {
+        let text = "Text under https://creativecommons.org/licenses/by-nc/4.0/, \
+            cover under (http://www.creativecommons.org/licenses/by/3.0). \
+            Not a license: https://creativecommons.org/about/ or creativecommons.org/licenses/by/4.0/ \
+            nor https://creativecommons.org/licenses/by/5.0/. Data: https://creativecommons.org/publicdomain/zero/1.0.";
+        let found: Vec<_> = Scanner::new(text).collect();
+        assert_eq!(found.len(), 3);
+        assert_eq!(
+            found[0].0,
+            License::from_parts(Rights::ByNc, Version::Four, None)
+        );
+        assert_eq!(
+            &text[found[0].1.clone()],
+            "https://creativecommons.org/licenses/by-nc/4.0/"
+        );
+        assert_eq!(
+            &text[found[1].1.clone()],
+            "http://www.creativecommons.org/licenses/by/3.0"
+        );
+        assert_eq!(
+            &text[found[2].1.clone()],
+            "https://creativecommons.org/publicdomain/zero/1.0"
+        );
+        assert_eq!(Scanner::new("").count(), 0);
+    }
+
+    /// Reader returning at most a few bytes per read, to exercise chunk boundaries
+    struct Trickle<'a> {
+        data: &'a [u8],
+        step: usize,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let length = self.step.min(buf.len()).min(self.data.len());
+            buf[..length].copy_from_slice(&self.data[..length]);
+            self.data = &self.data[length..];
+            Ok(length)
+        }
+    }
+
+    #[test]
+    fn test_stream_scanner() {
+        let text = "Text under https://creativecommons.org/licenses/by-nc/4.0/, \
+            cover under (http://www.creativecommons.org/licenses/by/3.0). \
+            Not a license: https://creativecommons.org/about/ or creativecommons.org/licenses/by/4.0/ \
+            nor https://creativecommons.org/licenses/by/5.0/. Data: https://creativecommons.org/publicdomain/zero/1.0.";
+        let expected: Vec<_> = Scanner::new(text)
+            .map(|(license, range)| (license, range.start as u64..range.end as u64))
+            .collect();
+        assert_eq!(expected.len(), 3);
+        for step in [1, 2, 3, 7, 13, 64, CHUNK] {
+            let reader = Trickle {
+                data: text.as_bytes(),
+                step,
+            };
+            let found: Vec<_> = StreamScanner::new(reader).map(Result::unwrap).collect();
+            assert_eq!(found, expected, "{}", step);
+        }
+        assert_eq!(StreamScanner::new(io::empty()).count(), 0);
+    }
+
+    #[test]
+    fn test_stream_scanner_large() {
+        let url = "https://creativecommons.org/licenses/by-sa/4.0/";
+        let padding = "é ".repeat(CHUNK / 3);
+        let text = format!("{padding}{url} {padding}{url}");
+        let found: Vec<_> = StreamScanner::new(text.as_bytes())
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(found.len(), 2);
+        for (license, range) in found {
+            assert_eq!(license, License::CC_BY_SA_4_0);
+            assert_eq!(&text[range.start as usize..range.end as usize], url);
+        }
+    }
+
+    #[test]
+    fn test_stream_scanner_error() {
+        struct Failing;
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("failed"))
+            }
+        }
+        let mut scanner = StreamScanner::new(Failing);
+        assert!(scanner.next().unwrap().is_err());
+        assert!(scanner.next().is_none());
+    }
+}
@@ -0,0 +1,391 @@
+use crate::error::ParseError;
+use crate::License;
+use std::fmt;
+use std::str::FromStr;
+
+/// Calendar date, formatted and parsed as `YYYY-MM-DD`
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Create a date, returning `None` if it does not exist in the Gregorian calendar
+    pub const fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        let leap =
+            year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+        let days = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return None,
+        };
+        if day == 0 || day > days {
+            return None;
+        }
+        Some(Date { year, month, day })
+    }
+
+    /// Year, e.g. 2024
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Month, from 1 to 12
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Day of the month, from 1
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('-');
+        let (Some(year), Some(month), Some(day), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(ParseError::InvalidDate);
+        };
+        if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+            return Err(ParseError::InvalidDate);
+        }
+        let number = |part: &str| {
+            part.bytes()
+                .all(|b| b.is_ascii_digit())
+                .then(|| part.parse::<u16>().ok())
+                .flatten()
+                .ok_or(ParseError::InvalidDate)
+        };
+        Date::new(number(year)?, number(month)? as u8, number(day)? as u8)
+            .ok_or(ParseError::InvalidDate)
+    }
+}
+
+/// A license that only applies within a period of time
+///
+/// An open `start` means the license has always applied, and an open `end` means
+/// it applies indefinitely. The end date is exclusive, so an embargoed work can be
+/// described by a license ending on the day another starts.
+///
+/// Formats as the canonical URL of the license followed by the period, if any,
+/// e.g. `https://creativecommons.org/licenses/by/4.0/ 2025-01-01..` or
+/// `https://creativecommons.org/licenses/by-nc/4.0/ 2024-06-01..2024-07-01`, and
+/// parses back from that form.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct EffectiveLicense {
+    /// License applying within the period
+    pub license: License,
+    /// First day on which the license applies
+    pub start: Option<Date>,
+    /// First day on which the license no longer applies
+    pub end: Option<Date>,
+}
+
+impl EffectiveLicense {
+    /// Create a license applying at all times
+    pub fn new(license: License) -> Self {
+        EffectiveLicense {
+            license,
+            start: None,
+            end: None,
+        }
+    }
+
+    /// Set the first day on which the license applies
+    pub fn starting(mut self, start: Date) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Set the first day on which the license no longer applies
+    pub fn until(mut self, end: Date) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    /// Whether the license applies on the given day
+    pub fn is_in_effect_at(&self, date: Date) -> bool {
+        self.start.is_none_or(|start| start <= date) && self.end.is_none_or(|end| date < end)
+    }
+}
+
+impl fmt::Display for EffectiveLicense {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.license.write_url_to(f)?;
+        if self.start.is_none() && self.end.is_none() {
+            return Ok(());
+        }
+        f.write_str(" ")?;
+        if let Some(start) = self.start {
+            write!(f, "{}", start)?;
+        }
+        f.write_str("..")?;
+        match self.end {
+            Some(end) => write!(f, "{}", end),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FromStr for EffectiveLicense {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (url, period) = match s.split_once(' ') {
+            Some((url, period)) => (url, Some(period.trim())),
+            None => (s, None),
+        };
+        let mut entry = EffectiveLicense::new(License::from_url(url)?);
+        if let Some(period) = period {
+            let (start, end) = period.split_once("..").ok_or(ParseError::InvalidDate)?;
+            if !start.is_empty() {
+                entry.start = Some(start.parse()?);
+            }
+            if !end.is_empty() {
+                entry.end = Some(end.parse()?);
+            }
+        }
+        Ok(entry)
+    }
+}
+
+/// The licenses of a work over time, as in Crossref `license` entries with a
+/// `start_date` or green open access deposits released after an embargo
+///
+/// Formats as one [`EffectiveLicense`] per line, and parses back from that form.
+///
+/// # Example
+///
+/// ```rust
+/// # use cc_license::ParseError;
+/// use cc_license::{Date, EffectiveLicense, License, LicenseSchedule};
+///
+/// # fn run() -> Result<(), ParseError> {
+/// let by = License::from_url("https://creativecommons.org/licenses/by/4.0/")?;
+/// let release: Date = "2025-01-01".parse()?;
+/// let schedule = LicenseSchedule::new().with(EffectiveLicense::new(by).starting(release));
+///
+/// assert_eq!(schedule.license_in_effect_at("2024-06-30".parse()?), None);
+/// assert_eq!(schedule.license_in_effect_at("2025-01-01".parse()?), Some(&by));
+/// assert_eq!(schedule.embargo_end(), Some(release));
+///
+/// let text = schedule.to_string();
+/// assert_eq!(text, "https://creativecommons.org/licenses/by/4.0/ 2025-01-01..");
+/// assert_eq!(text.parse::<LicenseSchedule>()?, schedule);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct LicenseSchedule {
+    entries: Vec<EffectiveLicense>,
+}
+
+impl LicenseSchedule {
+    /// Create a schedule without any license
+    pub fn new() -> Self {
+        LicenseSchedule::default()
+    }
+
+    /// Add a license to the schedule
+    pub fn with(mut self, entry: EffectiveLicense) -> Self {
+        self.push(entry);
+        self
+    }
+
+    /// Add a license to the schedule
+    pub fn push(&mut self, entry: EffectiveLicense) {
+        self.entries.push(entry);
+    }
+
+    /// Every license in the schedule, in order of addition
+    pub fn entries(&self) -> &[EffectiveLicense] {
+        &self.entries
+    }
+
+    /// Obtain the license applying on the given day
+    ///
+    /// When several licenses apply, the one that started most recently wins, so a
+    /// later entry supersedes an open-ended earlier one.
+    pub fn license_in_effect_at(&self, date: Date) -> Option<&License> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.is_in_effect_at(date))
+            .max_by_key(|entry| entry.start)
+            .map(|entry| &entry.license)
+    }
+
+    /// Obtain the first day on which any license applies, if the work is embargoed
+    ///
+    /// Returns `None` when a license applies from the start of time, or when the
+    /// schedule is empty.
+    pub fn embargo_end(&self) -> Option<Date> {
+        self.entries.iter().map(|entry| entry.start).min().flatten()
+    }
+}
+
+impl fmt::Display for LicenseSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, entry) in self.entries.iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            write!(f, "{}", entry)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for LicenseSchedule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let entries = s
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(str::parse)
+            .collect::<Result<_, _>>()?;
+        Ok(LicenseSchedule { entries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rights::Rights;
+    use crate::version::Version;
+
+    fn date(s: &str) -> Date {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_date() {
+        assert_eq!(Date::new(2024, 2, 29), Some(date("2024-02-29")));
+        assert_eq!(Date::new(2023, 2, 29), None);
+        assert_eq!(Date::new(1900, 2, 29), None);
+        assert_eq!(Date::new(2000, 2, 29).unwrap().to_string(), "2000-02-29");
+        assert_eq!(Date::new(2021, 13, 1), None);
+        assert!(date("2021-12-31") < date("2022-01-01"));
+        for invalid in [
+            "2021-1-01",
+            "2021-01-32",
+            "+021-01-01",
+            "2021-01-01-",
+            "20210101",
+        ] {
+            assert_eq!(Date::from_str(invalid), Err(ParseError::InvalidDate));
+        }
+    }
+
+    #[test]
+    fn test_schedule() {
+        let all_rights_reserved_until = date("2023-01-01");
+        let nc = License::from_parts(Rights::ByNc, Version::Four, None);
+        let by = License::from_parts(Rights::By, Version::Four, None);
+        let schedule = LicenseSchedule::new()
+            .with(EffectiveLicense::new(nc).starting(all_rights_reserved_until))
+            .with(EffectiveLicense::new(by).starting(date("2024-01-01")))
+            .with(
+                EffectiveLicense::new(nc)
+                    .starting(date("2024-06-01"))
+                    .until(date("2024-07-01")),
+            );
+        assert_eq!(schedule.license_in_effect_at(date("2022-12-31")), None);
+        assert_eq!(schedule.license_in_effect_at(date("2023-01-01")), Some(&nc));
+        assert_eq!(schedule.license_in_effect_at(date("2024-01-01")), Some(&by));
+        assert_eq!(schedule.license_in_effect_at(date("2024-06-30")), Some(&nc));
+        assert_eq!(schedule.license_in_effect_at(date("2024-07-01")), Some(&by));
+        assert_eq!(schedule.embargo_end(), Some(all_rights_reserved_until));
+        assert_eq!(schedule.entries().len(), 3);
+
+        let open = LicenseSchedule::new().with(EffectiveLicense::new(by));
+        assert_eq!(open.embargo_end(), None);
+        assert_eq!(open.license_in_effect_at(date("1999-01-01")), Some(&by));
+    }
+
+    #[test]
+    fn test_effective_license_text() {
+        let by = License::from_parts(Rights::By, Version::Four, None);
+        for (entry, text) in [
+            (
+                EffectiveLicense::new(by),
+                "https://creativecommons.org/licenses/by/4.0/",
+            ),
+            (
+                EffectiveLicense::new(by).starting(date("2025-01-01")),
+                "https://creativecommons.org/licenses/by/4.0/ 2025-01-01..",
+            ),
+            (
+                EffectiveLicense::new(by).until(date("2024-07-01")),
+                "https://creativecommons.org/licenses/by/4.0/ ..2024-07-01",
+            ),
+            (
+                EffectiveLicense::new(by)
+                    .starting(date("2024-06-01"))
+                    .until(date("2024-07-01")),
+                "https://creativecommons.org/licenses/by/4.0/ 2024-06-01..2024-07-01",
+            ),
+        ] {
+            assert_eq!(entry.to_string(), text);
+            assert_eq!(text.parse(), Ok(entry), "{}", text);
+        }
+        for (text, error) in [
+            (
+                "https://creativecommons.org/licenses/by/5.0/",
+                ParseError::InvalidVersion,
+            ),
+            (
+                "https://creativecommons.org/licenses/by/4.0/ 2025-01-01",
+                ParseError::InvalidDate,
+            ),
+            (
+                "https://creativecommons.org/licenses/by/4.0/ 2025-13-01..",
+                ParseError::InvalidDate,
+            ),
+        ] {
+            assert_eq!(EffectiveLicense::from_str(text), Err(error), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_schedule_text() {
+        let nc = License::from_parts(Rights::ByNc, Version::Four, None);
+        let by = License::from_parts(Rights::By, Version::Four, None);
+        let schedule = LicenseSchedule::new()
+            .with(EffectiveLicense::new(nc).until(date("2024-01-01")))
+            .with(EffectiveLicense::new(by).starting(date("2024-01-01")));
+        let text = schedule.to_string();
+        assert_eq!(
+            text,
+            "https://creativecommons.org/licenses/by-nc/4.0/ ..2024-01-01\n\
+            https://creativecommons.org/licenses/by/4.0/ 2024-01-01.."
+        );
+        assert_eq!(text.parse(), Ok(schedule));
+        assert_eq!(
+            format!("\n{}\n\n", text)
+                .parse::<LicenseSchedule>()
+                .unwrap()
+                .entries()
+                .len(),
+            2
+        );
+        assert_eq!("".parse(), Ok(LicenseSchedule::new()));
+    }
+}
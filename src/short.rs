@@ -0,0 +1,162 @@
+use crate::error::ParseError;
+use crate::jurisdiction::Jurisdiction;
+use crate::rights::Rights;
+use crate::version::Version;
+use crate::License;
+
+impl License {
+    /// Parse a Creative Commons license from its short name, e.g. "CC BY-NC 4.0"
+    ///
+    /// Accepts every name produced by [`License::short`], ignoring case and
+    /// tolerating extra whitespace, including around hyphens. The version of CC0
+    /// and of the Public Domain Mark may be omitted. Ported licenses end with their jurisdiction, e.g.
+    /// "CC BY 3.0 DE".
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_short_name("cc by-nc  4.0")?;
+    /// assert_eq!(license.short(), "CC BY-NC 4.0");
+    /// assert_eq!(License::from_short_name("CC0")?, License::from_short_name("CC0 1.0")?);
+    /// assert_eq!(License::from_short_name("CC BY 5.0"), Err(ParseError::InvalidVersion));
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn from_short_name(name: &str) -> Result<Self, ParseError> {
+        let normalised = normalise(name);
+        let starts_with_digit = |s: &str| s.starts_with(|c: char| c.is_ascii_digit());
+        let (name, jurisdiction) = match normalised.rsplit_once(' ') {
+            Some((name, port))
+                if !starts_with_digit(port)
+                    && name.rsplit(' ').next().is_some_and(starts_with_digit) =>
+            {
+                let jurisdiction = Jurisdiction::from_slug(port.as_bytes())
+                    .ok_or(ParseError::InvalidJurisdiction)?;
+                (name, Some(jurisdiction))
+            }
+            _ => (normalised.as_str(), None),
+        };
+        let (rights, version) = match name.rsplit_once(' ') {
+            Some((rights, version)) if version.starts_with(|c: char| c.is_ascii_digit()) => {
+                (rights, Some(version))
+            }
+            _ => (name, None),
+        };
+        let rights = match rights {
+            "pddc" => Rights::Certification,
+            "cc0" => Rights::Zero,
+            "pdm" => Rights::Mark,
+            _ => {
+                let slug = rights
+                    .strip_prefix("cc ")
+                    .ok_or(ParseError::InvalidRights)?;
+                match Rights::from_slug(slug.as_bytes()) {
+                    Some(rights) if !rights.is_unversioned() && rights != Rights::Zero => rights,
+                    _ => return Err(ParseError::InvalidRights),
+                }
+            }
+        };
+        let version = match version {
+            Some(version) => {
+                Version::from_slug(version.as_bytes()).ok_or(ParseError::InvalidVersion)?
+            }
+            None if matches!(rights, Rights::Zero | Rights::Mark) || rights.is_unversioned() => {
+                Version::One
+            }
+            None => return Err(ParseError::InvalidVersion),
+        };
+        match jurisdiction {
+            Some(jurisdiction) => License::ported(rights, version, jurisdiction),
+            None => License::new(rights, version),
+        }
+    }
+}
+
+/// Lowercase the name, collapse whitespace and remove it around hyphens
+fn normalise(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace(" -", "-")
+        .replace("- ", "-")
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::LICENSES;
+
+    #[test]
+    fn test_round_trip() {
+        for license in LICENSES {
+            assert_eq!(License::from_short_name(&license.short()), Ok(*license));
+        }
+    }
+
+    #[test]
+    fn test_ported() {
+        let license = License::ported(Rights::BySa, Version::Two, Jurisdiction::EnglandAndWales);
+        assert_eq!(License::from_short_name("CC BY-SA 2.0 UK"), license);
+        assert_eq!(License::from_short_name("cc by-sa 2.0 uk"), license);
+        assert_eq!(License::from_short_name(&license.unwrap().short()), license);
+        assert_eq!(
+            License::from_short_name("CC BY 3.0 XX"),
+            Err(ParseError::InvalidJurisdiction)
+        );
+        assert_eq!(
+            License::from_short_name("CC BY 4.0 DE"),
+            Err(ParseError::InvalidJurisdiction)
+        );
+    }
+
+    #[test]
+    fn test_tolerance() {
+        let by_nc_sa = License::new(Rights::ByNcSa, Version::Three).unwrap();
+        for name in [
+            "CC BY-NC-SA 3.0",
+            "cc by-nc-sa 3.0",
+            "  CC   BY - NC -SA\t3.0 ",
+        ] {
+            assert_eq!(License::from_short_name(name), Ok(by_nc_sa));
+        }
+        assert_eq!(
+            License::from_short_name("cc0"),
+            License::new(Rights::Zero, Version::One)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            License::from_short_name("BY 4.0"),
+            Err(ParseError::InvalidRights)
+        );
+        assert_eq!(
+            License::from_short_name("CC BY-XX 4.0"),
+            Err(ParseError::InvalidRights)
+        );
+        assert_eq!(
+            License::from_short_name("CC zero 1.0"),
+            Err(ParseError::InvalidRights)
+        );
+        assert_eq!(
+            License::from_short_name("CC BY"),
+            Err(ParseError::InvalidVersion)
+        );
+        assert_eq!(
+            License::from_short_name("CC BY 4"),
+            Err(ParseError::InvalidVersion)
+        );
+        assert_eq!(
+            License::from_short_name("CC0 2.0"),
+            Err(ParseError::InvalidPublicDomainVersion)
+        );
+        assert_eq!(License::from_short_name(""), Err(ParseError::InvalidRights));
+    }
+}
@@ -0,0 +1,365 @@
+use crate::error::ParseError;
+use crate::jurisdiction::Jurisdiction;
+use crate::rights::Rights;
+use crate::version::Version;
+use crate::License;
+use std::fmt;
+
+impl License {
+    /// Obtain the SPDX identifier of the license, e.g. "CC-BY-NC-ND-3.0"
+    ///
+    /// Ported licenses are suffixed with their jurisdiction, e.g. "CC-BY-3.0-DE".
+    /// `None` for licenses missing from the SPDX License List, which includes few
+    /// of the ported licenses and none of the retired Sampling, Developing Nations
+    /// and attribution-free 1.0 licenses, since systems that only accept SPDX
+    /// identifiers would reject any other.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("https://creativecommons.org/licenses/by-nc-nd/3.0/")?;
+    /// assert_eq!(license.spdx_id().as_deref(), Some("CC-BY-NC-ND-3.0"));
+    /// let license = License::from_url("https://creativecommons.org/publicdomain/zero/1.0/")?;
+    /// assert_eq!(license.spdx_id().as_deref(), Some("CC0-1.0"));
+    /// let license = License::from_url("https://creativecommons.org/licenses/sampling+/1.0/")?;
+    /// assert_eq!(license.spdx_id(), None);
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn spdx_id(&self) -> Option<String> {
+        if !self.is_on_spdx_list() {
+            return None;
+        }
+        let mut id = String::new();
+        self.write_spdx_id_to(&mut id)
+            .expect("writing to a String cannot fail");
+        Some(id)
+    }
+
+    /// Whether the SPDX License List includes the license
+    pub(crate) const fn is_on_spdx_list(&self) -> bool {
+        match self.jurisdiction {
+            None => matches!(
+                self.rights_enum(),
+                Rights::By
+                    | Rights::BySa
+                    | Rights::ByNd
+                    | Rights::ByNc
+                    | Rights::ByNcSa
+                    | Rights::ByNcNd
+                    | Rights::Zero
+                    | Rights::Mark
+                    | Rights::Certification
+            ),
+            Some(jurisdiction) => matches!(
+                (self.rights_enum(), self.version_enum(), jurisdiction),
+                (Rights::By, Version::TwoFive, Jurisdiction::Australia)
+                    | (
+                        Rights::By,
+                        Version::Three,
+                        Jurisdiction::Australia
+                            | Jurisdiction::Austria
+                            | Jurisdiction::Germany
+                            | Jurisdiction::Igo
+                            | Jurisdiction::Netherlands
+                            | Jurisdiction::UnitedStates
+                    )
+                    | (Rights::BySa, Version::Two, Jurisdiction::EnglandAndWales)
+                    | (Rights::BySa, Version::TwoOne, Jurisdiction::Japan)
+                    | (
+                        Rights::BySa,
+                        Version::Three,
+                        Jurisdiction::Austria | Jurisdiction::Germany | Jurisdiction::Igo
+                    )
+                    | (Rights::ByNd, Version::Three, Jurisdiction::Germany)
+                    | (Rights::ByNc, Version::Three, Jurisdiction::Germany)
+                    | (
+                        Rights::ByNcSa,
+                        Version::Two,
+                        Jurisdiction::Germany
+                            | Jurisdiction::France
+                            | Jurisdiction::EnglandAndWales
+                    )
+                    | (
+                        Rights::ByNcSa,
+                        Version::Three,
+                        Jurisdiction::Germany | Jurisdiction::Igo
+                    )
+                    | (
+                        Rights::ByNcNd,
+                        Version::Three,
+                        Jurisdiction::Germany | Jurisdiction::Igo
+                    )
+            ),
+        }
+    }
+
+    pub(crate) fn write_spdx_id_to<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        match self.rights_enum() {
+            Rights::Zero => write!(w, "CC0-{}", self.version_enum()),
+            Rights::Mark => write!(w, "CC-PDM-{}", self.version_enum()),
+            Rights::Certification => w.write_str("CC-PDDC"),
+            _ => {
+                w.write_str("CC-")?;
+                for c in self.rights_enum().slug().chars() {
+                    match c {
+                        '+' => w.write_str("-PLUS")?,
+                        _ => w.write_char(c.to_ascii_uppercase())?,
+                    }
+                }
+                write!(w, "-{}", self.version_enum())?;
+                match self.jurisdiction {
+                    Some(jurisdiction) => write!(w, "-{}", jurisdiction),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+
+    /// Parse a Creative Commons license from its SPDX identifier, e.g. "CC-BY-NC-SA-4.0"
+    ///
+    /// Identifiers are matched case-insensitively, as required by SPDX. Ported
+    /// identifiers such as "CC-BY-3.0-DE" are parsed into the ported license when
+    /// the jurisdiction is known, and into the license they port otherwise.
+    /// Identifiers of other licenses, such as "MIT", fail with
+    /// [`ParseError::InvalidRights`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_spdx_id("CC-BY-NC-SA-4.0")?;
+    /// assert_eq!(license.short(), "CC BY-NC-SA 4.0");
+    /// assert_eq!(License::from_spdx_id("CC0-1.0")?.short(), "CC0 1.0");
+    /// assert_eq!(License::from_spdx_id("Apache-2.0"), Err(ParseError::InvalidRights));
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn from_spdx_id(id: &str) -> Result<Self, ParseError> {
+        let id = id.trim().to_ascii_lowercase();
+        if id == "cc-pddc" {
+            return License::new(Rights::Certification, Version::One);
+        }
+        if let Some(version) = id.strip_prefix("cc0-") {
+            let version =
+                Version::from_slug(version.as_bytes()).ok_or(ParseError::InvalidVersion)?;
+            return License::new(Rights::Zero, version);
+        }
+        if let Some(version) = id.strip_prefix("cc-pdm-") {
+            let version =
+                Version::from_slug(version.as_bytes()).ok_or(ParseError::InvalidVersion)?;
+            return License::new(Rights::Mark, version);
+        }
+        let rest = id.strip_prefix("cc-").ok_or(ParseError::InvalidRights)?;
+        let version_start = rest
+            .find(|c: char| c.is_ascii_digit())
+            .ok_or(ParseError::InvalidVersion)?;
+        let (rights, version) = rest.split_at(version_start);
+        let rights = rights
+            .strip_suffix('-')
+            .and_then(|rights| match rights.strip_suffix("-plus") {
+                Some(sampling) => Rights::from_slug(format!("{sampling}+").as_bytes()),
+                None => Rights::from_slug(rights.as_bytes()),
+            })
+            .filter(|rights| !rights.is_public_domain())
+            .ok_or(ParseError::InvalidRights)?;
+        let (version, jurisdiction) = match version.split_once('-') {
+            Some((version, port)) => match Jurisdiction::from_slug(port.as_bytes()) {
+                Some(jurisdiction) => (version, Some(jurisdiction)),
+                None if (2..=3).contains(&port.len())
+                    && port.bytes().all(|b| b.is_ascii_alphabetic()) =>
+                {
+                    (version, None)
+                }
+                None => return Err(ParseError::InvalidRights),
+            },
+            None => (version, None),
+        };
+        let version = Version::from_slug(version.as_bytes()).ok_or(ParseError::InvalidVersion)?;
+        match jurisdiction {
+            Some(jurisdiction) => License::ported(rights, version, jurisdiction),
+            None => License::new(rights, version),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn license(rights: Rights, version: Version) -> License {
+        License::new(rights, version).unwrap()
+    }
+
+    #[test]
+    fn test_from_spdx_id() {
+        for (id, expected) in [
+            ("CC-BY-4.0", license(Rights::By, Version::Four)),
+            ("CC-BY-SA-2.5", license(Rights::BySa, Version::TwoFive)),
+            ("CC-BY-ND-1.0", license(Rights::ByNd, Version::One)),
+            ("CC-BY-NC-3.0", license(Rights::ByNc, Version::Three)),
+            ("cc-by-nc-sa-2.0", license(Rights::ByNcSa, Version::Two)),
+            ("CC-BY-NC-ND-3.0", license(Rights::ByNcNd, Version::Three)),
+            ("CC0-1.0", license(Rights::Zero, Version::One)),
+            ("CC-PDDC", license(Rights::Certification, Version::One)),
+            ("CC-PDM-1.0", license(Rights::Mark, Version::One)),
+            ("CC-SAMPLING-1.0", license(Rights::Sampling, Version::One)),
+            (
+                "CC-SAMPLING-PLUS-1.0",
+                license(Rights::SamplingPlus, Version::One),
+            ),
+            (
+                "CC-NC-SAMPLING-PLUS-1.0",
+                license(Rights::NcSamplingPlus, Version::One),
+            ),
+        ] {
+            assert_eq!(License::from_spdx_id(id), Ok(expected), "{}", id);
+        }
+    }
+
+    #[test]
+    fn test_spdx_id() {
+        for (license, id) in [
+            (license(Rights::By, Version::Four), "CC-BY-4.0"),
+            (license(Rights::ByNcSa, Version::TwoFive), "CC-BY-NC-SA-2.5"),
+            (license(Rights::ByNcNd, Version::One), "CC-BY-NC-ND-1.0"),
+            (license(Rights::Zero, Version::One), "CC0-1.0"),
+            (license(Rights::Mark, Version::One), "CC-PDM-1.0"),
+            (license(Rights::Certification, Version::One), "CC-PDDC"),
+        ] {
+            assert_eq!(license.spdx_id().as_deref(), Some(id));
+        }
+        for rights in [
+            Rights::Sampling,
+            Rights::SamplingPlus,
+            Rights::NcSamplingPlus,
+            Rights::Sa,
+            Rights::Nd,
+            Rights::Nc,
+            Rights::NcSa,
+            Rights::NdNc,
+        ] {
+            assert_eq!(
+                license(rights, Version::One).spdx_id(),
+                None,
+                "{:?}",
+                rights
+            );
+        }
+        assert_eq!(license(Rights::DevNations, Version::Two).spdx_id(), None);
+    }
+
+    #[test]
+    fn test_spdx_list() {
+        // every Creative Commons identifier on the SPDX License List
+        let mut listed: Vec<String> = [
+            "CC-BY-2.5-AU",
+            "CC-BY-3.0-AT",
+            "CC-BY-3.0-AU",
+            "CC-BY-3.0-DE",
+            "CC-BY-3.0-IGO",
+            "CC-BY-3.0-NL",
+            "CC-BY-3.0-US",
+            "CC-BY-NC-3.0-DE",
+            "CC-BY-NC-ND-3.0-DE",
+            "CC-BY-NC-ND-3.0-IGO",
+            "CC-BY-NC-SA-2.0-DE",
+            "CC-BY-NC-SA-2.0-FR",
+            "CC-BY-NC-SA-2.0-UK",
+            "CC-BY-NC-SA-3.0-DE",
+            "CC-BY-NC-SA-3.0-IGO",
+            "CC-BY-ND-3.0-DE",
+            "CC-BY-SA-2.0-UK",
+            "CC-BY-SA-2.1-JP",
+            "CC-BY-SA-3.0-AT",
+            "CC-BY-SA-3.0-DE",
+            "CC-BY-SA-3.0-IGO",
+            "CC-PDDC",
+            "CC-PDM-1.0",
+            "CC0-1.0",
+        ]
+        .iter()
+        .map(|id| id.to_string())
+        .collect();
+        for rights in ["BY", "BY-SA", "BY-ND", "BY-NC", "BY-NC-SA", "BY-NC-ND"] {
+            for version in ["1.0", "2.0", "2.5", "3.0", "4.0"] {
+                listed.push(format!("CC-{}-{}", rights, version));
+            }
+        }
+        let mut ids = Vec::new();
+        for rights in Rights::iter() {
+            for version in Version::iter() {
+                if let Ok(license) = License::new(rights, version) {
+                    ids.extend(license.spdx_id());
+                }
+                for jurisdiction in Jurisdiction::iter() {
+                    if let Ok(license) = License::ported(rights, version, jurisdiction) {
+                        ids.extend(license.spdx_id());
+                    }
+                }
+            }
+        }
+        ids.sort();
+        listed.sort();
+        assert_eq!(ids, listed);
+        for id in listed {
+            assert_eq!(License::from_spdx_id(&id).unwrap().spdx_id(), Some(id));
+        }
+    }
+
+    #[test]
+    fn test_ported() {
+        assert_eq!(
+            License::from_spdx_id("CC-BY-3.0-DE"),
+            License::ported(Rights::By, Version::Three, Jurisdiction::Germany)
+        );
+        assert_eq!(
+            License::from_spdx_id("CC-BY-NC-ND-3.0-IGO"),
+            License::ported(Rights::ByNcNd, Version::Three, Jurisdiction::Igo)
+        );
+        assert_eq!(
+            License::from_spdx_id("CC-BY-3.0-XY"),
+            Ok(license(Rights::By, Version::Three))
+        );
+        assert_eq!(
+            License::from_spdx_id("CC-BY-SA-2.0-UK"),
+            License::ported(Rights::BySa, Version::Two, Jurisdiction::EnglandAndWales)
+        );
+        let scotland = License::ported(Rights::ByNc, Version::TwoFive, Jurisdiction::Scotland);
+        assert_eq!(scotland.unwrap().spdx_id(), None);
+        assert_eq!(License::from_spdx_id("CC-BY-NC-2.5-SCOTLAND"), scotland);
+        assert_eq!(
+            License::from_spdx_id("CC-BY-4.0-DE"),
+            Err(ParseError::InvalidJurisdiction)
+        );
+        assert_eq!(
+            License::from_spdx_id("CC-BY-3.0-DE-X"),
+            Err(ParseError::InvalidRights)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        for (id, error) in [
+            ("MIT", ParseError::InvalidRights),
+            ("GPL-3.0-or-later", ParseError::InvalidRights),
+            ("CC-BY", ParseError::InvalidVersion),
+            ("CC-BY-5.0", ParseError::InvalidVersion),
+            ("CC-ZERO-1.0", ParseError::InvalidRights),
+            ("CC-XX-4.0", ParseError::InvalidRights),
+            ("CC-BY4.0", ParseError::InvalidRights),
+            ("CC0-4.0", ParseError::InvalidPublicDomainVersion),
+        ] {
+            assert_eq!(License::from_spdx_id(id), Err(error), "{}", id);
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use std::fmt;
+
+/// Whether Creative Commons still recommends a license
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Status {
+    /// Part of a current or superseded-but-supported license suite
+    Active,
+    /// Retired by Creative Commons and no longer recommended for new works
+    Retired,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Active => "Active",
+            Status::Retired => "Retired",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(format!("{}", Status::Active), "Active".to_string());
+        assert_eq!(format!("{}", Status::Retired), "Retired".to_string());
+    }
+}
@@ -0,0 +1,309 @@
+use crate::jurisdiction::{ported, Jurisdiction};
+use crate::rights::Rights;
+use crate::version::Version;
+use crate::License;
+
+/// Build a license known to be valid, failing compilation otherwise
+const fn valid(rights: Rights, version: Version) -> License {
+    match License::new(rights, version) {
+        Ok(license) => license,
+        Err(_) => panic!("invalid license in table"),
+    }
+}
+
+/// Position of the unported license in the table, failing compilation if absent
+const fn position(license: License) -> usize {
+    let mut i = 0;
+    while UNPORTED[i].packed != license.packed {
+        i += 1;
+    }
+    i
+}
+
+macro_rules! licenses {
+    ($($rights: ident $rights_slug: literal at $path: literal as $identifier: literal => [$($version: ident $version_slug: literal $name: ident = $code: literal),+]
+        $(ported [$($ported_version: ident $ported_version_slug: literal),+])?,)+) => {
+        impl License {
+            $($(
+                #[doc = concat!("The license with canonical key `", $rights_slug, "/", $version_slug, "`")]
+                pub const $name: License = valid(Rights::$rights, Version::$version);
+            )+)+
+        }
+
+        /// Every valid unported license, in the order of [`LICENSES`]
+        const UNPORTED: &[License] = &[$($(License::$name,)+)+];
+
+        /// Every valid unported license, interned
+        pub(crate) static LICENSES: &[License] = UNPORTED;
+
+        /// Index of the unported license in [`LICENSES`], if it is valid
+        pub(crate) const fn index(rights: Rights, version: Version) -> Option<usize> {
+            match (rights, version) {
+                $($(
+                    (Rights::$rights, Version::$version) => {
+                        const INDEX: usize = position(License::$name);
+                        Some(INDEX)
+                    }
+                )+)+
+                _ => None,
+            }
+        }
+
+        /// Stable key of every valid license
+        pub(crate) const fn canonical_key(license: &License) -> &'static str {
+            match (license.rights_enum(), license.version_enum()) {
+                $($(
+                    (Rights::$rights, Version::$version) => match license.jurisdiction {
+                        None => concat!($rights_slug, "/", $version_slug),
+                        Some(jurisdiction) => {
+                            ported!(jurisdiction, $rights_slug, "/", $version_slug, "/")
+                        }
+                    },
+                )+)+
+                $($($(
+                    (Rights::$rights, Version::$ported_version) => match license.jurisdiction {
+                        None => unreachable!(),
+                        Some(jurisdiction) => {
+                            ported!(jurisdiction, $rights_slug, "/", $ported_version_slug, "/")
+                        }
+                    },
+                )+)?)+
+                _ => unreachable!(),
+            }
+        }
+
+        /// Path of the canonical URL of every valid license, without surrounding slashes
+        pub(crate) const fn canonical_path(license: &License) -> &'static str {
+            match (license.rights_enum(), license.version_enum()) {
+                $($(
+                    (Rights::$rights, Version::$version) => match license.jurisdiction {
+                        _ if Rights::$rights.is_unversioned() => $path,
+                        None => concat!($path, "/", $version_slug),
+                        Some(jurisdiction) => ported!(jurisdiction, $path, "/", $version_slug, "/"),
+                    },
+                )+)+
+                $($($(
+                    (Rights::$rights, Version::$ported_version) => match license.jurisdiction {
+                        None => unreachable!(),
+                        Some(jurisdiction) => {
+                            ported!(jurisdiction, $path, "/", $ported_version_slug, "/")
+                        }
+                    },
+                )+)?)+
+                _ => unreachable!(),
+            }
+        }
+
+        /// Stable numeric code of every valid unported license
+        pub(crate) const fn canonical_code(license: &License) -> Option<u8> {
+            if license.jurisdiction.is_some() {
+                return None;
+            }
+            Some(match (license.rights_enum(), license.version_enum()) {
+                $($(
+                    (Rights::$rights, Version::$version) => $code,
+                )+)+
+                _ => unreachable!(),
+            })
+        }
+
+        /// License with the given numeric code, if assigned
+        pub(crate) const fn from_code(code: u8) -> Option<License> {
+            match code {
+                $($(
+                    $code => Some(License::$name),
+                )+)+
+                _ => None,
+            }
+        }
+
+        /// Filename-safe identifier of every valid license
+        pub(crate) const fn canonical_identifier(license: &License) -> &'static str {
+            match (license.rights_enum(), license.version_enum()) {
+                $($(
+                    (Rights::$rights, Version::$version) => match license.jurisdiction {
+                        _ if Rights::$rights.is_unversioned() => $identifier,
+                        None => concat!($identifier, "-", $version_slug),
+                        Some(jurisdiction) => {
+                            ported!(jurisdiction, $identifier, "-", $version_slug, "-")
+                        }
+                    },
+                )+)+
+                $($($(
+                    (Rights::$rights, Version::$ported_version) => match license.jurisdiction {
+                        None => unreachable!(),
+                        Some(jurisdiction) => {
+                            ported!(jurisdiction, $identifier, "-", $ported_version_slug, "-")
+                        }
+                    },
+                )+)?)+
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
+// Versions listed as ported only exist as ports to some jurisdictions.
+// Numeric codes are stable: a code is never changed or reused, and new licenses
+// take the next unassigned code. Code 0 is never assigned.
+licenses! {
+    By "by" at "licenses/by" as "cc-by" => [
+        One "1.0" CC_BY_1_0 = 1, Two "2.0" CC_BY_2_0 = 2, TwoFive "2.5" CC_BY_2_5 = 3,
+        Three "3.0" CC_BY_3_0 = 4, Four "4.0" CC_BY_4_0 = 5
+    ] ported [TwoOne "2.1"],
+    BySa "by-sa" at "licenses/by-sa" as "cc-by-sa" => [
+        One "1.0" CC_BY_SA_1_0 = 6, Two "2.0" CC_BY_SA_2_0 = 7, TwoFive "2.5" CC_BY_SA_2_5 = 8,
+        Three "3.0" CC_BY_SA_3_0 = 9, Four "4.0" CC_BY_SA_4_0 = 10
+    ] ported [TwoOne "2.1"],
+    ByNd "by-nd" at "licenses/by-nd" as "cc-by-nd" => [
+        One "1.0" CC_BY_ND_1_0 = 11, Two "2.0" CC_BY_ND_2_0 = 12, TwoFive "2.5" CC_BY_ND_2_5 = 13,
+        Three "3.0" CC_BY_ND_3_0 = 14, Four "4.0" CC_BY_ND_4_0 = 15
+    ] ported [TwoOne "2.1"],
+    ByNc "by-nc" at "licenses/by-nc" as "cc-by-nc" => [
+        One "1.0" CC_BY_NC_1_0 = 16, Two "2.0" CC_BY_NC_2_0 = 17, TwoFive "2.5" CC_BY_NC_2_5 = 18,
+        Three "3.0" CC_BY_NC_3_0 = 19, Four "4.0" CC_BY_NC_4_0 = 20
+    ] ported [TwoOne "2.1"],
+    ByNcSa "by-nc-sa" at "licenses/by-nc-sa" as "cc-by-nc-sa" => [
+        One "1.0" CC_BY_NC_SA_1_0 = 21, Two "2.0" CC_BY_NC_SA_2_0 = 22,
+        TwoFive "2.5" CC_BY_NC_SA_2_5 = 23,
+        Three "3.0" CC_BY_NC_SA_3_0 = 24, Four "4.0" CC_BY_NC_SA_4_0 = 25
+    ] ported [TwoOne "2.1"],
+    ByNcNd "by-nc-nd" at "licenses/by-nc-nd" as "cc-by-nc-nd" => [
+        One "1.0" CC_BY_NC_ND_1_0 = 26, Two "2.0" CC_BY_NC_ND_2_0 = 27,
+        TwoFive "2.5" CC_BY_NC_ND_2_5 = 28,
+        Three "3.0" CC_BY_NC_ND_3_0 = 29, Four "4.0" CC_BY_NC_ND_4_0 = 30
+    ] ported [TwoOne "2.1"],
+    Zero "zero" at "publicdomain/zero" as "cc0" => [One "1.0" CC0_1_0 = 31],
+    Mark "mark" at "publicdomain/mark" as "pdm" => [One "1.0" PDM_1_0 = 33],
+    Certification "certification" at "licenses/publicdomain" as "cc-pddc" => [One "1.0" PDDC = 32],
+    Sampling "sampling" at "licenses/sampling" as "cc-sampling" => [One "1.0" CC_SAMPLING_1_0 = 34],
+    SamplingPlus "sampling+" at "licenses/sampling+" as "cc-sampling-plus" => [
+        One "1.0" CC_SAMPLING_PLUS_1_0 = 35
+    ],
+    NcSamplingPlus "nc-sampling+" at "licenses/nc-sampling+" as "cc-nc-sampling-plus" => [
+        One "1.0" CC_NC_SAMPLING_PLUS_1_0 = 36
+    ],
+    DevNations "devnations" at "licenses/devnations" as "cc-devnations" => [
+        Two "2.0" CC_DEVNATIONS_2_0 = 37
+    ],
+    Sa "sa" at "licenses/sa" as "cc-sa" => [One "1.0" CC_SA_1_0 = 38],
+    Nd "nd" at "licenses/nd" as "cc-nd" => [One "1.0" CC_ND_1_0 = 39],
+    Nc "nc" at "licenses/nc" as "cc-nc" => [One "1.0" CC_NC_1_0 = 40],
+    NcSa "nc-sa" at "licenses/nc-sa" as "cc-nc-sa" => [One "1.0" CC_NC_SA_1_0 = 41],
+    NdNc "nd-nc" at "licenses/nd-nc" as "cc-nd-nc" => [One "1.0" CC_ND_NC_1_0 = 42],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_licenses_are_valid_and_unique() {
+        assert_eq!(LICENSES.len(), 42);
+        for (i, license) in LICENSES.iter().enumerate() {
+            assert!(license.validate().is_ok());
+            assert!(!LICENSES[i + 1..].contains(license));
+        }
+    }
+
+    #[test]
+    fn test_index() {
+        for (i, license) in LICENSES.iter().enumerate() {
+            assert_eq!(
+                index(license.rights_enum(), license.version_enum()),
+                Some(i)
+            );
+        }
+        assert_eq!(index(Rights::Zero, Version::Four), None);
+        assert_eq!(index(Rights::By, Version::TwoOne), None);
+    }
+
+    #[test]
+    fn test_licenses_are_sorted() {
+        assert!(LICENSES.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_canonical_key() {
+        for (i, license) in LICENSES.iter().enumerate() {
+            let key = canonical_key(license);
+            assert_eq!(
+                key,
+                format!(
+                    "{}/{}",
+                    license.rights_enum().slug(),
+                    license.version_enum()
+                )
+            );
+            assert!(LICENSES[i + 1..]
+                .iter()
+                .all(|other| canonical_key(other) != key));
+        }
+    }
+
+    #[test]
+    fn test_canonical_path() {
+        for license in LICENSES {
+            assert_eq!(
+                format!("https://creativecommons.org/{}/", canonical_path(license)),
+                license.to_url()
+            );
+        }
+        assert_eq!(
+            canonical_path(&License::CC_BY_NC_SA_4_0),
+            "licenses/by-nc-sa/4.0"
+        );
+        assert_eq!(canonical_path(&License::PDDC), "licenses/publicdomain");
+        let ported =
+            License::ported(Rights::ByNc, Version::TwoFive, Jurisdiction::Scotland).unwrap();
+        assert_eq!(canonical_path(&ported), "licenses/by-nc/2.5/scotland");
+        assert_eq!(canonical_key(&ported), "by-nc/2.5/scotland");
+        assert_eq!(canonical_identifier(&ported), "cc-by-nc-2.5-scotland");
+        let ported = License::ported(Rights::ByNd, Version::TwoOne, Jurisdiction::Japan).unwrap();
+        assert_eq!(canonical_path(&ported), "licenses/by-nd/2.1/jp");
+        assert_eq!(canonical_key(&ported), "by-nd/2.1/jp");
+        assert_eq!(canonical_identifier(&ported), "cc-by-nd-2.1-jp");
+    }
+
+    #[test]
+    fn test_codes() {
+        for (i, license) in LICENSES.iter().enumerate() {
+            let code = canonical_code(license).unwrap();
+            assert_ne!(code, 0);
+            assert_eq!(from_code(code), Some(*license));
+            assert!(LICENSES[i + 1..]
+                .iter()
+                .all(|other| canonical_code(other) != Some(code)));
+        }
+        assert_eq!(from_code(0), None);
+        let ported = License::ported(Rights::By, Version::Three, Jurisdiction::Germany).unwrap();
+        assert_eq!(canonical_code(&ported), None);
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(License::CC_BY_4_0.short(), "CC BY 4.0");
+        assert_eq!(License::CC_BY_NC_SA_3_0.short(), "CC BY-NC-SA 3.0");
+        assert_eq!(License::CC0_1_0.short(), "CC0 1.0");
+        assert_eq!(License::PDDC.short(), "PDDC");
+        assert_eq!(LICENSES[0], License::CC_BY_1_0);
+    }
+
+    #[test]
+    fn test_get() {
+        let license = License::get(Rights::BySa, Version::TwoFive).unwrap();
+        assert_eq!(
+            license,
+            &License::from_parts(Rights::BySa, Version::TwoFive, None)
+        );
+        assert!(std::ptr::eq(
+            license,
+            License::get(Rights::BySa, Version::TwoFive).unwrap()
+        ));
+        assert!(std::ptr::eq(
+            License::get(Rights::Zero, Version::One).unwrap(),
+            &LICENSES[30]
+        ));
+        assert!(License::get(Rights::Zero, Version::Three).is_none());
+    }
+}
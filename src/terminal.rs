@@ -0,0 +1,152 @@
+use crate::status::Status;
+use crate::License;
+use std::fmt::Write;
+
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[31m";
+const YELLOW: &str = "\x1b[33m";
+const RESET: &str = "\x1b[0m";
+
+/// Escape code of the style, or nothing when writing plain text
+fn style(ansi: bool, code: &'static str) -> &'static str {
+    if ansi {
+        code
+    } else {
+        ""
+    }
+}
+
+fn mark(out: &mut String, ansi: bool, granted: bool, label: &str) {
+    let (colour, symbol) = if granted {
+        (GREEN, "✔")
+    } else {
+        (RED, "✘")
+    };
+    let (colour, reset) = (style(ansi, colour), style(ansi, RESET));
+    let _ = writeln!(out, "  {colour}{symbol}{reset} {label}");
+}
+
+impl License {
+    /// Render the license for display in a terminal using ANSI colours
+    ///
+    /// The output shows the title and abbreviation, the canonical URL, what the
+    /// license permits, with check marks and crosses, and the conditions it
+    /// imposes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let license = License::from_url("https://creativecommons.org/licenses/by-nc/4.0/")?;
+    /// let output = license.render_terminal();
+    /// assert!(output.contains("CC BY-NC 4.0"));
+    /// assert!(output.contains("\x1b[31m✘\x1b[0m Commercial use"));
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn render_terminal(&self) -> String {
+        self.render(true)
+    }
+
+    /// Render the license as [`License::render_terminal`] does, without the ANSI
+    /// colours, for pipes, files and terminals that do not support them
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::License;
+    ///
+    /// let output = License::CC_BY_NC_4_0.render_plain();
+    /// assert!(output.contains("  ✘ Commercial use\n"));
+    /// assert!(!output.contains('\x1b'));
+    /// ```
+    pub fn render_plain(&self) -> String {
+        self.render(false)
+    }
+
+    fn render(&self, ansi: bool) -> String {
+        let bold = style(ansi, BOLD);
+        let dim = style(ansi, DIM);
+        let yellow = style(ansi, YELLOW);
+        let reset = style(ansi, RESET);
+        let mut out = String::new();
+        out.push_str(bold);
+        let _ = self.write_title_to(&mut out);
+        let _ = writeln!(out, "{reset} ({})", self.short());
+        let _ = writeln!(out, "{dim}{}{reset}", self.to_url());
+        if self.status() == Status::Retired {
+            let _ = writeln!(out, "{yellow}Retired: not recommended for new works{reset}");
+        }
+        mark(&mut out, ansi, true, "Share");
+        mark(
+            &mut out,
+            ansi,
+            self.rights_enum().allows_derivatives(),
+            "Adapt",
+        );
+        mark(
+            &mut out,
+            ansi,
+            self.rights_enum().allows_commercial_use(),
+            "Commercial use",
+        );
+        if self.rights_enum().requires_attribution() {
+            let _ = writeln!(out, "  {yellow}●{reset} Attribution required");
+        }
+        if self.rights_enum().requires_share_alike() {
+            let _ = writeln!(out, "  {yellow}●{reset} Adaptations must be shared alike");
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rights::Rights;
+    use crate::version::Version;
+
+    #[test]
+    fn test_render_terminal() {
+        let license = License::from_parts(Rights::BySa, Version::Four, None);
+        assert_eq!(
+            license.render_terminal(),
+            "\x1b[1mCreative Commons Attribution-ShareAlike 4.0 International\x1b[0m (CC BY-SA 4.0)\n\
+            \x1b[2mhttps://creativecommons.org/licenses/by-sa/4.0/\x1b[0m\n  \
+            \x1b[32m✔\x1b[0m Share\n  \
+            \x1b[32m✔\x1b[0m Adapt\n  \
+            \x1b[32m✔\x1b[0m Commercial use\n  \
+            \x1b[33m●\x1b[0m Attribution required\n  \
+            \x1b[33m●\x1b[0m Adaptations must be shared alike\n"
+        );
+    }
+
+    #[test]
+    fn test_render_plain() {
+        let license = License::from_parts(Rights::BySa, Version::Four, None);
+        assert_eq!(
+            license.render_plain(),
+            "Creative Commons Attribution-ShareAlike 4.0 International (CC BY-SA 4.0)\n\
+            https://creativecommons.org/licenses/by-sa/4.0/\n  \
+            ✔ Share\n  \
+            ✔ Adapt\n  \
+            ✔ Commercial use\n  \
+            ● Attribution required\n  \
+            ● Adaptations must be shared alike\n"
+        );
+    }
+
+    #[test]
+    fn test_render_terminal_retired() {
+        let license = License::from_parts(Rights::Certification, Version::One, None);
+        let output = license.render_terminal();
+        assert!(output.contains("Retired"));
+        assert!(!output.contains("Attribution required"));
+    }
+}
@@ -0,0 +1,453 @@
+use crate::error::{Error, ParseError};
+use crate::failure::ParseFailure;
+use crate::License;
+use std::io::Read;
+use std::ops::Range;
+
+/// Most words a short name can span, e.g. "CC BY - NC - SA 3.0 DE"
+const MAX_WORDS: usize = 8;
+
+impl License {
+    /// Extract a Creative Commons license from a free-form rights statement
+    ///
+    /// Returns the first license mentioned in the text, whether by URL, with or
+    /// without its scheme, by SPDX identifier or by short name, e.g. "This book
+    /// is published under a CC BY-NC 4.0 licence." URLs are parsed as by
+    /// [`License::from_url_lenient`]. Ported licenses named in prose must give
+    /// their jurisdiction in upper case, e.g. "CC BY 3.0 DE", so that a word
+    /// following the name is not mistaken for one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cc_license::ParseError;
+    /// use cc_license::License;
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let statement = "This book is published under a CC BY-NC 4.0 licence.";
+    /// assert_eq!(License::from_text(statement)?, License::CC_BY_NC_4_0);
+    /// let statement = "Licensed CC-BY-SA-4.0 (see creativecommons.org/licenses/by-sa/4.0)";
+    /// assert_eq!(License::from_text(statement)?, License::CC_BY_SA_4_0);
+    /// assert_eq!(License::from_text("All rights reserved."), Err(ParseError::NotFound));
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn from_text(text: &str) -> Result<Self, ParseError> {
+        Mentions::new(text)
+            .next()
+            .map(|(license, _)| license)
+            .ok_or(ParseError::NotFound)
+    }
+
+    /// Extract a Creative Commons license from a document read to its end
+    ///
+    /// Returns the first license mentioned, as [`License::from_text`] does. Read
+    /// failures, including text that is not UTF-8, are kept as the source of the
+    /// returned [`Error`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::License;
+    ///
+    /// let page = "<footer>Licensed CC BY-SA 4.0</footer>";
+    /// assert_eq!(License::from_reader(page.as_bytes())?, License::CC_BY_SA_4_0);
+    /// # Ok::<(), cc_license::Error>(())
+    /// ```
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self, Error> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        Ok(License::from_text(&text)?)
+    }
+
+    /// Find every Creative Commons license URL in a text, such as an HTML or XML
+    /// document
+    ///
+    /// Yields each license along with the byte range of its URL, in order of
+    /// appearance. URLs are parsed as by [`License::from_url_lenient`], so they
+    /// may lack their scheme, and invalid ones are skipped. Unlike the `Scanner`
+    /// of the `scan` feature, this needs no extra dependency, at the cost of
+    /// throughput on very large documents.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::License;
+    ///
+    /// let html = r#"<p>Text: <a href="https://creativecommons.org/licenses/by/4.0/">CC BY</a></p>
+    /// <p>Cover: <a href="http://creativecommons.org/licenses/by-nd/3.0/">CC BY-ND</a></p>"#;
+    /// let found: Vec<_> = License::find_all(html).collect();
+    /// assert_eq!(found.len(), 2);
+    /// assert_eq!(found[1].0, License::CC_BY_ND_3_0);
+    /// assert_eq!(&html[found[1].1.clone()], "http://creativecommons.org/licenses/by-nd/3.0/");
+    /// ```
+    pub fn find_all(text: &str) -> impl Iterator<Item = (License, Range<usize>)> + '_ {
+        Mentions {
+            names: false,
+            ..Mentions::new(text)
+        }
+    }
+
+    /// Find every Creative Commons license URL in a text, valid or not
+    ///
+    /// Like [`License::find_all`], but also yields the URLs that point to a
+    /// license or public domain tool yet fail to parse, such as
+    /// `creativecommons.org/licenses/by/5.0/`, with the reason and the position of
+    /// the rejected segment within the URL. Each item carries the byte range of
+    /// the URL within the text, so both matches and malformed URLs can be
+    /// highlighted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{License, ParseError};
+    ///
+    /// let text = "Text under https://creativecommons.org/licenses/by/4.0/, figures under http://creativecommons.org/licenses/by-nc/5.0/.";
+    /// let found: Vec<_> = License::find_all_detailed(text).collect();
+    /// assert_eq!(found.len(), 2);
+    /// assert_eq!(found[0].0, Ok(License::CC_BY_4_0));
+    /// let (result, range) = &found[1];
+    /// let failure = result.as_ref().unwrap_err();
+    /// assert_eq!(failure.error(), ParseError::InvalidVersion);
+    /// assert_eq!(&text[range.clone()], "http://creativecommons.org/licenses/by-nc/5.0/");
+    /// let span = failure.span().unwrap();
+    /// assert_eq!(&text[range.start + span.start..range.start + span.end], "5.0");
+    /// ```
+    pub fn find_all_detailed(
+        text: &str,
+    ) -> impl Iterator<Item = (Result<License, ParseFailure>, Range<usize>)> + '_ {
+        let mut mentions = Mentions {
+            names: false,
+            ..Mentions::new(text)
+        };
+        std::iter::from_fn(move || mentions.next_url_detailed())
+    }
+}
+
+/// Iterator over the licenses mentioned in a text, with the byte range of each
+/// mention, in order of appearance
+pub(crate) struct Mentions<'a> {
+    text: &'a str,
+    position: usize,
+    /// Whether SPDX identifiers and short names are mentions, besides URLs
+    names: bool,
+}
+
+impl<'a> Mentions<'a> {
+    pub(crate) fn new(text: &'a str) -> Self {
+        Mentions {
+            text,
+            position: 0,
+            names: true,
+        }
+    }
+
+    /// Move past the next byte offset a mention may start at, and return it
+    fn next_start(&mut self) -> Option<usize> {
+        while let Some(c) = self.text[self.position..].chars().next() {
+            let start = self.position;
+            self.position += c.len_utf8();
+            if (c.is_ascii_alphanumeric() || c == '/') && self.is_word_start(start) {
+                return Some(start);
+            }
+        }
+        None
+    }
+
+    /// The next license URL, along with the reason it fails to parse if it does
+    fn next_url_detailed(&mut self) -> Option<(Result<License, ParseFailure>, Range<usize>)> {
+        while let Some(start) = self.next_start() {
+            let Some(url) = url_candidate(self.text, start) else {
+                continue;
+            };
+            let result = match License::from_url_lenient(url) {
+                Ok(license) => Ok(license),
+                Err(error) if is_license_path(url) => Err(ParseFailure::new(url, error)),
+                Err(_) => continue,
+            };
+            self.position = start + url.len();
+            return Some((result, start..self.position));
+        }
+        None
+    }
+
+    /// Whether a mention may start at the byte offset, i.e. it is not within a word
+    fn is_word_start(&self, offset: usize) -> bool {
+        self.text[..offset].chars().next_back().is_none_or(|c| {
+            !(c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '/' | '@' | '%'))
+        })
+    }
+}
+
+impl Iterator for Mentions<'_> {
+    type Item = (License, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(start) = self.next_start() {
+            let mention = url_at(self.text, start)
+                .or_else(|| self.names.then(|| name_at(self.text, start)).flatten());
+            if let Some(mention) = mention {
+                self.position = mention.1.end;
+                return Some(mention);
+            }
+        }
+        None
+    }
+}
+
+/// License URL starting at the byte offset
+fn url_at(text: &str, start: usize) -> Option<(License, Range<usize>)> {
+    let url = url_candidate(text, start)?;
+    let license = License::from_url_lenient(url).ok()?;
+    Some((license, start..start + url.len()))
+}
+
+/// Word starting at the byte offset that mentions the Creative Commons domain,
+/// without the punctuation that may follow it in prose
+fn url_candidate(text: &str, start: usize) -> Option<&str> {
+    let rest = &text[start..];
+    let url = &rest[..rest.find(is_delimiter).unwrap_or(rest.len())];
+    let url = url.trim_end_matches(is_punctuation);
+    url.to_ascii_lowercase()
+        .contains("creativecommons.org")
+        .then_some(url)
+}
+
+/// Whether the URL points below the license or public domain paths of the
+/// Creative Commons domain
+fn is_license_path(url: &str) -> bool {
+    let url = url.to_ascii_lowercase();
+    url.contains("creativecommons.org/licenses/")
+        || url.contains("creativecommons.org/publicdomain/")
+}
+
+/// SPDX identifier or short name starting at the byte offset, the longest one
+/// if several words could be part of it
+fn name_at(text: &str, start: usize) -> Option<(License, Range<usize>)> {
+    let rest = &text[start..];
+    let prefix = rest.get(..4).unwrap_or(rest).to_ascii_lowercase();
+    if !prefix.starts_with("cc") && !prefix.starts_with("pd") {
+        return None;
+    }
+    let mut ends = Vec::with_capacity(MAX_WORDS);
+    let mut end = 0;
+    while ends.len() < MAX_WORDS {
+        let word = &rest[end..];
+        let length = word.find(is_delimiter).unwrap_or(word.len());
+        end += length;
+        ends.push(end);
+        let next = &rest[end..];
+        let spaces = next.len() - next.trim_start_matches(char::is_whitespace).len();
+        if spaces == 0 || spaces == next.len() {
+            break;
+        }
+        end += spaces;
+    }
+    ends.iter().enumerate().rev().find_map(|(i, &end)| {
+        let name = rest[..end].trim_end_matches(is_punctuation);
+        let license = License::from_spdx_id(name)
+            .or_else(|_| License::from_short_name(name))
+            .ok()?;
+        let last_word = name.rsplit(char::is_whitespace).next().unwrap_or(name);
+        if i > 0
+            && license.jurisdiction.is_some()
+            && last_word.bytes().any(|b| b.is_ascii_lowercase())
+        {
+            return None;
+        }
+        Some((license, start..start + name.len()))
+    })
+}
+
+/// Whether the character ends a URL or name within prose or markup
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace()
+        || matches!(
+            c,
+            '"' | '\'' | '<' | '>' | '(' | ')' | '[' | ']' | '{' | '}'
+        )
+}
+
+/// Whether the character is punctuation that may follow a mention in prose
+fn is_punctuation(c: char) -> bool {
+    matches!(c, '.' | ',' | ';' | ':' | '!' | '?')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jurisdiction::Jurisdiction;
+    use crate::rights::Rights;
+    use crate::version::Version;
+
+    #[test]
+    fn test_from_text() {
+        for (text, license) in [
+            (
+                "This book is published under a CC BY-NC 4.0 licence.",
+                License::CC_BY_NC_4_0,
+            ),
+            ("© The Authors. CC-BY-4.0", License::CC_BY_4_0),
+            (
+                "See https://creativecommons.org/licenses/by-nd/4.0/.",
+                License::CC_BY_ND_4_0,
+            ),
+            (
+                "(creativecommons.org/publicdomain/zero/1.0)",
+                License::CC0_1_0,
+            ),
+            (
+                r#"<a rel="license" href="http://creativecommons.org/licenses/by/3.0/">CC BY 3.0</a>"#,
+                License::CC_BY_3_0,
+            ),
+            ("Dedicated to the public domain (CC0).", License::CC0_1_0),
+            ("cc by-sa 3.0, see below", License::CC_BY_SA_3_0),
+            ("Released under CC BY 2.5 in 2010", License::CC_BY_2_5),
+            ("CC BY - NC - SA 4.0", License::CC_BY_NC_SA_4_0),
+            ("CC BY 5.0 or rather CC BY-SA 4.0", License::CC_BY_SA_4_0),
+        ] {
+            assert_eq!(License::from_text(text), Ok(license), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_ported() {
+        let license =
+            License::ported(Rights::By, Version::Three, Jurisdiction::UnitedStates).unwrap();
+        assert_eq!(
+            License::from_text("Released under CC BY 3.0 US."),
+            Ok(license)
+        );
+        assert_eq!(License::from_text("Released as CC-BY-3.0-US"), Ok(license));
+        assert_eq!(
+            License::from_text("Released under CC BY 2.5 in India"),
+            Ok(License::CC_BY_2_5)
+        );
+    }
+
+    #[test]
+    fn test_not_found() {
+        for text in [
+            "",
+            "All rights reserved.",
+            "CC BY 5.0",
+            "https://creativecommons.org/about/",
+            "ACC BY 4.0",
+            "CCBY",
+            "CC",
+        ] {
+            assert_eq!(
+                License::from_text(text),
+                Err(ParseError::NotFound),
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_reader() {
+        use std::error::Error as _;
+        use std::io;
+
+        assert_eq!(
+            License::from_reader("See creativecommons.org/licenses/by/4.0/".as_bytes()).unwrap(),
+            License::CC_BY_4_0
+        );
+        let error = License::from_reader(&b"CC BY 4.0 \xff"[..]).unwrap_err();
+        assert!(matches!(error, Error::Io(ref e) if e.kind() == io::ErrorKind::InvalidData));
+        assert!(error.source().is_some());
+
+        struct Failing;
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("failed"))
+            }
+        }
+        let error = License::from_reader(Failing).unwrap_err();
+        assert_eq!(error.parse_error(), None);
+        assert_eq!(error.source().unwrap().to_string(), "failed");
+        assert_eq!(
+            License::from_reader(io::empty()).unwrap_err().parse_error(),
+            Some(ParseError::NotFound)
+        );
+    }
+
+    #[test]
+    fn test_mentions() {
+        let text = "Text CC BY 4.0, cover https://creativecommons.org/licenses/by-nd/3.0/.";
+        let found: Vec<_> = Mentions::new(text).collect();
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].0, License::CC_BY_4_0);
+        assert_eq!(&text[found[0].1.clone()], "CC BY 4.0");
+        assert_eq!(found[1].0, License::CC_BY_ND_3_0);
+        assert_eq!(
+            &text[found[1].1.clone()],
+            "https://creativecommons.org/licenses/by-nd/3.0/"
+        );
+    }
+
+    #[test]
+    fn test_find_all() {
+        let xml = "<license>CC BY 4.0</license>\
+            <license href=\"https://creativecommons.org/licenses/by/4.0/\"/>\
+            <cover>creativecommons.org/licenses/by-nc/4.0</cover>\
+            <invalid>https://creativecommons.org/licenses/by/5.0/</invalid>";
+        let found: Vec<_> = License::find_all(xml).collect();
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].0, License::CC_BY_4_0);
+        assert_eq!(found[1].0, License::CC_BY_NC_4_0);
+        assert_eq!(
+            &xml[found[1].1.clone()],
+            "creativecommons.org/licenses/by-nc/4.0"
+        );
+        assert_eq!(License::find_all("").count(), 0);
+    }
+
+    #[test]
+    fn test_find_all_detailed() {
+        let text = "CC BY 4.0 (https://creativecommons.org/about/), \
+            creativecommons.org/licenses/BY-XX/4.0, \
+            <https://creativecommons.org/licenses/by/4.0/>, \
+            https://creativecommons.org/publicdomain/zero/2.0/.";
+        let found: Vec<_> = License::find_all_detailed(text).collect();
+        assert_eq!(found.len(), 3);
+
+        let (result, range) = &found[0];
+        assert_eq!(
+            &text[range.clone()],
+            "creativecommons.org/licenses/BY-XX/4.0"
+        );
+        let failure = result.as_ref().unwrap_err();
+        assert_eq!(failure.error(), ParseError::InvalidRights);
+        assert_eq!(failure.rejected(), Some("BY-XX"));
+        let span = failure.span().unwrap();
+        assert_eq!(
+            &text[range.start + span.start..range.start + span.end],
+            "BY-XX"
+        );
+
+        assert_eq!(found[1].0, Ok(License::CC_BY_4_0));
+        assert_eq!(
+            &text[found[1].1.clone()],
+            "https://creativecommons.org/licenses/by/4.0/"
+        );
+
+        let (result, range) = &found[2];
+        assert_eq!(
+            &text[range.clone()],
+            "https://creativecommons.org/publicdomain/zero/2.0/"
+        );
+        assert_eq!(
+            result.as_ref().unwrap_err().error(),
+            ParseError::InvalidPublicDomainVersion
+        );
+
+        let valid: Vec<_> = License::find_all_detailed(text)
+            .filter_map(|(result, range)| Some((result.ok()?, range)))
+            .collect();
+        assert_eq!(valid, License::find_all(text).collect::<Vec<_>>());
+        assert_eq!(License::find_all_detailed("").count(), 0);
+    }
+}
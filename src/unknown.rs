@@ -0,0 +1,197 @@
+use crate::error::ParseError;
+use crate::rights::Rights;
+use crate::version::Version;
+use crate::view::LicenseRef;
+use crate::License;
+use std::fmt;
+
+/// Outcome of parsing a license URL that may name a license published after this
+/// version of the crate
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Recognition {
+    /// A license known to the crate
+    Known(License),
+    /// A well-formed Creative Commons license URL with unrecognised segments
+    Unknown(UnknownLicense),
+}
+
+/// Rights and version segments of a license the crate does not know yet
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct UnknownLicense {
+    rights: String,
+    version: String,
+}
+
+impl UnknownLicense {
+    /// The rights segment of the URL, e.g. `by-nc`
+    pub fn rights(&self) -> &str {
+        &self.rights
+    }
+
+    /// The version segment of the URL, e.g. `5.0`
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// The rights, if they are known on their own
+    ///
+    /// The version may be new, or known but never published with these rights,
+    /// e.g. `by/2.1`, which only exists as ported licenses.
+    pub fn known_rights(&self) -> Option<Rights> {
+        Rights::from_slug(self.rights.as_bytes())
+    }
+
+    /// The version, if it is known on its own
+    ///
+    /// The rights may be new, or known but never published in this version, e.g.
+    /// `by/2.1`, which only exists as ported licenses.
+    pub fn known_version(&self) -> Option<Version> {
+        Version::from_slug(self.version.as_bytes())
+    }
+}
+
+impl fmt::Display for UnknownLicense {
+    /// Format the license as its likely short name, e.g. "CC BY-NC 5.0"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CC {} {}", self.rights.to_uppercase(), self.version)
+    }
+}
+
+impl License {
+    /// Parse a license URL, accepting well-formed URLs of licenses not known yet
+    ///
+    /// When Creative Commons publishes a new suite or rights module, its URLs
+    /// keep the same shape, so instead of rejecting them this returns
+    /// [`Recognition::Unknown`] and leaves the decision to the caller. The rights
+    /// segment must consist of lowercase letters and hyphens and the version of
+    /// digits around a single dot; anything else fails as with [`License::from_url`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{License, ParseError, Recognition, Rights};
+    ///
+    /// let url = "https://creativecommons.org/licenses/by-nc/5.0/";
+    /// assert_eq!(License::from_url(url), Err(ParseError::InvalidVersion));
+    /// match License::from_url_forward_compatible(url)? {
+    ///     Recognition::Known(license) => println!("{}", license),
+    ///     Recognition::Unknown(unknown) => {
+    ///         assert_eq!(unknown.known_rights(), Some(Rights::ByNc));
+    ///         assert_eq!(unknown.to_string(), "CC BY-NC 5.0");
+    ///     }
+    /// }
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn from_url_forward_compatible(url: &str) -> Result<Recognition, ParseError> {
+        let view = LicenseRef::parse(url)?;
+        let error = match view.to_license() {
+            Ok(license) => return Ok(Recognition::Known(license)),
+            Err(
+                e @ (ParseError::InvalidUrl
+                | ParseError::InvalidScheme
+                | ParseError::InvalidDomain
+                | ParseError::TrailingSegments),
+            ) => return Err(e),
+            Err(e) => e,
+        };
+        let rights = view.rights();
+        let Some(version) = view.version() else {
+            return Err(error);
+        };
+        if !is_rights_slug(rights) || !is_version_slug(version) {
+            return Err(error);
+        }
+        Ok(Recognition::Unknown(UnknownLicense {
+            rights: rights.to_string(),
+            version: version.to_string(),
+        }))
+    }
+}
+
+fn is_rights_slug(s: &str) -> bool {
+    !s.is_empty()
+        && !s.starts_with('-')
+        && !s.ends_with('-')
+        && s.bytes().all(|b| b.is_ascii_lowercase() || b == b'-')
+}
+
+fn is_version_slug(s: &str) -> bool {
+    let digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+    s.split_once('.')
+        .is_some_and(|(major, minor)| digits(major) && digits(minor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known() {
+        assert_eq!(
+            License::from_url_forward_compatible("https://creativecommons.org/licenses/by/4.0/"),
+            Ok(Recognition::Known(License::CC_BY_4_0))
+        );
+    }
+
+    #[test]
+    fn test_unknown() {
+        let Ok(Recognition::Unknown(unknown)) =
+            License::from_url_forward_compatible("https://creativecommons.org/licenses/by-xx/4.0/")
+        else {
+            panic!("expected an unknown license");
+        };
+        assert_eq!(unknown.rights(), "by-xx");
+        assert_eq!(unknown.version(), "4.0");
+        assert_eq!(unknown.known_rights(), None);
+        assert_eq!(unknown.known_version(), Some(Version::Four));
+
+        let Ok(Recognition::Unknown(unknown)) = License::from_url_forward_compatible(
+            "https://creativecommons.org/publicdomain/zero/2.0/",
+        ) else {
+            panic!("expected an unknown license");
+        };
+        assert_eq!(unknown.known_rights(), Some(Rights::Zero));
+        assert_eq!(unknown.to_string(), "CC ZERO 2.0");
+
+        let Ok(Recognition::Unknown(unknown)) =
+            License::from_url_forward_compatible("https://creativecommons.org/licenses/by/2.1/")
+        else {
+            panic!("expected an unknown license");
+        };
+        assert_eq!(unknown.known_rights(), Some(Rights::By));
+        assert_eq!(unknown.known_version(), Some(Version::TwoOne));
+    }
+
+    #[test]
+    fn test_malformed() {
+        for (url, error) in [
+            (
+                "https://creativecommons.org/licenses/BY/5.0/",
+                ParseError::InvalidRights,
+            ),
+            (
+                "https://creativecommons.org/licenses/by/5/",
+                ParseError::InvalidVersion,
+            ),
+            (
+                "https://creativecommons.org/licenses/by/v5.0/",
+                ParseError::InvalidVersion,
+            ),
+            (
+                "https://creativecommons.org/licenses/-by/5.0/",
+                ParseError::InvalidRights,
+            ),
+            (
+                "https://example.org/licenses/by/5.0/",
+                ParseError::InvalidDomain,
+            ),
+        ] {
+            assert_eq!(
+                License::from_url_forward_compatible(url),
+                Err(error),
+                "{}",
+                url
+            );
+        }
+    }
+}
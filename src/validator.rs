@@ -0,0 +1,173 @@
+use crate::error::ParseError;
+use crate::License;
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+
+const DEFAULT_SAMPLE_LIMIT: usize = 10;
+
+/// Incremental validator for arbitrarily long streams of license URLs
+///
+/// Memory use is bounded regardless of the number of records: the validator keeps
+/// counters per license and per error, plus at most a fixed number of failing
+/// records as samples.
+///
+/// # Example
+///
+/// ```rust
+/// use cc_license::{ParseError, Validator};
+///
+/// let mut validator = Validator::new();
+/// validator.feed("https://creativecommons.org/licenses/by/4.0/");
+/// validator.feed("https://creativecommons.org/licenses/by/4.0");
+/// validator.feed("https://creativecommons.org/licenses/by/5.0/");
+/// assert_eq!(validator.total(), 3);
+/// assert_eq!(validator.valid(), 2);
+/// assert_eq!(validator.count(ParseError::InvalidVersion), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Validator {
+    total: u64,
+    licenses: HashMap<License, u64>,
+    errors: HashMap<ParseError, u64>,
+    samples: Vec<(u64, String, ParseError)>,
+    sample_limit: usize,
+}
+
+impl Default for Validator {
+    fn default() -> Self {
+        Validator::with_sample_limit(DEFAULT_SAMPLE_LIMIT)
+    }
+}
+
+impl Validator {
+    /// Create a validator keeping up to 10 error samples
+    pub fn new() -> Self {
+        Validator::default()
+    }
+
+    /// Create a validator keeping up to `limit` error samples
+    pub fn with_sample_limit(limit: usize) -> Self {
+        Validator {
+            total: 0,
+            licenses: HashMap::new(),
+            errors: HashMap::new(),
+            samples: Vec::with_capacity(limit),
+            sample_limit: limit,
+        }
+    }
+
+    /// Validate a single record and update the statistics
+    pub fn feed(&mut self, record: &str) -> Result<License, ParseError> {
+        let result = License::from_url(record);
+        match result {
+            Ok(license) => *self.licenses.entry(license).or_insert(0) += 1,
+            Err(e) => {
+                *self.errors.entry(e).or_insert(0) += 1;
+                if self.samples.len() < self.sample_limit {
+                    self.samples.push((self.total, record.to_string(), e));
+                }
+            }
+        }
+        self.total += 1;
+        result
+    }
+
+    /// Validate every line of a reader, one record per line
+    ///
+    /// Line terminators are stripped and empty lines are skipped. Lines are read
+    /// into a single reused buffer, so memory use does not grow with the input.
+    pub fn feed_reader<R: BufRead>(&mut self, mut reader: R) -> io::Result<()> {
+        let mut line = String::new();
+        while reader.read_line(&mut line)? != 0 {
+            let record = line.trim_end_matches(['\n', '\r']);
+            if !record.is_empty() {
+                let _ = self.feed(record);
+            }
+            line.clear();
+        }
+        Ok(())
+    }
+
+    /// Number of records validated so far
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Number of valid records so far
+    pub fn valid(&self) -> u64 {
+        self.licenses.values().sum()
+    }
+
+    /// Number of invalid records so far
+    pub fn invalid(&self) -> u64 {
+        self.errors.values().sum()
+    }
+
+    /// Number of records that failed with the given error
+    pub fn count(&self, error: ParseError) -> u64 {
+        self.errors.get(&error).copied().unwrap_or(0)
+    }
+
+    /// Number of records that parsed into the given license
+    pub fn occurrences(&self, license: &License) -> u64 {
+        self.licenses.get(license).copied().unwrap_or(0)
+    }
+
+    /// The first failing records, with their position in the stream and error
+    pub fn samples(&self) -> &[(u64, String, ParseError)] {
+        &self.samples
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feed() {
+        let mut validator = Validator::with_sample_limit(1);
+        assert!(validator
+            .feed("https://creativecommons.org/licenses/by/4.0/")
+            .is_ok());
+        assert!(validator.feed("CC BY").is_err());
+        assert!(validator
+            .feed("https://creativecommons.org/licenses/by/1.1/")
+            .is_err());
+        assert_eq!(validator.total(), 3);
+        assert_eq!(validator.valid(), 1);
+        assert_eq!(validator.invalid(), 2);
+        assert_eq!(validator.count(ParseError::InvalidScheme), 1);
+        assert_eq!(validator.count(ParseError::InvalidVersion), 1);
+        assert_eq!(
+            validator.samples(),
+            &[(1, "CC BY".to_string(), ParseError::InvalidScheme)]
+        );
+    }
+
+    #[test]
+    fn test_feed_reader() {
+        let input = "https://creativecommons.org/licenses/by/4.0/\r\n\
+            \n\
+            https://creativecommons.org/licenses/by/4.0\n\
+            https://creativecommons.org/licenses/by-sa/4.0/\n\
+            https://creativecommons.org/publicdomain/zero/2.0/";
+        let mut validator = Validator::new();
+        validator.feed_reader(input.as_bytes()).unwrap();
+        assert_eq!(validator.total(), 4);
+        assert_eq!(validator.valid(), 3);
+        assert_eq!(
+            validator.occurrences(
+                &License::from_url("https://creativecommons.org/licenses/by/4.0/").unwrap()
+            ),
+            2
+        );
+        assert_eq!(
+            validator.samples(),
+            &[(
+                3,
+                "https://creativecommons.org/publicdomain/zero/2.0/".to_string(),
+                ParseError::InvalidPublicDomainVersion
+            )]
+        );
+    }
+}
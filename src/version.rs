@@ -2,25 +2,78 @@ use crate::error::ParseError;
 use std::fmt;
 use std::str::FromStr;
 
-#[derive(Debug, PartialEq)]
-pub(crate) enum Version {
+/// Version of a Creative Commons license suite
+///
+/// Versions are ordered chronologically.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[non_exhaustive]
+pub enum Version {
     One,
     Two,
+    /// Only published as ports to some jurisdictions, such as Japan
+    TwoOne,
     TwoFive,
     Three,
     Four,
 }
 
-impl fmt::Display for Version {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let version = match self {
+impl Version {
+    /// Every version, in chronological order
+    pub(crate) const ALL: [Version; 6] = [
+        Version::One,
+        Version::Two,
+        Version::TwoOne,
+        Version::TwoFive,
+        Version::Three,
+        Version::Four,
+    ];
+
+    /// Iterate over every version, in chronological order
+    ///
+    /// Since the enum is non-exhaustive, later versions of the crate may yield
+    /// more versions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{License, Rights, Version};
+    ///
+    /// let versions: Vec<Version> = Version::iter()
+    ///     .filter(|version| License::new(Rights::By, *version).is_ok())
+    ///     .collect();
+    /// assert_eq!(versions.last(), Some(&Version::Four));
+    /// ```
+    pub fn iter() -> impl DoubleEndedIterator<Item = Version> + ExactSizeIterator {
+        Version::ALL.into_iter()
+    }
+
+    pub(crate) const fn from_slug(slug: &[u8]) -> Option<Self> {
+        match slug {
+            b"1.0" => Some(Version::One),
+            b"2.0" => Some(Version::Two),
+            b"2.1" => Some(Version::TwoOne),
+            b"2.5" => Some(Version::TwoFive),
+            b"3.0" => Some(Version::Three),
+            b"4.0" => Some(Version::Four),
+            _ => None,
+        }
+    }
+
+    pub(crate) const fn slug(&self) -> &'static str {
+        match self {
             Version::One => "1.0",
             Version::Two => "2.0",
+            Version::TwoOne => "2.1",
             Version::TwoFive => "2.5",
             Version::Three => "3.0",
             Version::Four => "4.0",
-        };
-        write!(f, "{}", version)
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.slug())
     }
 }
 
@@ -28,14 +81,7 @@ impl FromStr for Version {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "1.0" => Ok(Version::One),
-            "2.0" => Ok(Version::Two),
-            "2.5" => Ok(Version::TwoFive),
-            "3.0" => Ok(Version::Three),
-            "4.0" => Ok(Version::Four),
-            &_ => Err(ParseError::InvalidVersion),
-        }
+        Version::from_slug(s.as_bytes()).ok_or(ParseError::InvalidVersion)
     }
 }
 
@@ -48,6 +94,7 @@ mod tests {
     fn test_to_string() {
         assert_eq!(format!("{}", Version::One), "1.0".to_string());
         assert_eq!(format!("{}", Version::Two), "2.0".to_string());
+        assert_eq!(format!("{}", Version::TwoOne), "2.1".to_string());
         assert_eq!(format!("{}", Version::TwoFive), "2.5".to_string());
         assert_eq!(format!("{}", Version::Three), "3.0".to_string());
         assert_eq!(format!("{}", Version::Four), "4.0".to_string());
@@ -57,6 +104,7 @@ mod tests {
     fn test_from_string() {
         assert_eq!(Version::from_str("1.0").unwrap(), Version::One);
         assert_eq!(Version::from_str("2.0").unwrap(), Version::Two);
+        assert_eq!(Version::from_str("2.1").unwrap(), Version::TwoOne);
         assert_eq!(Version::from_str("2.5").unwrap(), Version::TwoFive);
         assert_eq!(Version::from_str("3.0").unwrap(), Version::Three);
         assert_eq!(Version::from_str("4.0").unwrap(), Version::Four);
@@ -65,4 +113,23 @@ mod tests {
         assert!(Version::from_str("2").is_err());
         assert!(Version::from_str("4.5").is_err());
     }
+
+    #[test]
+    fn test_iter() {
+        assert_eq!(Version::iter().len(), 6);
+        assert!(Version::iter()
+            .zip(Version::iter().skip(1))
+            .all(|(a, b)| a < b));
+        for version in Version::iter() {
+            assert_eq!(Version::from_str(version.slug()), Ok(version));
+        }
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Version::One < Version::Two);
+        assert!(Version::TwoOne < Version::TwoFive);
+        assert!(Version::TwoFive < Version::Three);
+        assert!(Version::Four > Version::Three);
+    }
 }
@@ -0,0 +1,216 @@
+use crate::error::ParseError;
+use crate::options::ParseOptions;
+use crate::parser::{self, Segments};
+use crate::License;
+
+/// Zero-copy view of a Creative Commons license URL
+///
+/// Borrows the matched segments from the input instead of owning anything, for
+/// use inside larger zero-copy document processing. Only the structure of the
+/// URL is checked when the view is created; the segments are validated when it is
+/// converted into a [`License`].
+///
+/// # Example
+///
+/// ```rust
+/// use cc_license::{LicenseRef, ParseError};
+///
+/// let url = "https://creativecommons.org/licenses/by-nc/4.0/";
+/// let view = LicenseRef::parse(url)?;
+/// assert_eq!(view.rights(), "by-nc");
+/// assert_eq!(view.version(), Some("4.0"));
+/// assert_eq!(view.to_license()?.short(), "CC BY-NC 4.0".to_string());
+/// # Ok::<(), ParseError>(())
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct LicenseRef<'a> {
+    input: &'a str,
+    segments: Segments,
+}
+
+impl<'a> LicenseRef<'a> {
+    /// Locate the segments of a license URL without validating them
+    pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let segments = parser::split_url(input.as_bytes())?;
+        Ok(LicenseRef { input, segments })
+    }
+
+    /// The whole input the view borrows from
+    pub fn input(&self) -> &'a str {
+        self.input
+    }
+
+    /// The rights segment of the URL, e.g. `by-nc-sa`
+    pub fn rights(&self) -> &'a str {
+        &self.input[self.segments.rights.start..self.segments.rights.end]
+    }
+
+    /// The version segment of the URL, e.g. `4.0`
+    ///
+    /// `None` for instruments whose URL carries no version, such as the retired
+    /// Public Domain Dedication and Certification.
+    pub fn version(&self) -> Option<&'a str> {
+        let version = &self.input[self.segments.version.start..self.segments.version.end];
+        (!version.is_empty()).then_some(version)
+    }
+
+    /// The jurisdiction segment of the URL of a ported license, e.g. `de`
+    pub fn jurisdiction(&self) -> Option<&'a str> {
+        let jurisdiction =
+            &self.input[self.segments.jurisdiction.start..self.segments.jurisdiction.end];
+        (!jurisdiction.is_empty()).then_some(jurisdiction)
+    }
+
+    /// Whether the URL points to the legal code of the license, e.g.
+    /// `https://creativecommons.org/licenses/by/4.0/legalcode.de`
+    pub fn is_legal_code(&self) -> bool {
+        self.segments.legal_code
+    }
+
+    /// Whether the URL points to the RDF description of the license, e.g.
+    /// `https://creativecommons.org/licenses/by/3.0/rdf`
+    pub fn is_rdf(&self) -> bool {
+        self.segments.rdf
+    }
+
+    /// The language of the page, e.g. `fr` for `deed.fr` or `de` for `legalcode.de`
+    ///
+    /// `None` when the URL does not select a translation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cc_license::{License, LicenseRef, ParseError};
+    ///
+    /// let view = LicenseRef::parse("https://creativecommons.org/licenses/by/4.0/legalcode.de")?;
+    /// assert!(view.is_legal_code());
+    /// assert_eq!(view.language(), Some("de"));
+    /// assert_eq!(view.to_license()?, License::CC_BY_4_0);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn language(&self) -> Option<&'a str> {
+        let language = &self.input[self.segments.language.start..self.segments.language.end];
+        (!language.is_empty()).then_some(language)
+    }
+
+    /// Validate the segments using the global default [`ParseOptions`]
+    pub fn to_license(&self) -> Result<License, ParseError> {
+        self.to_license_with(ParseOptions::global_default())
+    }
+
+    /// Validate the segments using the given options
+    pub fn to_license_with(&self, options: &ParseOptions) -> Result<License, ParseError> {
+        parser::parse_segments(self.input.as_bytes(), &self.segments, options)
+    }
+}
+
+impl TryFrom<LicenseRef<'_>> for License {
+    type Error = ParseError;
+
+    fn try_from(view: LicenseRef<'_>) -> Result<Self, Self::Error> {
+        view.to_license()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let url = "http://www.creativecommons.org/publicdomain/zero/1.0";
+        let view = LicenseRef::parse(url).unwrap();
+        assert_eq!(view.input(), url);
+        assert_eq!(view.rights(), "zero");
+        assert_eq!(view.version(), Some("1.0"));
+        assert_eq!(view.jurisdiction(), None);
+        assert_eq!(License::try_from(view), License::from_url(url));
+
+        let view = LicenseRef::parse("http://creativecommons.org/licenses/by-sa/2.0/uk/").unwrap();
+        assert_eq!(view.version(), Some("2.0"));
+        assert_eq!(view.jurisdiction(), Some("uk"));
+        assert_eq!(view.to_license().unwrap().short(), "CC BY-SA 2.0 UK");
+
+        let view = LicenseRef::parse("http://creativecommons.org/licenses/publicdomain").unwrap();
+        assert_eq!(view.rights(), "publicdomain");
+        assert_eq!(view.version(), None);
+
+        let view = LicenseRef::parse("https://creativecommons.org/licenses/by-xx/4.0/").unwrap();
+        assert_eq!(view.rights(), "by-xx");
+        assert_eq!(view.to_license(), Err(ParseError::InvalidRights));
+
+        assert_eq!(
+            LicenseRef::parse("https://creativecommons.org/licenses/by/"),
+            Err(ParseError::MissingVersion)
+        );
+    }
+
+    #[test]
+    fn test_legal_code() {
+        let view = LicenseRef::parse("http://creativecommons.org/licenses/by-nc/3.0/de/legalcode")
+            .unwrap();
+        assert!(view.is_legal_code());
+        assert_eq!(view.language(), None);
+        assert_eq!(view.jurisdiction(), Some("de"));
+        assert_eq!(view.to_license().unwrap().short(), "CC BY-NC 3.0 DE");
+
+        let view = LicenseRef::parse(
+            "https://creativecommons.org/publicdomain/zero/1.0/legalcode.zh-hans",
+        )
+        .unwrap();
+        assert_eq!(view.language(), Some("zh-hans"));
+        assert_eq!(view.to_license(), Ok(License::CC0_1_0));
+
+        let view = LicenseRef::parse("https://creativecommons.org/licenses/by/4.0/").unwrap();
+        assert!(!view.is_legal_code());
+        assert_eq!(view.language(), None);
+    }
+
+    #[test]
+    fn test_deed() {
+        let url = "https://creativecommons.org/licenses/by-nc/4.0/deed.fr";
+        let view = LicenseRef::parse(url).unwrap();
+        assert!(!view.is_legal_code());
+        assert_eq!(view.language(), Some("fr"));
+        assert_eq!(view.to_license(), Ok(License::CC_BY_NC_4_0));
+        assert_eq!(License::from_url(url), Ok(License::CC_BY_NC_4_0));
+
+        let view =
+            LicenseRef::parse("http://creativecommons.org/licenses/by/3.0/deed.en_US").unwrap();
+        assert_eq!(view.language(), Some("en_US"));
+
+        let view = LicenseRef::parse("https://creativecommons.org/licenses/by/4.0/deed").unwrap();
+        assert_eq!(view.language(), None);
+        assert_eq!(view.to_license(), Ok(License::CC_BY_4_0));
+    }
+
+    #[test]
+    fn test_rdf() {
+        let url = "https://creativecommons.org/licenses/by/3.0/rdf";
+        let view = LicenseRef::parse(url).unwrap();
+        assert!(view.is_rdf());
+        assert_eq!(License::from_url(url), Ok(License::CC_BY_3_0));
+        assert_eq!(
+            License::from_url("https://creativecommons.org/licenses/by/3.0/"),
+            License::from_url(url)
+        );
+
+        let view = LicenseRef::parse("http://creativecommons.org/licenses/by-nc/2.0/legalcode.rdf")
+            .unwrap();
+        assert!(view.is_rdf());
+        assert!(view.is_legal_code());
+        assert_eq!(view.language(), None);
+        assert_eq!(view.to_license(), Ok(License::CC_BY_NC_2_0));
+    }
+
+    #[test]
+    fn test_to_license_with() {
+        let mut options = ParseOptions::new();
+        options.require_https = true;
+        let view = LicenseRef::parse("http://creativecommons.org/licenses/by/4.0/").unwrap();
+        assert_eq!(
+            view.to_license_with(&options),
+            Err(ParseError::InvalidScheme)
+        );
+    }
+}